@@ -1,7 +1,22 @@
 fn main() -> Result<(), std::io::Error> {
-    prost_build::compile_protos(
-        &["src/protos/messages/v1/consensus.proto"],
-        &["src/protos/"],
-    )?;
+    prost_build::Config::new()
+        .type_attribute(
+            ".",
+            "#[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize))]",
+        )
+        // Votes encoded before abstentions existed decode as non-abstaining.
+        .field_attribute(
+            "consensus.v1.Vote.abstain",
+            "#[cfg_attr(feature = \"serde\", serde(default))]",
+        )
+        // Proposals encoded before the hash algorithm was selectable use SHA-256.
+        .field_attribute(
+            "consensus.v1.Proposal.hash_algo",
+            "#[cfg_attr(feature = \"serde\", serde(default))]",
+        )
+        .compile_protos(
+            &["src/protos/messages/v1/consensus.proto"],
+            &["src/protos/"],
+        )?;
     Ok(())
 }
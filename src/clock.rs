@@ -0,0 +1,65 @@
+//! Pluggable time source for callers that do not track time themselves.
+//!
+//! Every time-sensitive method still takes an explicit `now`; the service's
+//! [`Clock`] is where [`ConsensusService::now`](crate::service::ConsensusService::now)
+//! and the background reaper read it from. Tests install a [`MockClock`] and
+//! advance it instead of sleeping.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Source of the current time in seconds since Unix epoch.
+///
+/// Install one with
+/// [`ConsensusService::with_clock`](crate::service::ConsensusService::with_clock).
+pub trait Clock: Send + Sync + 'static {
+    /// Current time in seconds since Unix epoch.
+    fn now_secs(&self) -> u64;
+}
+
+/// Default clock reading [`SystemTime::now`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_secs(&self) -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs())
+    }
+}
+
+/// Manually driven clock for deterministic tests.
+///
+/// Clones share the same time, so a test can keep one handle and advance the
+/// clock installed in a service.
+#[derive(Debug, Clone, Default)]
+pub struct MockClock {
+    now: Arc<AtomicU64>,
+}
+
+impl MockClock {
+    /// A clock frozen at `now` (seconds since Unix epoch).
+    pub fn new(now: u64) -> Self {
+        Self {
+            now: Arc::new(AtomicU64::new(now)),
+        }
+    }
+
+    /// Move the clock to `now`.
+    pub fn set(&self, now: u64) {
+        self.now.store(now, Ordering::SeqCst);
+    }
+
+    /// Move the clock forward by `by` (whole seconds).
+    pub fn advance(&self, by: Duration) {
+        self.now.fetch_add(by.as_secs(), Ordering::SeqCst);
+    }
+}
+
+impl Clock for MockClock {
+    fn now_secs(&self) -> u64 {
+        self.now.load(Ordering::SeqCst)
+    }
+}
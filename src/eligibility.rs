@@ -0,0 +1,30 @@
+//! Pluggable proof-of-eligibility checks for votes.
+//!
+//! In permissioned-but-anonymous settings a vote can carry a membership proof
+//! in [`Vote::eligibility_proof`] (e.g. a signature from a group authority over
+//! the voter's identity). The proof is covered by the vote hash and signature;
+//! an [`EligibilityVerifier`] decides whether it is acceptable.
+//!
+//! [`Vote::eligibility_proof`]: crate::protos::consensus::v1::Vote::eligibility_proof
+
+use crate::protos::consensus::v1::Vote;
+
+/// Decides whether a vote's owner is eligible to vote, based on the proof it carries.
+///
+/// Consulted for every incoming vote after the hash and signature checks pass.
+/// Install one with
+/// [`ConsensusService::with_eligibility_verifier`](crate::service::ConsensusService::with_eligibility_verifier).
+pub trait EligibilityVerifier: Send + Sync + 'static {
+    /// Return `true` if `vote.eligibility_proof` proves `vote.vote_owner` may vote.
+    fn verify(&self, vote: &Vote) -> bool;
+}
+
+/// Default verifier: every voter is eligible and the proof is ignored.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AcceptAllEligibility;
+
+impl EligibilityVerifier for AcceptAllEligibility {
+    fn verify(&self, _vote: &Vote) -> bool {
+        true
+    }
+}
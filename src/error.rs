@@ -4,14 +4,28 @@
 //! configuration validation, vote/proposal validation, session state, and
 //! consensus result categories.
 
-use crate::signing::ConsensusSchemeError;
+use crate::{session::ConsensusState, signing::ConsensusSchemeError};
 
 /// Enumerates everything that can go wrong during consensus operations.
 #[derive(Debug, thiserror::Error)]
 pub enum ConsensusError {
     // Configuration Validation Errors
-    #[error("consensus_threshold must be between 0.0 and 1.0")]
+    #[error("consensus_threshold must be between 0.0 and 1.0 (or an absolute count of at least 1)")]
     InvalidConsensusThreshold,
+    #[error(
+        "absolute threshold of {required} YES votes exceeds expected_voters_count {expected_voters_count}"
+    )]
+    AbsoluteThresholdExceedsVoters {
+        required: u32,
+        expected_voters_count: u32,
+    },
+    #[error(
+        "expected_voters_count {expected_voters_count} exceeds the {allowed_voters} allowed voters"
+    )]
+    ExpectedVotersExceedAllowlist {
+        expected_voters_count: u32,
+        allowed_voters: usize,
+    },
     #[error("timeout must be greater than 0")]
     InvalidTimeout,
     #[error("expected_voters_count must be greater than 0")]
@@ -22,12 +36,33 @@ pub enum ConsensusError {
     // Vote and Proposal Validation Errors
     #[error("Invalid vote signature")]
     InvalidVoteSignature,
+    #[error("Non-canonical vote signature")]
+    NonCanonicalSignature,
+    #[error("Invalid proposal signature")]
+    InvalidProposalSignature,
     #[error("Empty signature")]
     EmptySignature,
     #[error("Duplicate vote")]
     DuplicateVote,
     #[error("User already voted")]
     UserAlreadyVoted,
+    /// The owner signed two votes with opposite choices on the same proposal.
+    #[error("Equivocation: owner cast conflicting votes")]
+    Equivocation {
+        owner: Vec<u8>,
+        first_hash: Vec<u8>,
+        second_hash: Vec<u8>,
+    },
+    #[error("Vote not found")]
+    VoteNotFound,
+    #[error("Vote is chained to by a later vote and cannot be retracted")]
+    VoteReferenced,
+    #[error("Voter is not in the scope's allowed voters")]
+    VoterNotEligible,
+    #[error("Proposal owner cannot vote on their own proposal")]
+    OwnerCannotVote,
+    #[error("Session already holds the maximum of {max_votes} votes")]
+    TooManyVotes { max_votes: usize },
     #[error("Vote expired")]
     VoteExpired,
     #[error("Empty vote owner")]
@@ -38,6 +73,12 @@ pub enum ConsensusError {
     EmptyVoteHash,
     #[error("Proposal expired")]
     ProposalExpired,
+    #[error("Proposal created {age_secs}s ago exceeds the inbound age limit of {max_age_secs}s")]
+    ProposalTooOld { age_secs: u64, max_age_secs: u64 },
+    #[error("Proposal payload of {size} bytes exceeds the scope limit of {max} bytes")]
+    PayloadTooLarge { size: usize, max: usize },
+    #[error("Proposal round is inconsistent with its votes")]
+    InvalidRound,
     #[error("Vote proposal_id mismatch: vote belongs to different proposal")]
     VoteProposalIdMismatch,
     #[error("Received hash mismatch")]
@@ -46,18 +87,48 @@ pub enum ConsensusError {
     ParentHashMismatch,
     #[error("Invalid vote timestamp")]
     InvalidVoteTimestamp,
+    #[error("Vote cast {age_secs}s ago exceeds the maximum vote age of {max_age_secs}s")]
+    VoteTooOld { age_secs: u64, max_age_secs: u64 },
     #[error("Vote timestamp is older than creation time")]
     TimestampOlderThanCreationTime,
+    #[error("Invalid vote eligibility proof")]
+    InvalidEligibilityProof,
 
     // Session/State Errors
-    #[error("Session not active")]
-    SessionNotActive,
+    #[error("Session is in terminal state {state:?}")]
+    SessionTerminal { state: ConsensusState },
     #[error("Session not found")]
     SessionNotFound,
+    #[error("Only the proposal owner can do this")]
+    NotProposalOwner,
+    #[error("Session annotation exceeds size limits")]
+    AnnotationLimitExceeded,
     #[error("Proposal already exist in consensus service")]
     ProposalAlreadyExist,
+    #[error("Proposal header does not match the local proposal")]
+    ProposalHeaderMismatch,
+    #[error("Proposal ID was recently used by an evicted proposal")]
+    ProposalIdRecentlyUsed,
     #[error("Scope not found")]
     ScopeNotFound,
+    #[error("Finalization is paused for this scope")]
+    ScopePaused,
+    #[error("Scope already exists")]
+    ScopeAlreadyExists,
+    #[error("Scope has no known voter set")]
+    VoterSetUnknown,
+    #[error("Stored session is corrupted")]
+    StorageCorrupted,
+
+    // Wire Format Errors
+    #[error("Malformed consensus message")]
+    MalformedMessage,
+    #[error("Unsupported protocol version {0}")]
+    UnsupportedProtocolVersion(u32),
+    #[error("Snapshot checksum mismatch")]
+    CorruptSnapshot,
+    #[error("Unsupported vote hash algorithm {0}")]
+    UnsupportedHashAlgo(u32),
 
     // Consensus Result Errors
     #[error("Insufficient votes at timeout")]
@@ -66,9 +137,19 @@ pub enum ConsensusError {
     MaxRoundsExceeded,
     #[error("Consensus not reached")]
     ConsensusNotReached,
+    /// The proposal expired while still active and could not be finalized
+    /// (e.g. its scope is paused).
+    #[error("Consensus expired before it could be finalized")]
+    ConsensusExpired,
+    #[error("Certificate votes do not decide its stated result")]
+    CertificateMismatch,
     #[error("Consensus failed")]
     ConsensusFailed,
 
     #[error("Signature scheme failure: {0}")]
     SignatureScheme(#[from] ConsensusSchemeError),
+    /// The storage backend failed (I/O, lost connection, ...). Never produced
+    /// by the in-memory backend.
+    #[error("Storage backend failure: {0}")]
+    StorageBackend(#[source] Box<dyn std::error::Error + Send + Sync>),
 }
@@ -1,11 +1,15 @@
-use std::sync::{
-    Arc,
-    mpsc::{self, Receiver, SyncSender, TrySendError},
+use std::{
+    collections::VecDeque,
+    sync::{
+        Arc,
+        mpsc::{self, Receiver, SyncSender, TrySendError},
+    },
+    time::{Duration, Instant},
 };
 
 use parking_lot::Mutex;
 
-use crate::{scope::ConsensusScope, types::ConsensusEvent};
+use crate::{error::ConsensusError, scope::ConsensusScope, types::ConsensusEvent};
 
 /// Trait for broadcasting consensus events to subscribers.
 ///
@@ -21,11 +25,22 @@ where
 
     /// Subscribe to receive consensus events from all scopes.
     fn subscribe(&self) -> Self::Receiver;
+    /// Subscribe to receive consensus events for `scope` only.
+    ///
+    /// Buses should route natively so events from other scopes never reach
+    /// (or queue for) this subscriber.
+    fn subscribe_scoped(&self, scope: &Scope) -> Self::Receiver;
     /// Publish an event for a specific scope.
     fn publish(&self, scope: Scope, event: ConsensusEvent);
 }
 
-type Subscribers<Scope> = Arc<Mutex<Vec<SyncSender<(Scope, ConsensusEvent)>>>>;
+struct Subscriber<Scope> {
+    sender: SyncSender<(Scope, ConsensusEvent)>,
+    terminal_only: bool,
+    scope: Option<Scope>,
+}
+
+type Subscribers<Scope> = Arc<Mutex<Vec<Subscriber<Scope>>>>;
 
 /// Sends every event to all current subscribers in-process.
 ///
@@ -54,6 +69,102 @@ where
             subscribers: Arc::new(Mutex::new(Vec::new())),
         }
     }
+
+    /// Subscribe to terminal outcomes only (see [`ConsensusEvent::is_terminal`]).
+    ///
+    /// Other events are never queued for this subscriber, so they cannot fill
+    /// its buffer and crowd out outcomes.
+    pub fn subscribe_terminal_events(&self) -> Receiver<(Scope, ConsensusEvent)> {
+        self.add_subscriber(true, None)
+    }
+
+    /// Subscribe to consensus results only, as `(scope, result)` pairs.
+    ///
+    /// Covers [`ConsensusReached`](ConsensusEvent::ConsensusReached) and
+    /// [`ConsensusCertified`](ConsensusEvent::ConsensusCertified); failures and
+    /// other events are skipped. Saves consumers the usual
+    /// `if let ConsensusEvent::ConsensusReached { .. }` loop.
+    pub fn subscribe_reached_only(&self) -> ReachedReceiver<Scope> {
+        ReachedReceiver {
+            inner: self.add_subscriber(true, None),
+        }
+    }
+
+    fn add_subscriber(
+        &self,
+        terminal_only: bool,
+        scope: Option<Scope>,
+    ) -> Receiver<(Scope, ConsensusEvent)> {
+        self.add_subscriber_with_backlog(terminal_only, scope, std::iter::empty())
+    }
+
+    /// Register a subscriber whose channel is first filled with `backlog`.
+    fn add_subscriber_with_backlog(
+        &self,
+        terminal_only: bool,
+        scope: Option<Scope>,
+        backlog: impl ExactSizeIterator<Item = (Scope, ConsensusEvent)>,
+    ) -> Receiver<(Scope, ConsensusEvent)> {
+        let (sender, receiver) = mpsc::sync_channel(self.capacity + backlog.len());
+        for event in backlog {
+            // Cannot fail: the channel has room for the whole backlog.
+            let _ = sender.try_send(event);
+        }
+        self.subscribers.lock().push(Subscriber {
+            sender,
+            terminal_only,
+            scope,
+        });
+        receiver
+    }
+}
+
+/// Receiver of `(scope, result)` pairs returned by
+/// [`BroadcastEventBus::subscribe_reached_only`].
+///
+/// Iterating blocks for the next result and ends once the bus is dropped.
+pub struct ReachedReceiver<Scope> {
+    inner: Receiver<(Scope, ConsensusEvent)>,
+}
+
+impl<Scope> ReachedReceiver<Scope> {
+    /// Block until the next result arrives, or `None` once the bus is dropped.
+    pub fn recv(&self) -> Option<(Scope, bool)> {
+        self.inner.iter().find_map(reached_result)
+    }
+
+    /// Return the next queued result without blocking.
+    pub fn try_recv(&self) -> Option<(Scope, bool)> {
+        self.inner.try_iter().find_map(reached_result)
+    }
+
+    /// Wait up to `timeout` for the next result.
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<(Scope, bool)> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            let event = self.inner.recv_timeout(remaining).ok()?;
+            if let Some(reached) = reached_result(event) {
+                return Some(reached);
+            }
+        }
+    }
+}
+
+impl<Scope> Iterator for ReachedReceiver<Scope> {
+    type Item = (Scope, bool);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.recv()
+    }
+}
+
+fn reached_result<Scope>((scope, event): (Scope, ConsensusEvent)) -> Option<(Scope, bool)> {
+    match event {
+        ConsensusEvent::ConsensusReached { result, .. }
+        | ConsensusEvent::ConsensusCertified { result, .. } => Some((scope, result)),
+        _ => None,
+    }
 }
 
 impl<Scope> Default for BroadcastEventBus<Scope>
@@ -72,21 +183,288 @@ where
     type Receiver = Receiver<(Scope, ConsensusEvent)>;
 
     fn subscribe(&self) -> Self::Receiver {
-        let (sender, receiver) = mpsc::sync_channel(self.capacity);
-        self.subscribers.lock().push(sender);
-        receiver
+        self.add_subscriber(false, None)
+    }
+
+    /// Events for other scopes are dropped at publish time, so however many
+    /// scopes share the bus they never fill this subscriber's buffer.
+    fn subscribe_scoped(&self, scope: &Scope) -> Self::Receiver {
+        self.add_subscriber(false, Some(scope.clone()))
     }
 
     fn publish(&self, scope: Scope, event: ConsensusEvent) {
+        let terminal = event.is_terminal();
         let mut subscribers = self.subscribers.lock();
         // Deliver to every live subscriber; drop senders whose receiver is gone,
         // and skip (without blocking) any subscriber whose buffer is full.
-        subscribers.retain(
-            |sender| match sender.try_send((scope.clone(), event.clone())) {
+        subscribers.retain(|subscriber| {
+            if (subscriber.terminal_only && !terminal)
+                || subscriber.scope.as_ref().is_some_and(|only| *only != scope)
+            {
+                return true;
+            }
+            match subscriber.sender.try_send((scope.clone(), event.clone())) {
                 Ok(()) => true,
                 Err(TrySendError::Full(_)) => true,
                 Err(TrySendError::Disconnected(_)) => false,
-            },
-        );
+            }
+        });
+    }
+}
+
+/// Broadcast bus that replays its most recent events to every new subscriber.
+///
+/// Keeps a ring buffer of the last `replay_size` events; a subscriber created
+/// later first receives those (oldest first, filtered like its subscription)
+/// and then live events, so a component started after a proposal finalized
+/// still observes its outcome. For replay from an arbitrary position, see
+/// [`LoggedEventBus`].
+#[derive(Clone)]
+pub struct ReplayEventBus<Scope>
+where
+    Scope: ConsensusScope,
+{
+    inner: BroadcastEventBus<Scope>,
+    replay_size: usize,
+    history: Arc<Mutex<VecDeque<(Scope, ConsensusEvent)>>>,
+}
+
+impl<Scope> ReplayEventBus<Scope>
+where
+    Scope: ConsensusScope,
+{
+    /// Create a bus replaying the last `replay_size` events to new subscribers.
+    ///
+    /// Each subscriber additionally buffers up to 1000 live events, like
+    /// [`BroadcastEventBus::default`].
+    pub fn new(replay_size: usize) -> Self {
+        Self {
+            inner: BroadcastEventBus::default(),
+            replay_size,
+            history: Arc::new(Mutex::new(VecDeque::new())),
+        }
+    }
+
+    fn subscribe_filtered(
+        &self,
+        terminal_only: bool,
+        scope: Option<&Scope>,
+    ) -> Receiver<(Scope, ConsensusEvent)> {
+        // Holding the history lock keeps publishes out until the subscriber
+        // is registered, so no event is replayed twice or missed.
+        let history = self.history.lock();
+        let backlog: Vec<_> = history
+            .iter()
+            .filter(|(event_scope, event)| {
+                (!terminal_only || event.is_terminal())
+                    && scope.is_none_or(|only| only == event_scope)
+            })
+            .cloned()
+            .collect();
+        self.inner
+            .add_subscriber_with_backlog(terminal_only, scope.cloned(), backlog.into_iter())
+    }
+}
+
+impl<Scope> ConsensusEventBus<Scope> for ReplayEventBus<Scope>
+where
+    Scope: ConsensusScope,
+{
+    type Receiver = Receiver<(Scope, ConsensusEvent)>;
+
+    fn subscribe(&self) -> Self::Receiver {
+        self.subscribe_filtered(false, None)
+    }
+
+    fn subscribe_scoped(&self, scope: &Scope) -> Self::Receiver {
+        self.subscribe_filtered(false, Some(scope))
+    }
+
+    fn publish(&self, scope: Scope, event: ConsensusEvent) {
+        let mut history = self.history.lock();
+        if self.replay_size > 0 {
+            if history.len() == self.replay_size {
+                history.pop_front();
+            }
+            history.push_back((scope.clone(), event.clone()));
+        }
+        self.inner.publish(scope, event);
+    }
+}
+
+/// An event recorded by an [`EventLog`], tagged with its position in the stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoggedEvent<Scope> {
+    /// Position in the log; starts at 0 and increases by one per appended event.
+    pub sequence: u64,
+    /// Scope the event was published for.
+    pub scope: Scope,
+    /// The event exactly as published.
+    pub event: ConsensusEvent,
+}
+
+/// Append-only record of published events that consumers can replay.
+///
+/// Implement this to back the log with a file, database, or message queue so
+/// that a restarting consumer can catch up on events it missed. The default
+/// [`InMemoryEventLog`] keeps a bounded window of events in RAM.
+pub trait EventLog<Scope>: Clone + Send + Sync + 'static
+where
+    Scope: ConsensusScope,
+{
+    /// Append an event and return its sequence number.
+    fn append(&self, scope: Scope, event: ConsensusEvent) -> Result<u64, ConsensusError>;
+
+    /// Return every retained event with `sequence >= since`, oldest first.
+    ///
+    /// Events dropped by retention are skipped; compare the first returned
+    /// sequence with `since` to detect the gap.
+    fn replay_events(&self, since: u64) -> Result<Vec<LoggedEvent<Scope>>, ConsensusError>;
+}
+
+struct EventLogState<Scope> {
+    next_sequence: u64,
+    events: VecDeque<LoggedEvent<Scope>>,
+}
+
+/// Keeps the most recent events in memory.
+///
+/// Once `retention` events are held, appending drops the oldest one.
+#[derive(Clone)]
+pub struct InMemoryEventLog<Scope>
+where
+    Scope: ConsensusScope,
+{
+    retention: usize,
+    state: Arc<Mutex<EventLogState<Scope>>>,
+}
+
+impl<Scope> InMemoryEventLog<Scope>
+where
+    Scope: ConsensusScope,
+{
+    /// Create a log that retains at most `retention` events. Default is 10000.
+    pub fn new(retention: usize) -> Self {
+        Self {
+            retention,
+            state: Arc::new(Mutex::new(EventLogState {
+                next_sequence: 0,
+                events: VecDeque::new(),
+            })),
+        }
+    }
+}
+
+impl<Scope> Default for InMemoryEventLog<Scope>
+where
+    Scope: ConsensusScope,
+{
+    fn default() -> Self {
+        Self::new(10_000)
+    }
+}
+
+impl<Scope> EventLog<Scope> for InMemoryEventLog<Scope>
+where
+    Scope: ConsensusScope,
+{
+    fn append(&self, scope: Scope, event: ConsensusEvent) -> Result<u64, ConsensusError> {
+        let mut state = self.state.lock();
+        let sequence = state.next_sequence;
+        state.next_sequence += 1;
+        if self.retention == 0 {
+            return Ok(sequence);
+        }
+        if state.events.len() == self.retention {
+            state.events.pop_front();
+        }
+        state.events.push_back(LoggedEvent {
+            sequence,
+            scope,
+            event,
+        });
+        Ok(sequence)
+    }
+
+    fn replay_events(&self, since: u64) -> Result<Vec<LoggedEvent<Scope>>, ConsensusError> {
+        let state = self.state.lock();
+        Ok(state
+            .events
+            .iter()
+            .filter(|logged| logged.sequence >= since)
+            .cloned()
+            .collect())
+    }
+}
+
+/// Event bus that appends every event to an [`EventLog`] before forwarding it
+/// to an inner bus.
+///
+/// Live subscribers use the inner bus as usual; late or restarting consumers
+/// call [`replay_events`](Self::replay_events) to catch up.
+#[derive(Clone)]
+pub struct LoggedEventBus<Scope, Bus, Log>
+where
+    Scope: ConsensusScope,
+    Bus: ConsensusEventBus<Scope>,
+    Log: EventLog<Scope>,
+{
+    inner: Bus,
+    log: Log,
+    _scope: std::marker::PhantomData<fn() -> Scope>,
+}
+
+impl<Scope, Bus, Log> LoggedEventBus<Scope, Bus, Log>
+where
+    Scope: ConsensusScope,
+    Bus: ConsensusEventBus<Scope>,
+    Log: EventLog<Scope>,
+{
+    /// Wrap `inner` so every published event is also appended to `log`.
+    pub fn new(inner: Bus, log: Log) -> Self {
+        Self {
+            inner,
+            log,
+            _scope: std::marker::PhantomData,
+        }
+    }
+
+    /// The bus events are forwarded to.
+    pub fn inner(&self) -> &Bus {
+        &self.inner
+    }
+
+    /// The log events are appended to.
+    pub fn log(&self) -> &Log {
+        &self.log
+    }
+
+    /// Return every retained event with `sequence >= since`, oldest first.
+    pub fn replay_events(&self, since: u64) -> Result<Vec<LoggedEvent<Scope>>, ConsensusError> {
+        self.log.replay_events(since)
+    }
+}
+
+impl<Scope, Bus, Log> ConsensusEventBus<Scope> for LoggedEventBus<Scope, Bus, Log>
+where
+    Scope: ConsensusScope,
+    Bus: ConsensusEventBus<Scope>,
+    Log: EventLog<Scope>,
+{
+    type Receiver = Bus::Receiver;
+
+    fn subscribe(&self) -> Self::Receiver {
+        self.inner.subscribe()
+    }
+
+    fn subscribe_scoped(&self, scope: &Scope) -> Self::Receiver {
+        self.inner.subscribe_scoped(scope)
+    }
+
+    fn publish(&self, scope: Scope, event: ConsensusEvent) {
+        // Publishing cannot fail; a log that rejects the append only loses the
+        // replay copy, live subscribers still receive the event.
+        let _ = self.log.append(scope.clone(), event.clone());
+        self.inner.publish(scope, event);
     }
 }
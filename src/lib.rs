@@ -23,11 +23,15 @@
 //!   / [`process_incoming_vote`](service::ConsensusService::process_incoming_vote) on receipt.
 //! - **Timeout scheduling** — schedule a timer per proposal and call
 //!   [`handle_consensus_timeout`](service::ConsensusService::handle_consensus_timeout)
-//!   when it fires. Without this, proposals with offline voters stay `Active`
-//!   forever and silent-peer liveness logic never runs.
+//!   when it fires (or run one sweep for all scopes with
+//!   [`finalize_all_expired`](service::ConsensusService::finalize_all_expired) /
+//!   [`spawn_reaper`](service::ConsensusService::spawn_reaper)). Without this,
+//!   proposals with offline voters stay `Active` forever and silent-peer
+//!   liveness logic never runs.
 //! - **Time source** — every time-sensitive method takes `now` (seconds since
 //!   Unix epoch) as a parameter, so the application controls where time comes
-//!   from (system time, a test clock, etc.).
+//!   from (system time, a test clock, etc.). [`ConsensusService::now`](service::ConsensusService::now)
+//!   reads the service's [`Clock`](clock::Clock) for callers that do not track time.
 //! - **`expected_voters_count` accuracy** — this drives all threshold math;
 //!   a wrong value produces wrong results.
 //! - **Session eviction awareness** — the default service keeps at most 10
@@ -95,15 +99,21 @@
 //! | Module | Purpose |
 //! |--------|---------|
 //! | [`service`] | [`ConsensusService`](service::ConsensusService) and [`DefaultConsensusService`](service::DefaultConsensusService) |
+//! | [`reaper`] | Opt-in background sweep finalizing expired sessions ([`ReaperHandle`](reaper::ReaperHandle)) |
 //! | [`session`] | [`ConsensusSession`](session::ConsensusSession), [`ConsensusConfig`](session::ConsensusConfig), and [`ConsensusState`](session::ConsensusState) |
 //! | [`scope`] | [`ConsensusScope`](scope::ConsensusScope) trait and [`ScopeID`](scope::ScopeID) alias |
 //! | [`scope_config`] | Per-scope defaults ([`ScopeConfig`](scope_config::ScopeConfig), [`NetworkType`](scope_config::NetworkType)) |
 //! | [`types`] | Request/event types ([`CreateProposalRequest`](types::CreateProposalRequest), [`ConsensusEvent`](types::ConsensusEvent)) |
-//! | [`storage`] | [`ConsensusStorage`](storage::ConsensusStorage) trait and [`InMemoryConsensusStorage`](storage::InMemoryConsensusStorage) |
-//! | [`events`] | [`ConsensusEventBus`](events::ConsensusEventBus) trait and [`BroadcastEventBus`](events::BroadcastEventBus) |
+//! | [`storage`] | [`ConsensusStorage`](storage::ConsensusStorage) trait and [`InMemoryConsensusStorage`](storage::InMemoryConsensusStorage); `RedbConsensusStorage` with the `redb` feature |
+//! | [`events`] | [`ConsensusEventBus`](events::ConsensusEventBus) trait, [`BroadcastEventBus`](events::BroadcastEventBus), [`ReplayEventBus`](events::ReplayEventBus), and the replayable [`EventLog`](events::EventLog) |
 //! | [`signing`] | [`ConsensusSignatureScheme`](signing::ConsensusSignatureScheme) trait and the default [`EthereumConsensusSigner`](signing::EthereumConsensusSigner) impl |
+//! | [`eligibility`] | [`EligibilityVerifier`](eligibility::EligibilityVerifier) trait for vote proof-of-eligibility checks |
+//! | [`clock`] | [`Clock`](clock::Clock) trait with [`SystemClock`](clock::SystemClock) and the test [`MockClock`](clock::MockClock) |
+//! | [`timestamp_policy`] | [`TimestampPolicy`](timestamp_policy::TimestampPolicy) trait for vote freshness rules (RFC 3.4) |
+//! | [`weights`] | [`VoterWeights`](weights::VoterWeights) for stake-weighted voting |
 //! | [`error`] | [`ConsensusError`](error::ConsensusError) enum |
 //! | [`utils`] | Low-level validation and hashing helpers |
+//! | [`wire`] | [`ConsensusMessage`](protos::consensus::v1::ConsensusMessage) envelope encoding and decoding, checksummed snapshot export |
 
 pub mod protos {
     pub mod consensus {
@@ -113,8 +123,11 @@ pub mod protos {
     }
 }
 
+pub mod clock;
+pub mod eligibility;
 pub mod error;
 pub mod events;
+pub mod reaper;
 pub mod scope;
 pub mod scope_config;
 pub mod service;
@@ -122,8 +135,15 @@ pub mod service_stats;
 pub mod session;
 pub mod signing;
 pub mod storage;
+pub mod timestamp_policy;
 pub mod types;
 pub mod utils;
+pub mod weights;
+pub mod wire;
+
+mod orphan_votes;
+mod recent_ids;
+mod vote_batches;
 
 #[cfg(test)]
 pub(crate) mod test_utils;
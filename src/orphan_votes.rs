@@ -0,0 +1,85 @@
+//! Buffer for votes that arrive before their proposal.
+//!
+//! Gossip does not preserve order, so a vote can reach a peer before the
+//! proposal it belongs to. When enabled, [`OrphanVotes`] holds such votes per
+//! scope (bounded and expiring) until the proposal is processed.
+
+use std::collections::{HashMap, VecDeque};
+
+use parking_lot::Mutex;
+
+use crate::{protos::consensus::v1::Vote, scope::ConsensusScope};
+
+/// Votes waiting for their proposal, per scope, each with the time (seconds
+/// since Unix epoch) it was received, oldest first.
+pub(crate) struct OrphanVotes<Scope> {
+    by_scope: Mutex<HashMap<Scope, VecDeque<(Vote, u64)>>>,
+}
+
+impl<Scope: ConsensusScope> OrphanVotes<Scope> {
+    pub(crate) fn new() -> Self {
+        Self {
+            by_scope: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Hold `vote` received at `now`, keeping at most `capacity` votes per
+    /// scope by dropping the oldest.
+    pub(crate) fn hold(&self, scope: &Scope, vote: Vote, now: u64, capacity: usize) {
+        let mut by_scope = self.by_scope.lock();
+        let held = by_scope.entry(scope.clone()).or_default();
+        while held.len() >= capacity {
+            if held.pop_front().is_none() {
+                return;
+            }
+        }
+        held.push_back((vote, now));
+    }
+
+    /// Remove and return the votes held for `proposal_id`, skipping any
+    /// received `ttl_secs` or more before `now`.
+    pub(crate) fn take(
+        &self,
+        scope: &Scope,
+        proposal_id: u64,
+        now: u64,
+        ttl_secs: u64,
+    ) -> Vec<Vote> {
+        let mut by_scope = self.by_scope.lock();
+        let Some(held) = by_scope.get_mut(scope) else {
+            return Vec::new();
+        };
+        held.retain(|(_, received_at)| received_at.saturating_add(ttl_secs) > now);
+
+        let mut taken = Vec::new();
+        held.retain(|(vote, _)| {
+            if vote.proposal_id == proposal_id {
+                taken.push(vote.clone());
+                false
+            } else {
+                true
+            }
+        });
+        if held.is_empty() {
+            by_scope.remove(scope);
+        }
+        taken
+    }
+
+    /// Move the votes held for `old` under `new`, oldest first.
+    pub(crate) fn rename_scope(&self, old: &Scope, new: &Scope) {
+        let mut by_scope = self.by_scope.lock();
+        let Some(moved) = by_scope.remove(old) else {
+            return;
+        };
+        let held = by_scope.entry(new.clone()).or_default();
+        held.extend(moved);
+        held.make_contiguous()
+            .sort_by_key(|&(_, received_at)| received_at);
+    }
+
+    /// Drop every vote held for `scope`.
+    pub(crate) fn clear_scope(&self, scope: &Scope) {
+        self.by_scope.lock().remove(scope);
+    }
+}
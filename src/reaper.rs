@@ -0,0 +1,132 @@
+//! Opt-in background sweep that finalizes expired sessions across all scopes.
+//!
+//! The library never schedules timeouts on its own. Instead of one timer per
+//! proposal, an application can call
+//! [`finalize_all_expired`](ConsensusService::finalize_all_expired) from its
+//! own scheduler, or start a single background thread with
+//! [`spawn_reaper`](ConsensusService::spawn_reaper).
+
+use std::sync::Arc;
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use parking_lot::Mutex;
+
+use crate::{
+    error::ConsensusError, events::ConsensusEventBus, scope::ConsensusScope,
+    service::ConsensusService, signing::ConsensusSignatureScheme, storage::ConsensusStorage,
+    types::ExpirySweep,
+};
+
+/// One scope's entry in [`finalize_all_expired`](ConsensusService::finalize_all_expired).
+type ScopeSweep<Scope> = (Scope, Result<ExpirySweep, ConsensusError>);
+
+/// Handle to a background reaper started by
+/// [`spawn_reaper`](ConsensusService::spawn_reaper).
+///
+/// Dropping the handle stops the reaper without waiting for it; call
+/// [`abort`](Self::abort) to stop it and wait for the thread to exit.
+#[derive(Debug)]
+pub struct ReaperHandle {
+    stop: Option<Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+    last_error: Arc<Mutex<Option<ConsensusError>>>,
+}
+
+impl ReaperHandle {
+    /// Take the most recent error a sweep ran into, clearing it.
+    ///
+    /// Covers failures to list scopes, to list a scope's sessions, and to
+    /// finalize a session. Poll it to surface storage trouble the reaper
+    /// would otherwise only retry past.
+    pub fn take_last_error(&self) -> Option<ConsensusError> {
+        self.last_error.lock().take()
+    }
+
+    /// Stop the reaper and wait for an in-flight sweep to finish.
+    pub fn abort(mut self) {
+        self.stop.take();
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+
+    /// Whether the reaper thread has exited.
+    pub fn is_finished(&self) -> bool {
+        self.thread.as_ref().is_none_or(JoinHandle::is_finished)
+    }
+}
+
+impl Drop for ReaperHandle {
+    fn drop(&mut self) {
+        // Disconnecting the channel wakes the reaper out of its wait.
+        self.stop.take();
+    }
+}
+
+impl<Scope, Storage, Event, Signer> ConsensusService<Scope, Storage, Event, Signer>
+where
+    Scope: ConsensusScope,
+    Storage: ConsensusStorage<Scope>,
+    Event: ConsensusEventBus<Scope>,
+    Signer: ConsensusSignatureScheme,
+{
+    /// Run [`finalize_expired`](Self::finalize_expired) for every known scope.
+    ///
+    /// Only `Active` sessions past their expiration timestamp are finalized;
+    /// sessions that already reached consensus or failed are left alone and
+    /// emit nothing. Returns one entry per scope where the sweep did something,
+    /// in storage order. A scope whose sessions cannot be listed gets an `Err`
+    /// entry, and the remaining scopes are still swept.
+    pub fn finalize_all_expired(&self, now: u64) -> Result<Vec<ScopeSweep<Scope>>, ConsensusError> {
+        Ok(self
+            .list_scopes()?
+            .into_iter()
+            .filter_map(|scope| match self.finalize_expired(&scope, now) {
+                Ok(sweep) if sweep.is_empty() => None,
+                sweep => Some((scope, sweep)),
+            })
+            .collect())
+    }
+
+    /// Start a background thread that calls
+    /// [`finalize_all_expired`](Self::finalize_all_expired) at the service's
+    /// [`now`](Self::now) every `interval`, then
+    /// [`flush_vote_batches`](Self::flush_vote_batches).
+    ///
+    /// One sweep replaces a timer per proposal. Sessions that fail to
+    /// finalize stay active and are retried on the next tick; the latest
+    /// failure is kept for [`ReaperHandle::take_last_error`]. The reaper runs
+    /// until the returned handle is aborted or dropped.
+    pub fn spawn_reaper(&self, interval: Duration) -> ReaperHandle {
+        let (stop, stopped) = mpsc::channel::<()>();
+        let last_error = Arc::new(Mutex::new(None));
+        let service = self.clone();
+        let slot = Arc::clone(&last_error);
+        let thread = thread::spawn(move || {
+            while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(interval) {
+                let now = service.now();
+                let failure = match service.finalize_all_expired(now) {
+                    Ok(swept) => swept
+                        .into_iter()
+                        .flat_map(|(_, sweep)| match sweep {
+                            Ok(sweep) => sweep.errors.into_iter().map(|(_, err)| err).collect(),
+                            Err(err) => vec![err],
+                        })
+                        .last(),
+                    Err(err) => Some(err),
+                };
+                if let Some(err) = failure {
+                    *slot.lock() = Some(err);
+                }
+                service.flush_vote_batches(now);
+            }
+        });
+        ReaperHandle {
+            stop: Some(stop),
+            thread: Some(thread),
+            last_error,
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//! Tombstones for recently evicted proposal IDs.
+//!
+//! Proposal IDs can repeat (peers may build the same content-addressed ID, or
+//! a random one may collide), so once a finalized session is evicted its ID
+//! can be seen again. [`RecentProposalIds`] remembers evicted IDs per scope
+//! for a window so a stale gossiped vote for the old proposal cannot attach to
+//! a new one that happens to reuse the ID.
+
+use std::collections::{HashMap, VecDeque};
+
+use parking_lot::Mutex;
+
+use crate::scope::ConsensusScope;
+
+/// Most tombstones kept per scope; the oldest are forgotten first.
+const MAX_RECENT_IDS_PER_SCOPE: usize = 1024;
+
+/// Evicted proposal IDs per scope, each with the time (seconds since Unix
+/// epoch) it was evicted, oldest first.
+pub(crate) struct RecentProposalIds<Scope> {
+    by_scope: Mutex<HashMap<Scope, VecDeque<(u64, u64)>>>,
+}
+
+impl<Scope: ConsensusScope> RecentProposalIds<Scope> {
+    pub(crate) fn new() -> Self {
+        Self {
+            by_scope: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Remember that `proposal_ids` left `scope` at `now`.
+    pub(crate) fn retire(&self, scope: &Scope, proposal_ids: &[u64], now: u64) {
+        if proposal_ids.is_empty() {
+            return;
+        }
+        let mut by_scope = self.by_scope.lock();
+        let recent = by_scope.entry(scope.clone()).or_default();
+        for &proposal_id in proposal_ids {
+            if recent.len() == MAX_RECENT_IDS_PER_SCOPE {
+                recent.pop_front();
+            }
+            recent.push_back((proposal_id, now));
+        }
+    }
+
+    /// Move the IDs retired from `old` under `new`, keeping retirement order.
+    pub(crate) fn rename_scope(&self, old: &Scope, new: &Scope) {
+        let mut by_scope = self.by_scope.lock();
+        let Some(moved) = by_scope.remove(old) else {
+            return;
+        };
+        let recent = by_scope.entry(new.clone()).or_default();
+        recent.extend(moved);
+        recent
+            .make_contiguous()
+            .sort_by_key(|&(_, retired_at)| retired_at);
+        while recent.len() > MAX_RECENT_IDS_PER_SCOPE {
+            recent.pop_front();
+        }
+    }
+
+    /// Whether `proposal_id` was retired from `scope` less than `window_secs`
+    /// before `now`. Entries older than the window are dropped.
+    pub(crate) fn is_recent(
+        &self,
+        scope: &Scope,
+        proposal_id: u64,
+        now: u64,
+        window_secs: u64,
+    ) -> bool {
+        let mut by_scope = self.by_scope.lock();
+        let Some(recent) = by_scope.get_mut(scope) else {
+            return false;
+        };
+        while recent
+            .front()
+            .is_some_and(|&(_, retired_at)| retired_at.saturating_add(window_secs) <= now)
+        {
+            recent.pop_front();
+        }
+        recent.iter().any(|&(id, _)| id == proposal_id)
+    }
+}
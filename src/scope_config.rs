@@ -5,15 +5,23 @@
 //! Use [`ScopeConfigBuilder`] (via [`ConsensusService::scope()`](crate::service::ConsensusService::scope))
 //! to create or update configurations.
 
-use std::time::Duration;
+use std::{collections::HashSet, sync::Arc, time::Duration};
 
 use crate::error::ConsensusError;
+use crate::types::HashAlgo;
 use crate::utils::{validate_threshold, validate_timeout};
+use crate::weights::VoterWeights;
 
 pub(crate) const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+pub(crate) const DEFAULT_MAX_VOTES_MULTIPLIER: u32 = 2;
+pub(crate) const DEFAULT_MAX_VOTES_PER_PROPOSAL: usize = 10_000;
+pub(crate) const DEFAULT_MAX_PAYLOAD_BYTES: usize = 64 * 1024;
+pub(crate) const DEFAULT_MAX_PROPOSAL_LIFETIME: Duration = Duration::from_secs(30 * 24 * 60 * 60);
+pub(crate) const DEFAULT_MAX_VOTE_AGE: Duration = Duration::from_secs(60 * 60);
 
 /// Network type determines how rounds and votes are handled.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NetworkType {
     /// Gossipsub network: 2 rounds, multiple votes can be in round 2
     Gossipsub,
@@ -22,22 +30,177 @@ pub enum NetworkType {
     P2P,
 }
 
+/// How a session treats a vote from an owner who already voted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DuplicateVotePolicy {
+    /// Every repeated vote fails with
+    /// [`DuplicateVote`](crate::error::ConsensusError::DuplicateVote).
+    #[default]
+    Reject,
+    /// A vote identical to the one already held (same `vote_hash`) is
+    /// accepted as a no-op, as happens when gossip delivers it over two paths.
+    /// A different vote from the same owner is still rejected.
+    Ignore,
+}
+
+/// How an exact YES/NO tie is decided once every expected voter has voted.
+///
+/// Without one, the proposal's `liveness_criteria_yes` breaks the tie, as
+/// [`TieBreak::from`] maps it: `true` to [`LivenessYes`](Self::LivenessYes),
+/// `false` to [`LivenessNo`](Self::LivenessNo). Ties at timeout with partial
+/// turnout are governed by `resolve_partial_tie_on_timeout` instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TieBreak {
+    /// The tie resolves to YES.
+    LivenessYes,
+    /// The tie resolves to NO.
+    LivenessNo,
+    /// The tie resolves to the proposal owner's vote. If the owner did not
+    /// vote YES or NO, the proposal's liveness criteria decide instead.
+    OwnerVote,
+    /// The session fails as soon as the tie is reached.
+    Fail,
+}
+
+impl From<bool> for TieBreak {
+    fn from(liveness_criteria_yes: bool) -> Self {
+        if liveness_criteria_yes {
+            Self::LivenessYes
+        } else {
+            Self::LivenessNo
+        }
+    }
+}
+
+/// How many YES votes decide a proposal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ThresholdMode {
+    /// A fraction of the expected voters (RFC Section 4): at least
+    /// `ceil(n * fraction)` distinct peers must vote, and a side wins with that
+    /// much weight and strictly more than the other side.
+    Fraction(f64),
+    /// Exactly this many YES votes, whatever the expected voters count: YES
+    /// once `yes_votes >= k`, NO once the remaining peers can no longer
+    /// supply `k` YES votes. Must be between 1 and the expected voters count;
+    /// with [`VoterWeights`], `k` is a YES weight instead.
+    AbsoluteYes(u32),
+}
+
+impl From<f64> for ThresholdMode {
+    fn from(fraction: f64) -> Self {
+        ThresholdMode::Fraction(fraction)
+    }
+}
+
+impl ThresholdMode {
+    /// Validate the mode on its own (fraction range, non-zero count).
+    pub fn validate(&self) -> Result<(), ConsensusError> {
+        match *self {
+            ThresholdMode::Fraction(fraction) => validate_threshold(fraction),
+            ThresholdMode::AbsoluteYes(0) => Err(ConsensusError::InvalidConsensusThreshold),
+            ThresholdMode::AbsoluteYes(_) => Ok(()),
+        }
+    }
+
+    /// Validate the mode for a proposal with `expected_voters_count` voters:
+    /// an absolute count may not exceed it.
+    pub fn validate_for(&self, expected_voters_count: u32) -> Result<(), ConsensusError> {
+        self.validate()?;
+        match *self {
+            ThresholdMode::AbsoluteYes(required) if required > expected_voters_count => {
+                Err(ConsensusError::AbsoluteThresholdExceedsVoters {
+                    required,
+                    expected_voters_count,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
 /// Scope-level configuration that applies to all proposals in a scope.
 ///
 /// This provides default settings for proposals created in a scope.
 /// Individual proposals can override these defaults if needed.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ScopeConfig {
     /// Network type: P2P or Gossipsub
     pub network_type: NetworkType,
     /// Default consensus threshold (e.g., 2/3 = 0.667)
     pub default_consensus_threshold: f64,
+    /// Optional: threshold mode replacing `default_consensus_threshold`, e.g.
+    /// [`ThresholdMode::AbsoluteYes`]. `None` means
+    /// `ThresholdMode::Fraction(default_consensus_threshold)`.
+    pub threshold_mode: Option<ThresholdMode>,
+    /// Per-voter stake weights; consensus sums weights instead of counting
+    /// votes (default: empty, every voter weighs 1)
+    pub voter_weights: VoterWeights,
+    /// Hash function for the votes of proposals created in this scope; the
+    /// choice travels in each proposal's header (default: [`HashAlgo::Sha256`])
+    pub hash_algo: HashAlgo,
     /// Default timeout for proposals in this scope
     pub default_timeout: Duration,
     /// Default liveness criteria (how silent peers are counted)
     pub default_liveness_criteria_yes: bool,
     /// Optional: Max rounds override (if None, uses network_type defaults)
     pub max_rounds_override: Option<u32>,
+    /// Optional: the known participant set for this scope (voter identity bytes).
+    /// Votes from anyone else are rejected with
+    /// [`VoterNotEligible`](ConsensusError::VoterNotEligible), and proposals
+    /// may not expect more voters than the set holds. `None` means membership
+    /// is open/unknown. Sessions share this set instead of copying it.
+    pub allowed_voters: Option<Arc<HashSet<Vec<u8>>>>,
+    /// Resolve a tie at timeout with partial turnout to the liveness default
+    /// instead of failing (default: false)
+    pub resolve_partial_tie_on_timeout: bool,
+    /// Optional: how a YES/NO tie at full turnout is decided. `None` follows
+    /// each proposal's liveness criteria (default: None)
+    pub tie_break: Option<TieBreak>,
+    /// Reject votes carrying a valid but non-canonical (malleated) signature
+    /// (default: true)
+    pub strict_signatures: bool,
+    /// Accept votes from a proposal's owner on their own proposal (default: true)
+    pub allow_owner_vote: bool,
+    /// Cap on stored votes per session, as a multiple of the proposal's
+    /// expected voters count (default: 2)
+    pub max_votes_multiplier: u32,
+    /// Absolute cap on stored votes per session, whatever the proposal's
+    /// expected voters count claims (default: 10_000)
+    pub max_votes_per_proposal: usize,
+    /// Record votes arriving after consensus as late votes instead of
+    /// rejecting them (default: false)
+    pub accept_late_votes: bool,
+    /// Let a voter replace their vote on an active session with a new one
+    /// chained to it (default: false)
+    pub allow_vote_changes: bool,
+    /// How a repeated vote from the same owner is handled
+    /// (default: [`DuplicateVotePolicy::Reject`])
+    pub duplicate_vote_policy: DuplicateVotePolicy,
+    /// Furthest in the future (relative to receipt) an inbound proposal may
+    /// expire (default: 30 days)
+    pub max_proposal_lifetime: Duration,
+    /// Oldest a vote's timestamp may be (relative to receipt) for the vote
+    /// to be accepted (default: 1 hour)
+    pub max_vote_age: Duration,
+    /// Largest proposal payload (in bytes) accepted when creating proposals
+    /// in this scope or receiving them from the network. `None` means no
+    /// limit (default: 64 KiB)
+    pub max_payload_bytes: Option<usize>,
+    /// Optional: most signature verifications an inbound proposal may require
+    /// (its own plus one per embedded vote) before it is rejected unvalidated.
+    /// `None` means no budget.
+    pub max_validation_cost: Option<usize>,
+    /// Optional: oldest creation `timestamp` (relative to receipt) an inbound
+    /// proposal may carry to be recorded. `None` means no limit.
+    pub max_inbound_proposal_age: Option<Duration>,
+    /// Optional: how long a finalized session stays queryable after
+    /// `finalized_at` before `gc_finalized_sessions` may remove it. `None`
+    /// keeps finalized sessions until they are trimmed for capacity.
+    pub session_ttl: Option<Duration>,
 }
 
 impl Default for ScopeConfig {
@@ -45,9 +208,28 @@ impl Default for ScopeConfig {
         Self {
             network_type: NetworkType::Gossipsub,
             default_consensus_threshold: 2.0 / 3.0,
+            threshold_mode: None,
+            voter_weights: VoterWeights::default(),
+            hash_algo: HashAlgo::default(),
             default_timeout: DEFAULT_TIMEOUT,
             default_liveness_criteria_yes: true,
             max_rounds_override: None,
+            allowed_voters: None,
+            resolve_partial_tie_on_timeout: false,
+            tie_break: None,
+            strict_signatures: true,
+            allow_owner_vote: true,
+            max_votes_multiplier: DEFAULT_MAX_VOTES_MULTIPLIER,
+            max_votes_per_proposal: DEFAULT_MAX_VOTES_PER_PROPOSAL,
+            accept_late_votes: false,
+            allow_vote_changes: false,
+            duplicate_vote_policy: DuplicateVotePolicy::Reject,
+            max_proposal_lifetime: DEFAULT_MAX_PROPOSAL_LIFETIME,
+            max_vote_age: DEFAULT_MAX_VOTE_AGE,
+            max_payload_bytes: Some(DEFAULT_MAX_PAYLOAD_BYTES),
+            max_validation_cost: None,
+            max_inbound_proposal_age: None,
+            session_ttl: None,
         }
     }
 }
@@ -56,7 +238,12 @@ impl ScopeConfig {
     /// Validate the configuration
     pub fn validate(&self) -> Result<(), ConsensusError> {
         validate_threshold(self.default_consensus_threshold)?;
+        if let Some(mode) = self.threshold_mode {
+            mode.validate()?;
+        }
         validate_timeout(self.default_timeout)?;
+        validate_timeout(self.max_proposal_lifetime)?;
+        validate_timeout(self.max_vote_age)?;
         // Allow max_rounds_override = Some(0) only for P2P networks (triggers dynamic calculation)
         // For Gossipsub networks, max_rounds_override must be greater than 0
         if let Some(max_rounds) = self.max_rounds_override
@@ -71,21 +258,9 @@ impl ScopeConfig {
 
 impl From<NetworkType> for ScopeConfig {
     fn from(network_type: NetworkType) -> Self {
-        match network_type {
-            NetworkType::Gossipsub => Self {
-                network_type: NetworkType::Gossipsub,
-                default_consensus_threshold: 2.0 / 3.0,
-                default_timeout: DEFAULT_TIMEOUT,
-                default_liveness_criteria_yes: true,
-                max_rounds_override: None,
-            },
-            NetworkType::P2P => Self {
-                network_type: NetworkType::P2P,
-                default_consensus_threshold: 2.0 / 3.0,
-                default_timeout: DEFAULT_TIMEOUT,
-                default_liveness_criteria_yes: true,
-                max_rounds_override: None,
-            },
+        Self {
+            network_type,
+            ..Self::default()
         }
     }
 }
@@ -113,6 +288,24 @@ impl ScopeConfigBuilder {
         self
     }
 
+    /// Replace the fractional threshold with `mode` (e.g. an absolute YES count)
+    pub fn with_threshold_mode(mut self, mode: ThresholdMode) -> Self {
+        self.config.threshold_mode = Some(mode);
+        self
+    }
+
+    /// Weigh each listed voter's vote by their stake
+    pub fn with_voter_weights(mut self, voter_weights: VoterWeights) -> Self {
+        self.config.voter_weights = voter_weights;
+        self
+    }
+
+    /// Hash votes of new proposals with `hash_algo`
+    pub fn with_hash_algo(mut self, hash_algo: HashAlgo) -> Self {
+        self.config.hash_algo = hash_algo;
+        self
+    }
+
     /// Set default timeout for proposals (in seconds)
     pub fn with_timeout(mut self, timeout: Duration) -> Self {
         self.config.default_timeout = timeout;
@@ -131,6 +324,103 @@ impl ScopeConfigBuilder {
         self
     }
 
+    /// Set the known participant set; other voters are rejected (if None, membership is open)
+    pub fn with_allowed_voters(mut self, allowed_voters: Option<HashSet<Vec<u8>>>) -> Self {
+        self.config.allowed_voters = allowed_voters.map(Arc::new);
+        self
+    }
+
+    /// Resolve partial-turnout ties at timeout to the liveness default
+    pub fn with_resolve_partial_tie_on_timeout(mut self, resolve: bool) -> Self {
+        self.config.resolve_partial_tie_on_timeout = resolve;
+        self
+    }
+
+    /// Decide YES/NO ties at full turnout with `tie_break`
+    pub fn with_tie_break(mut self, tie_break: TieBreak) -> Self {
+        self.config.tie_break = Some(tie_break);
+        self
+    }
+
+    /// Reject votes with non-canonical signatures (e.g. high-S ECDSA)
+    pub fn with_strict_signatures(mut self, strict_signatures: bool) -> Self {
+        self.config.strict_signatures = strict_signatures;
+        self
+    }
+
+    /// Allow or forbid proposal owners voting on their own proposals
+    pub fn with_allow_owner_vote(mut self, allow_owner_vote: bool) -> Self {
+        self.config.allow_owner_vote = allow_owner_vote;
+        self
+    }
+
+    /// Cap stored votes per session at a multiple of the expected voters count
+    pub fn with_max_votes_multiplier(mut self, max_votes_multiplier: u32) -> Self {
+        self.config.max_votes_multiplier = max_votes_multiplier;
+        self
+    }
+
+    /// Cap stored votes per session at an absolute count
+    pub fn with_max_votes_per_proposal(mut self, max_votes_per_proposal: usize) -> Self {
+        self.config.max_votes_per_proposal = max_votes_per_proposal;
+        self
+    }
+
+    /// Record (rather than reject) votes arriving after a session is terminal
+    pub fn with_accept_late_votes(mut self, accept_late_votes: bool) -> Self {
+        self.config.accept_late_votes = accept_late_votes;
+        self
+    }
+
+    /// Let voters change their vote while a session is active
+    pub fn with_allow_vote_changes(mut self, allow_vote_changes: bool) -> Self {
+        self.config.allow_vote_changes = allow_vote_changes;
+        self
+    }
+
+    /// Reject votes whose timestamp is older than this when received
+    pub fn with_max_vote_age(mut self, max_vote_age: Duration) -> Self {
+        self.config.max_vote_age = max_vote_age;
+        self
+    }
+
+    /// Choose whether identical repeated votes fail or are ignored
+    pub fn with_duplicate_vote_policy(mut self, policy: DuplicateVotePolicy) -> Self {
+        self.config.duplicate_vote_policy = policy;
+        self
+    }
+
+    /// Reject inbound proposals expiring further than this in the future
+    pub fn with_max_proposal_lifetime(mut self, max_proposal_lifetime: Duration) -> Self {
+        self.config.max_proposal_lifetime = max_proposal_lifetime;
+        self
+    }
+
+    /// Limit the payload size of proposals created or received in this scope;
+    /// `0` lifts the limit
+    pub fn with_max_payload_bytes(mut self, max_payload_bytes: usize) -> Self {
+        self.config.max_payload_bytes = (max_payload_bytes > 0).then_some(max_payload_bytes);
+        self
+    }
+
+    /// Cap the signature verifications an inbound proposal may require
+    pub fn with_max_validation_cost(mut self, max_signature_verifications: usize) -> Self {
+        self.config.max_validation_cost = Some(max_signature_verifications);
+        self
+    }
+
+    /// Refuse inbound proposals created longer ago than `max_age`
+    pub fn with_max_inbound_proposal_age(mut self, max_age: Duration) -> Self {
+        self.config.max_inbound_proposal_age = Some(max_age);
+        self
+    }
+
+    /// Let `gc_finalized_sessions` remove sessions finalized longer ago than `ttl`
+    pub fn with_session_ttl(mut self, ttl: Duration) -> Self {
+        self.config.session_ttl = Some(ttl);
+        self
+    }
+
     /// Start builder from an existing ScopeConfig (useful for partial updates)
     pub fn from_existing(config: ScopeConfig) -> Self {
         Self { config }
@@ -1,23 +1,49 @@
+use std::collections::{HashMap, HashSet};
 use std::marker::PhantomData;
+use std::sync::Arc;
 use std::time::Duration;
 
 use crate::{
+    clock::{Clock, SystemClock},
+    eligibility::{AcceptAllEligibility, EligibilityVerifier},
     error::ConsensusError,
     events::ConsensusEventBus,
-    protos::consensus::v1::{Proposal, Vote},
+    orphan_votes::OrphanVotes,
+    protos::consensus::v1::{Proposal, Vote, consensus_message::Payload},
+    recent_ids::RecentProposalIds,
     scope::ConsensusScope,
-    scope_config::{NetworkType, ScopeConfig, ScopeConfigBuilder},
+    scope_config::{
+        DuplicateVotePolicy, NetworkType, ScopeConfig, ScopeConfigBuilder, ThresholdMode, TieBreak,
+    },
     session::{ConsensusConfig, ConsensusSession, ConsensusState},
     signing::ConsensusSignatureScheme,
-    storage::ConsensusStorage,
-    types::{ConsensusEvent, CreateProposalRequest, SessionTransition},
-    utils::{build_vote, calculate_consensus_result, validate_proposal_timestamp, validate_vote},
+    storage::{ConsensusStorage, ScopeSnapshot},
+    timestamp_policy::{RfcTimestampPolicy, TimestampPolicy},
+    types::{
+        ConsensusCertificate, ConsensusEvent, ConsensusExplanation, ConsensusStatus,
+        CreateProposalRequest, EvictionReason, ExpirySweep, FailReason, HashAlgo, IntegrityIssue,
+        IntegrityReport, ProposalId, QuorumRule, SessionTransition, TimeoutOutcome,
+        VoteApplyResult, VoteChoice,
+    },
+    utils::{
+        VoteChecks, build_vote_from_session, build_vote_from_session_with_eligibility_proof,
+        compute_session_digest, estimate_validation_cost, sign_proposal, validate_embedded_vote,
+        validate_payload_size, validate_vote, validate_vote_chain, validate_vote_chain_extension,
+        verify_proposal_signature,
+    },
+    vote_batches::VoteBatches,
+    weights::VoterWeights,
+    wire::{self, WireScope},
 };
 #[cfg(feature = "ethereum")]
 use crate::{
     events::BroadcastEventBus, scope::ScopeID, signing::EthereumConsensusSigner,
     storage::InMemoryConsensusStorage,
 };
+
+/// Default time an evicted proposal's ID stays reserved.
+const DEFAULT_PROPOSAL_ID_REUSE_WINDOW: Duration = Duration::from_secs(60 * 60);
+
 /// The main service that handles proposals, votes, and consensus.
 ///
 /// This is the main entry point for using the consensus service.
@@ -47,6 +73,19 @@ where
     max_sessions_per_scope: usize,
     event_bus: Event,
     signer: Signer,
+    eligibility_verifier: Arc<dyn EligibilityVerifier>,
+    eligibility_proof: Vec<u8>,
+    timestamp_policy: Arc<dyn TimestampPolicy>,
+    clock: Arc<dyn Clock>,
+    proposal_id_reuse_window: Duration,
+    recent_proposal_ids: Arc<RecentProposalIds<Scope>>,
+    orphan_vote_capacity: usize,
+    orphan_vote_ttl: Duration,
+    orphan_votes: Arc<OrphanVotes<Scope>>,
+    certified_events: bool,
+    vote_events: bool,
+    vote_batch_window: Option<Duration>,
+    vote_batches: Arc<VoteBatches<Scope>>,
     _scope: PhantomData<Scope>,
 }
 
@@ -63,6 +102,19 @@ where
             max_sessions_per_scope: self.max_sessions_per_scope,
             event_bus: self.event_bus.clone(),
             signer: self.signer.clone(),
+            eligibility_verifier: Arc::clone(&self.eligibility_verifier),
+            eligibility_proof: self.eligibility_proof.clone(),
+            timestamp_policy: Arc::clone(&self.timestamp_policy),
+            clock: Arc::clone(&self.clock),
+            proposal_id_reuse_window: self.proposal_id_reuse_window,
+            recent_proposal_ids: Arc::clone(&self.recent_proposal_ids),
+            orphan_vote_capacity: self.orphan_vote_capacity,
+            orphan_vote_ttl: self.orphan_vote_ttl,
+            orphan_votes: Arc::clone(&self.orphan_votes),
+            certified_events: self.certified_events,
+            vote_events: self.vote_events,
+            vote_batch_window: self.vote_batch_window,
+            vote_batches: Arc::clone(&self.vote_batches),
             _scope: PhantomData,
         }
     }
@@ -93,8 +145,8 @@ impl DefaultConsensusService {
     /// Create a service with a custom limit on how many sessions can exist per scope.
     ///
     /// When the limit is reached, older sessions are automatically removed to make room.
-    /// Eviction is silent — no event is emitted. Archive results you need before they
-    /// are evicted.
+    /// Each removal emits [`ConsensusEvent::SessionEvicted`]; archive results you need
+    /// before they are evicted.
     pub fn new_with_max_sessions(
         signer: EthereumConsensusSigner,
         max_sessions_per_scope: usize,
@@ -134,10 +186,127 @@ where
             max_sessions_per_scope,
             event_bus,
             signer,
+            eligibility_verifier: Arc::new(AcceptAllEligibility),
+            eligibility_proof: Vec::new(),
+            timestamp_policy: Arc::new(RfcTimestampPolicy),
+            clock: Arc::new(SystemClock),
+            proposal_id_reuse_window: DEFAULT_PROPOSAL_ID_REUSE_WINDOW,
+            recent_proposal_ids: Arc::new(RecentProposalIds::new()),
+            orphan_vote_capacity: 0,
+            orphan_vote_ttl: Duration::ZERO,
+            orphan_votes: Arc::new(OrphanVotes::new()),
+            certified_events: false,
+            vote_events: false,
+            vote_batch_window: None,
+            vote_batches: Arc::new(VoteBatches::new()),
             _scope: PhantomData,
         }
     }
 
+    /// Replace the verifier consulted for every incoming vote's eligibility proof.
+    ///
+    /// Defaults to [`AcceptAllEligibility`]. Votes the verifier rejects fail with
+    /// [`ConsensusError::InvalidEligibilityProof`].
+    pub fn with_eligibility_verifier(mut self, verifier: impl EligibilityVerifier) -> Self {
+        self.eligibility_verifier = Arc::new(verifier);
+        self
+    }
+
+    /// Attach this peer's eligibility proof to every vote it casts.
+    ///
+    /// Defaults to an empty proof.
+    pub fn with_eligibility_proof(mut self, proof: Vec<u8>) -> Self {
+        self.eligibility_proof = proof;
+        self
+    }
+
+    /// Replace the policy consulted for every incoming vote's timestamp.
+    ///
+    /// Defaults to [`RfcTimestampPolicy`] (RFC Section 3.4). Votes the policy
+    /// rejects fail with the error it returns.
+    pub fn with_timestamp_policy(mut self, policy: impl TimestampPolicy) -> Self {
+        self.timestamp_policy = Arc::new(policy);
+        self
+    }
+
+    /// Replace the time source read by [`now`](Self::now) and the background
+    /// reaper.
+    ///
+    /// Defaults to [`SystemClock`]. Install a [`MockClock`](crate::clock::MockClock)
+    /// in tests to drive expiration without sleeping.
+    pub fn with_clock(mut self, clock: impl Clock) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Set how long the ID of an evicted proposal stays reserved.
+    ///
+    /// Within the window, creating or ingesting a new proposal with that ID
+    /// fails with [`ConsensusError::ProposalIdRecentlyUsed`], so stale votes for
+    /// the evicted proposal cannot attach to it. Defaults to one hour;
+    /// `Duration::ZERO` disables the check. Clones of the service share the
+    /// reserved IDs.
+    pub fn with_proposal_id_reuse_window(mut self, window: Duration) -> Self {
+        self.proposal_id_reuse_window = window;
+        self
+    }
+
+    /// Hold votes that arrive before their proposal instead of rejecting them.
+    ///
+    /// With the buffer enabled, [`process_incoming_vote`](Self::process_incoming_vote)
+    /// keeps a vote for an unknown proposal (up to `capacity` per scope,
+    /// oldest dropped first) and returns `Ok(())`. Once the proposal arrives via
+    /// [`process_incoming_proposal`](Self::process_incoming_proposal), held
+    /// votes younger than `ttl` are validated and applied; invalid ones are
+    /// dropped. Disabled by default (a `capacity` of 0 disables it).
+    pub fn with_orphan_vote_buffer(mut self, capacity: usize, ttl: Duration) -> Self {
+        self.orphan_vote_capacity = capacity;
+        self.orphan_vote_ttl = ttl;
+        self
+    }
+
+    /// Attach a [`ConsensusCertificate`] to every consensus event.
+    ///
+    /// When enabled, [`ConsensusEvent::ConsensusCertified`] is emitted in place
+    /// of [`ConsensusEvent::ConsensusReached`], carrying the deciding votes and
+    /// config so subscribers can build or check certificates without a
+    /// follow-up query that could race eviction. Disabled by default.
+    pub fn with_certified_events(mut self, enabled: bool) -> Self {
+        self.certified_events = enabled;
+        self
+    }
+
+    /// Emit [`ConsensusEvent::VoteAdded`] for every vote that enters a tally.
+    ///
+    /// Covers every path that counts a vote: [`cast_vote`](Self::cast_vote),
+    /// [`recast_vote`](Self::recast_vote),
+    /// [`process_incoming_vote`](Self::process_incoming_vote),
+    /// [`process_incoming_votes`](Self::process_incoming_votes),
+    /// [`merge_proposal`](Self::merge_proposal), and the votes embedded in a
+    /// proposal passed to [`process_incoming_proposal`](Self::process_incoming_proposal).
+    /// Events are published before any outcome event they trigger. Disabled by default so event
+    /// volume does not grow with voting load.
+    pub fn with_vote_events(mut self, enabled: bool) -> Self {
+        self.vote_events = enabled;
+        self
+    }
+
+    /// Coalesce vote events into one [`ConsensusEvent::VotesBatch`] per
+    /// proposal and `window`, instead of a [`VoteAdded`](ConsensusEvent::VoteAdded)
+    /// per vote. Implies [`with_vote_events`](Self::with_vote_events).
+    ///
+    /// A batch opens with the first vote counted after the previous one
+    /// closed, and is published by the first vote counted once `window` has
+    /// passed (measured on the callers' `now`, in whole seconds), by
+    /// [`flush_vote_batches`](Self::flush_vote_batches), or just before the
+    /// proposal's outcome event. Batches of proposals removed before they are
+    /// published are dropped. Clones of the service share pending batches.
+    pub fn with_vote_batching(mut self, window: Duration) -> Self {
+        self.vote_events = true;
+        self.vote_batch_window = Some(window);
+        self
+    }
+
     // ── Accessors ──────────────────────────────────────────────────────
 
     /// Access the underlying storage backend.
@@ -163,6 +332,13 @@ where
         &self.signer
     }
 
+    /// Current time from the service's [`Clock`], in seconds since Unix epoch.
+    ///
+    /// Pass it as `now` when the application does not track time itself.
+    pub fn now(&self) -> u64 {
+        self.clock.now_secs()
+    }
+
     // ── Consensus operations (business logic) ──────────────────────────
 
     /// Create a new proposal and start the voting process.
@@ -179,6 +355,9 @@ where
     /// Configuration is resolved from: proposal config > scope config > global default.
     /// If no config is provided, the scope's default configuration is used.
     ///
+    /// The proposal is signed with this service's signer, so peers only accept
+    /// it if the request's `proposal_owner` is the signer's identity.
+    ///
     /// `now` is the current time in seconds since Unix epoch, supplied by the caller.
     pub fn create_proposal(
         &self,
@@ -192,6 +371,10 @@ where
     /// Create a new proposal with an explicit [`ConsensusConfig`] override.
     ///
     /// Pass `None` to fall back to scope defaults (same as [`create_proposal`](Self::create_proposal)).
+    /// If the generated proposal ID collides with a proposal already
+    /// stored in the scope, nothing is overwritten and
+    /// [`ProposalAlreadyExist`](ConsensusError::ProposalAlreadyExist) is
+    /// returned; retrying generates a fresh ID.
     pub fn create_proposal_with_config(
         &self,
         scope: &Scope,
@@ -199,43 +382,216 @@ where
         config: Option<ConsensusConfig>,
         now: u64,
     ) -> Result<Proposal, ConsensusError> {
-        let proposal = request.into_proposal(now)?;
-        let config = self.resolve_config(scope, config, Some(&proposal))?;
-        let (session, _) =
-            ConsensusSession::from_proposal::<Signer>(proposal.clone(), config.clone(), now)?;
-        self.save_session(scope, session)?;
-        self.trim_scope_sessions(scope)?;
+        let session = self.prepare_session(scope, request, config, now)?;
+        let proposal = session.proposal.clone();
+        if !self.storage.insert_session_if_absent(scope, session)? {
+            return Err(ConsensusError::ProposalAlreadyExist);
+        }
+        self.trim_scope_sessions(scope, now)?;
         Ok(proposal)
     }
 
+    /// Run every check [`create_proposal`](Self::create_proposal) would run
+    /// for `request` in `scope`, without storing anything.
+    ///
+    /// Useful for validating user input before committing to a proposal.
+    pub fn validate_create(
+        &self,
+        scope: &Scope,
+        request: &CreateProposalRequest,
+        now: u64,
+    ) -> Result<(), ConsensusError> {
+        self.prepare_session(scope, request.clone(), None, now)
+            .map(|_| ())
+    }
+
+    /// Build the session for a locally created proposal, applying scope limits.
+    fn prepare_session(
+        &self,
+        scope: &Scope,
+        request: CreateProposalRequest,
+        config: Option<ConsensusConfig>,
+        now: u64,
+    ) -> Result<ConsensusSession, ConsensusError> {
+        let scope_config = self.storage.get_scope_config(scope)?.unwrap_or_default();
+        validate_payload_size(request.payload.len(), scope_config.max_payload_bytes)?;
+        let mut proposal = request.into_proposal(now)?;
+        self.ensure_proposal_id_not_recent(scope, proposal.proposal_id, now)?;
+        let config = self.resolve_config(scope, config, Some(&proposal))?;
+        proposal.hash_algo = config.hash_algo().into();
+        sign_proposal(&mut proposal, &self.signer)?;
+        let (session, _) = ConsensusSession::from_proposal_with_policies::<Signer>(
+            proposal,
+            config,
+            now,
+            self.eligibility_verifier.as_ref(),
+            self.timestamp_policy.as_ref(),
+        )?;
+        Ok(session)
+    }
+
     /// Cast a vote on an active proposal using this service's held signer.
     ///
     /// The vote is cryptographically signed and linked into the hashgraph
     /// chain. Returns the signed [`Vote`] for network propagation. Each peer
-    /// (identity) can only vote once per proposal.
+    /// (identity) can only vote once per proposal. Voting on a session that
+    /// already reached consensus or failed returns
+    /// [`SessionTerminal`](ConsensusError::SessionTerminal), unless the scope
+    /// accepts late votes, in which case the vote is recorded as late.
+    ///
+    /// `choice` is a `bool` (YES/NO) or a [`VoteChoice`]; pass
+    /// [`VoteChoice::Abstain`] to take part without backing either side.
     pub fn cast_vote(
         &self,
         scope: &Scope,
-        proposal_id: u32,
-        choice: bool,
+        proposal_id: impl Into<ProposalId>,
+        choice: impl Into<VoteChoice>,
         now: u64,
     ) -> Result<Vote, ConsensusError> {
+        let proposal_id = proposal_id.into().0;
         let session = self.get_session(scope, proposal_id)?;
-        validate_proposal_timestamp(session.proposal.expiration_timestamp, now)?;
+        session.precheck_vote(self.signer.identity(), now)?;
 
-        if session.votes.contains_key(self.signer.identity()) {
-            return Err(ConsensusError::UserAlreadyVoted);
-        }
+        let vote = build_vote_from_session_with_eligibility_proof(
+            &session,
+            choice,
+            &self.signer,
+            self.eligibility_proof.clone(),
+            now,
+        )?;
+        let mut added = Vec::new();
+        let (transition, certificate) =
+            self.update_session_certified(scope, proposal_id, |session| {
+                add_counted_vote(session, vote.clone(), now, &mut added)
+            })?;
+        self.emit_vote_events(scope, added);
+        self.handle_transition(scope, proposal_id, transition, certificate, now)?;
+        Ok(vote)
+    }
 
-        let vote = build_vote(&session.proposal, choice, &self.signer, now)?;
-        let vote_clone = vote.clone();
-        let transition = self.update_session(scope, proposal_id, move |session| {
-            session.add_vote(vote_clone, now)
-        })?;
-        self.handle_transition(scope, proposal_id, transition, now);
+    /// Replace `signer`'s vote on an active proposal with `choice` and
+    /// re-check consensus.
+    ///
+    /// Only allowed in scopes configured with
+    /// [`with_allow_vote_changes`](crate::scope_config::ScopeConfigBuilder::with_allow_vote_changes);
+    /// otherwise fails with [`UserAlreadyVoted`](ConsensusError::UserAlreadyVoted)
+    /// like a second [`cast_vote`](Self::cast_vote). Returns
+    /// [`VoteNotFound`](ConsensusError::VoteNotFound) if `signer` has not voted.
+    /// The new vote names the replaced one as its `parent_hash`, so it can be
+    /// gossiped and applied by peers like any other vote.
+    pub fn recast_vote(
+        &self,
+        scope: &Scope,
+        proposal_id: impl Into<ProposalId>,
+        choice: impl Into<VoteChoice>,
+        signer: &Signer,
+        now: u64,
+    ) -> Result<Vote, ConsensusError> {
+        let proposal_id = proposal_id.into().0;
+        let session = self.get_session(scope, proposal_id)?;
+        let previous = session
+            .votes()
+            .get(signer.identity())
+            .ok_or(ConsensusError::VoteNotFound)?;
+        let vote = build_vote_from_session_with_eligibility_proof(
+            &session,
+            choice,
+            signer,
+            previous.eligibility_proof.clone(),
+            now,
+        )?;
+        let mut added = Vec::new();
+        let (transition, certificate) =
+            self.update_session_certified(scope, proposal_id, |session| {
+                let transition = session.replace_vote(vote.clone(), now)?;
+                added.push(vote_added_event(&vote, session.proposal.round, now));
+                Ok(transition)
+            })?;
+        self.emit_vote_events(scope, added);
+        self.handle_transition(scope, proposal_id, transition, certificate, now)?;
         Ok(vote)
     }
 
+    /// Retract `signer`'s vote on an active proposal and re-check consensus.
+    ///
+    /// Only the latest vote in the proposal can be retracted; a vote that a
+    /// later vote chains to via `received_hash` is rejected with
+    /// [`VoteReferenced`](ConsensusError::VoteReferenced), so the stored chain
+    /// stays valid. Returns [`VoteNotFound`](ConsensusError::VoteNotFound) if
+    /// `signer` has not voted. Retraction is local: peers that already
+    /// received the vote keep it.
+    pub fn retract_vote(
+        &self,
+        scope: &Scope,
+        proposal_id: impl Into<ProposalId>,
+        signer: &Signer,
+        now: u64,
+    ) -> Result<(), ConsensusError> {
+        let proposal_id = proposal_id.into().0;
+        let (transition, certificate) =
+            self.update_session_certified(scope, proposal_id, |session| {
+                session.retract_vote(signer.identity(), now)
+            })?;
+        self.handle_transition(scope, proposal_id, transition, certificate, now)?;
+        Ok(())
+    }
+
+    /// Check whether a vote from `voter` (identity bytes) would be accepted at
+    /// `now`, without building, signing, or storing anything.
+    ///
+    /// Runs the same pre-checks as [`cast_vote`](Self::cast_vote): the session
+    /// must still accept votes and not be expired, `voter` must not have voted
+    /// ([`UserAlreadyVoted`](ConsensusError::UserAlreadyVoted)), owner votes
+    /// must be allowed, and the session must have room for another vote.
+    pub fn can_vote(
+        &self,
+        scope: &Scope,
+        proposal_id: impl Into<ProposalId>,
+        voter: &[u8],
+        now: u64,
+    ) -> Result<(), ConsensusError> {
+        self.get_session(scope, proposal_id.into().0)?
+            .precheck_vote(voter, now)
+    }
+
+    /// Simulate `signers` each voting `choice`, in order, on a copy of the
+    /// session, and report whether that would finalize the proposal.
+    ///
+    /// Votes are built, signed, and validated exactly as remote votes would
+    /// be, but nothing is stored and no event is emitted. Returns
+    /// [`ConsensusReached`](SessionTransition::ConsensusReached) as soon as a
+    /// vote decides the proposal (later signers are not used),
+    /// [`Failed`](SessionTransition::Failed) if a vote would fail it, or
+    /// [`StillActive`](SessionTransition::StillActive) if all of them would
+    /// not. Any vote the real session would refuse (e.g. a signer that already
+    /// voted) fails the simulation with that error.
+    pub fn simulate_finalize_with(
+        &self,
+        scope: &Scope,
+        proposal_id: impl Into<ProposalId>,
+        signers: &[Signer],
+        choice: impl Into<VoteChoice>,
+        now: u64,
+    ) -> Result<SessionTransition, ConsensusError> {
+        let choice = choice.into();
+        let mut session = self.get_session(scope, proposal_id.into().0)?;
+        session.ensure_active()?;
+        session.set_finalization_paused(self.storage.is_scope_paused(scope)?);
+        let checks = self.vote_checks(&session.config);
+        for signer in signers {
+            let vote = build_vote_from_session(&session, choice, signer, now)?;
+            validate_vote::<Signer>(&vote, &session.proposal, now, &checks)?;
+            let transition = session.add_vote(vote, now)?;
+            if matches!(
+                transition,
+                SessionTransition::ConsensusReached(_) | SessionTransition::Failed(_)
+            ) {
+                return Ok(transition);
+            }
+        }
+        Ok(SessionTransition::StillActive)
+    }
+
     /// Cast a vote and return the updated [`Proposal`] (with the new vote included).
     ///
     /// Convenience method useful for the proposal creator who wants to immediately
@@ -243,12 +599,13 @@ where
     pub fn cast_vote_and_get_proposal(
         &self,
         scope: &Scope,
-        proposal_id: u32,
-        choice: bool,
+        proposal_id: impl Into<ProposalId>,
+        choice: impl Into<VoteChoice>,
         now: u64,
     ) -> Result<Proposal, ConsensusError> {
+        let proposal_id = proposal_id.into();
         self.cast_vote(scope, proposal_id, choice, now)?;
-        let session = self.get_session(scope, proposal_id)?;
+        let session = self.get_session(scope, proposal_id.0)?;
         Ok(session.proposal)
     }
 
@@ -260,21 +617,151 @@ where
     ///
     /// Validates the proposal and all embedded votes, then stores it locally.
     /// If enough votes are already present, consensus is reached immediately.
+    /// Proposals whose `proposal_signature` was not produced by their
+    /// `proposal_owner` are rejected with
+    /// [`InvalidProposalSignature`](ConsensusError::InvalidProposalSignature).
+    ///
+    /// If the proposal is already known (including when a concurrent copy was
+    /// stored first), embedded votes not yet recorded are merged into the
+    /// existing session instead. Returns
+    /// [`ProposalAlreadyExist`](ConsensusError::ProposalAlreadyExist) when the
+    /// copy carries no new votes.
+    ///
+    /// If the scope sets a [`max_validation_cost`](ScopeConfig::max_validation_cost),
+    /// proposals whose [`estimate_validation_cost`] exceeds it are rejected with
+    /// [`TooManyVotes`](ConsensusError::TooManyVotes) before any signature is checked.
+    /// Likewise, with a [`max_inbound_proposal_age`](ScopeConfig::max_inbound_proposal_age),
+    /// proposals created longer ago are refused with
+    /// [`ProposalTooOld`](ConsensusError::ProposalTooOld), and payloads over the
+    /// scope's [`max_payload_bytes`](ScopeConfig::max_payload_bytes) with
+    /// [`PayloadTooLarge`](ConsensusError::PayloadTooLarge).
     pub fn process_incoming_proposal(
         &self,
         scope: &Scope,
         proposal: Proposal,
         now: u64,
     ) -> Result<(), ConsensusError> {
+        let scope_config = self.storage.get_scope_config(scope)?.unwrap_or_default();
+        validate_payload_size(proposal.payload.len(), scope_config.max_payload_bytes)?;
+        if let Some(max_age) = scope_config.max_inbound_proposal_age {
+            let age_secs = now.saturating_sub(proposal.timestamp);
+            if age_secs > max_age.as_secs() {
+                return Err(ConsensusError::ProposalTooOld {
+                    age_secs,
+                    max_age_secs: max_age.as_secs(),
+                });
+            }
+        }
+        if let Some(budget) = scope_config.max_validation_cost
+            && estimate_validation_cost(&proposal).signature_verifications > budget
+        {
+            return Err(ConsensusError::TooManyVotes { max_votes: budget });
+        }
+        verify_proposal_signature::<Signer>(&proposal)?;
         if self.get_session(scope, proposal.proposal_id).is_ok() {
-            return Err(ConsensusError::ProposalAlreadyExist);
+            return self.merge_incoming_proposal(scope, proposal, now);
         }
+        self.ensure_proposal_id_not_recent(scope, proposal.proposal_id, now)?;
         let config = self.resolve_config(scope, None, Some(&proposal))?;
-        let (session, transition) =
-            ConsensusSession::from_proposal::<Signer>(proposal, config, now)?;
-        self.handle_transition(scope, session.proposal.proposal_id, transition, now);
-        self.save_session(scope, session)?;
-        self.trim_scope_sessions(scope)?;
+        let (session, transition) = ConsensusSession::from_proposal_in_scope::<Signer>(
+            proposal.clone(),
+            config,
+            now,
+            self.eligibility_verifier.as_ref(),
+            self.timestamp_policy.as_ref(),
+            self.storage.is_scope_paused(scope)?,
+        )?;
+        let proposal_id = session.proposal.proposal_id;
+        let certificate = self.certify_transition(&session, transition);
+        let added = if self.vote_events {
+            embedded_vote_events(&session, now)
+        } else {
+            Vec::new()
+        };
+        if !self.storage.insert_session_if_absent(scope, session)? {
+            // Lost the race against another copy of this proposal: merge into it once.
+            return self.merge_incoming_proposal(scope, proposal, now);
+        }
+        self.emit_vote_events(scope, added);
+        self.handle_transition(scope, proposal_id, transition, certificate, now)?;
+        self.apply_orphan_votes(scope, proposal_id, now);
+        self.trim_scope_sessions(scope, now)?;
+        Ok(())
+    }
+
+    /// Apply votes held for `proposal_id` before the proposal arrived.
+    fn apply_orphan_votes(&self, scope: &Scope, proposal_id: u64, now: u64) {
+        if self.orphan_vote_capacity == 0 {
+            return;
+        }
+        let ttl = self.orphan_vote_ttl.as_secs();
+        for vote in self.orphan_votes.take(scope, proposal_id, now, ttl) {
+            // Invalid votes, and duplicates of votes embedded in the proposal,
+            // are dropped: the sender never learned they were accepted.
+            let _ = self.process_incoming_vote(scope, vote, now);
+        }
+    }
+
+    /// Merge the embedded votes of an already-known proposal into its session.
+    ///
+    /// Votes the session already holds, or that repeat a held voter's choice,
+    /// are skipped. The rest are validated as embedded votes (including their
+    /// `proposal_id`) and applied to a staged copy, so nothing is stored
+    /// unless every one is accepted. A vote contradicting a held one fails
+    /// with [`Equivocation`](ConsensusError::Equivocation) and is reported as
+    /// [`EquivocationDetected`](ConsensusEvent::EquivocationDetected). Votes
+    /// arriving after the merge reaches consensus are recorded as late votes if
+    /// the session accepts them, and dropped otherwise.
+    fn merge_incoming_proposal(
+        &self,
+        scope: &Scope,
+        proposal: Proposal,
+        now: u64,
+    ) -> Result<(), ConsensusError> {
+        let proposal_id = proposal.proposal_id;
+        let mut added = Vec::new();
+        let outcome = self.update_session_certified(scope, proposal_id, |session| {
+            let fresh: Vec<Vote> = proposal
+                .votes
+                .iter()
+                .filter(|vote| {
+                    session
+                        .held_vote(&vote.vote_owner)
+                        .is_none_or(|held| held.choice() != vote.choice())
+                })
+                .cloned()
+                .collect();
+            if fresh.is_empty() {
+                return Err(ConsensusError::ProposalAlreadyExist);
+            }
+            session.ensure_accepting_votes()?;
+
+            let checks = self.vote_checks(&session.config);
+            for vote in &fresh {
+                validate_embedded_vote::<Signer>(&session.proposal, vote, now, &checks)?;
+            }
+
+            let mut staged = session.clone();
+            let mut transition = SessionTransition::StillActive;
+            for vote in fresh {
+                if !staged.accepts_votes() {
+                    break;
+                }
+                let step = add_counted_vote(&mut staged, vote, now, &mut added)?;
+                if matches!(
+                    step,
+                    SessionTransition::ConsensusReached(_) | SessionTransition::Failed(_)
+                ) {
+                    transition = step;
+                }
+            }
+            *session = staged;
+            Ok(transition)
+        });
+        let (transition, certificate) = outcome
+            .inspect_err(|err| self.report_equivocation(scope, err, &proposal.votes, now))?;
+        self.emit_vote_events(scope, added);
+        self.handle_transition(scope, proposal_id, transition, certificate, now)?;
         Ok(())
     }
 
@@ -282,28 +769,331 @@ where
     ///
     /// Call this when your networking layer delivers a vote from another peer.
     /// Validates the vote (signature, timestamp, chain) and adds it to the
-    /// corresponding proposal session. May trigger consensus.
+    /// corresponding proposal session. May trigger consensus. Votes for a
+    /// session that already reached consensus or failed are rejected with
+    /// [`SessionTerminal`](ConsensusError::SessionTerminal), or recorded as
+    /// late votes if the scope accepts them. Votes for an unknown proposal fail
+    /// with [`SessionNotFound`](ConsensusError::SessionNotFound) unless the
+    /// orphan vote buffer is enabled (see
+    /// [`with_orphan_vote_buffer`](Self::with_orphan_vote_buffer)).
     pub fn process_incoming_vote(
         &self,
         scope: &Scope,
         vote: Vote,
         now: u64,
     ) -> Result<(), ConsensusError> {
-        let session = self.get_session(scope, vote.proposal_id)?;
+        let session = match self.get_session(scope, vote.proposal_id) {
+            Err(ConsensusError::SessionNotFound) if self.orphan_vote_capacity > 0 => {
+                self.orphan_votes
+                    .hold(scope, vote, now, self.orphan_vote_capacity);
+                return Ok(());
+            }
+            session => session?,
+        };
+        session.ensure_accepting_votes()?;
         validate_vote::<Signer>(
             &vote,
-            session.proposal.expiration_timestamp,
-            session.proposal.timestamp,
+            &session.proposal,
             now,
+            &self.vote_checks(&session.config),
         )?;
         let proposal_id = vote.proposal_id;
-        let transition = self.update_session(scope, proposal_id, move |session| {
-            session.add_vote(vote, now)
-        })?;
-        self.handle_transition(scope, proposal_id, transition, now);
+        let mut added = Vec::new();
+        let (transition, certificate) = self
+            .update_session_certified(scope, proposal_id, |session| {
+                add_counted_vote(session, vote.clone(), now, &mut added)
+            })
+            .inspect_err(|err| {
+                self.report_equivocation(scope, err, std::slice::from_ref(&vote), now)
+            })?;
+        self.emit_vote_events(scope, added);
+        self.handle_transition(scope, proposal_id, transition, certificate, now)?;
         Ok(())
     }
 
+    /// Process several votes for one proposal, received together from the
+    /// network, as a single atomic step.
+    ///
+    /// Every vote is validated against the session like in
+    /// [`process_incoming_vote`](Self::process_incoming_vote), and the batch
+    /// must extend the hash chain of the session's votes. The votes are
+    /// then applied in order under one storage write. If any vote is invalid
+    /// (including one for another proposal, which fails with
+    /// [`VoteProposalIdMismatch`](ConsensusError::VoteProposalIdMismatch)),
+    /// the whole batch is rejected and the session is left untouched. Votes
+    /// after the one that decides the proposal are kept as late votes if the
+    /// session accepts them, and dropped otherwise.
+    ///
+    /// Returns [`ConsensusReached`](SessionTransition::ConsensusReached) if the
+    /// batch decided the proposal, and otherwise the transition of its last
+    /// vote.
+    pub fn process_incoming_votes(
+        &self,
+        scope: &Scope,
+        proposal_id: impl Into<ProposalId>,
+        votes: Vec<Vote>,
+        now: u64,
+    ) -> Result<SessionTransition, ConsensusError> {
+        let proposal_id = proposal_id.into().0;
+        let mut added = Vec::new();
+        let outcome = self.update_session_certified(scope, proposal_id, |session| {
+            session.ensure_accepting_votes()?;
+            let checks = self.vote_checks(&session.config);
+            for vote in &votes {
+                if vote.proposal_id != proposal_id {
+                    return Err(ConsensusError::VoteProposalIdMismatch);
+                }
+                validate_vote::<Signer>(vote, &session.proposal, now, &checks)?;
+            }
+            validate_vote_chain_extension(&session.proposal.votes, &votes)?;
+
+            let mut staged = session.clone();
+            let mut transition = SessionTransition::StillActive;
+            for vote in &votes {
+                if !staged.accepts_votes() {
+                    break;
+                }
+                let step = add_counted_vote(&mut staged, vote.clone(), now, &mut added)?;
+                if !matches!(
+                    transition,
+                    SessionTransition::ConsensusReached(_) | SessionTransition::Failed(_)
+                ) {
+                    transition = step;
+                }
+            }
+            *session = staged;
+            Ok(transition)
+        });
+        let (transition, certificate) =
+            outcome.inspect_err(|err| self.report_equivocation(scope, err, &votes, now))?;
+        self.emit_vote_events(scope, added);
+        self.handle_transition(scope, proposal_id, transition, certificate, now)?;
+        Ok(transition)
+    }
+
+    /// Sorted `vote_id`s of the votes held in a proposal, for reconciliation.
+    ///
+    /// A peer sends this digest and receives only the votes it lacks from
+    /// [`collect_votes_not_in`](Self::collect_votes_not_in), instead of the full
+    /// proposal on every gossip round.
+    pub fn vote_digest(
+        &self,
+        scope: &Scope,
+        proposal_id: impl Into<ProposalId>,
+    ) -> Result<Vec<u64>, ConsensusError> {
+        let session = self.get_session(scope, proposal_id.into().0)?;
+        let mut ids: Vec<u64> = session.proposal.votes.iter().map(|v| v.vote_id).collect();
+        ids.sort_unstable();
+        Ok(ids)
+    }
+
+    /// Votes held in a proposal whose `vote_id` is absent from `known` (a
+    /// peer's [`vote_digest`](Self::vote_digest)), in the proposal's chain
+    /// order so they can be fed to
+    /// [`process_incoming_votes`](Self::process_incoming_votes).
+    pub fn collect_votes_not_in(
+        &self,
+        scope: &Scope,
+        proposal_id: impl Into<ProposalId>,
+        known: &[u64],
+    ) -> Result<Vec<Vote>, ConsensusError> {
+        let known: HashSet<u64> = known.iter().copied().collect();
+        let session = self.get_session(scope, proposal_id.into().0)?;
+        Ok(session
+            .proposal
+            .votes
+            .into_iter()
+            .filter(|vote| !known.contains(&vote.vote_id))
+            .collect())
+    }
+
+    /// Digest of a proposal's session state, for checking that two peers agree.
+    ///
+    /// See [`compute_session_digest`]: equal for peers that processed the same
+    /// votes in any order.
+    pub fn session_state_digest(
+        &self,
+        scope: &Scope,
+        proposal_id: impl Into<ProposalId>,
+    ) -> Result<[u8; 32], ConsensusError> {
+        Ok(compute_session_digest(
+            &self.get_session(scope, proposal_id.into().0)?,
+        ))
+    }
+
+    /// Merge a peer's copy of a known proposal into the local session.
+    ///
+    /// Meant for anti-entropy after a partition, when both peers hold the same
+    /// proposal with different subsets of votes. The remote proposal must be
+    /// signed by its owner and match the local header (every field the owner
+    /// signs but the expiration), else
+    /// [`ProposalHeaderMismatch`](ConsensusError::ProposalHeaderMismatch).
+    /// The remote hash chain is validated as received, so each remote vote's
+    /// `received_hash` is checked against the branch it was cast on. Remote
+    /// votes the session lacks (by `vote_hash`) are then validated like
+    /// embedded votes (including their `proposal_id`) and appended in
+    /// timestamp order, so a conflicting vote from an owner already held fails
+    /// with [`Equivocation`](ConsensusError::Equivocation). Every appended
+    /// vote follows the vote its `received_hash` names, so when both sides
+    /// voted during the partition the result joins two branches that
+    /// [`process_incoming_proposal`](Self::process_incoming_proposal) and
+    /// [`verify_scope_integrity`](Self::verify_scope_integrity) accept on any
+    /// peer. The round advances with each new voter. A later expiration on an active session is adopted
+    /// along with the owner's signature over it (see
+    /// [`extend_expiration`](Self::extend_expiration)), bounded by the scope's
+    /// `max_proposal_lifetime` from `now`; an earlier one is ignored. Nothing
+    /// is applied unless the whole merge succeeds.
+    ///
+    /// Returns [`ConsensusReached`](SessionTransition::ConsensusReached) if the
+    /// merge decided the proposal, and [`StillActive`](SessionTransition::StillActive)
+    /// if the remote held no new votes.
+    pub fn merge_proposal(
+        &self,
+        scope: &Scope,
+        remote: Proposal,
+        now: u64,
+    ) -> Result<SessionTransition, ConsensusError> {
+        verify_proposal_signature::<Signer>(&remote)?;
+        validate_vote_chain(&remote.votes)?;
+        let proposal_id = remote.proposal_id;
+        let mut added = Vec::new();
+        let outcome = self.update_session_certified(scope, proposal_id, |session| {
+            let local = &session.proposal;
+            // The expiration may differ: an owner extension is adopted below.
+            if local.name != remote.name
+                || local.payload != remote.payload
+                || local.proposal_owner != remote.proposal_owner
+                || local.expected_voters_count != remote.expected_voters_count
+                || local.timestamp != remote.timestamp
+                || local.liveness_criteria_yes != remote.liveness_criteria_yes
+                || local.hash_algo != remote.hash_algo
+            {
+                return Err(ConsensusError::ProposalHeaderMismatch);
+            }
+            let held: HashSet<&[u8]> = local
+                .votes
+                .iter()
+                .map(|vote| vote.vote_hash.as_slice())
+                .collect();
+            let mut fresh: Vec<Vote> = remote
+                .votes
+                .iter()
+                .filter(|vote| !held.contains(vote.vote_hash.as_slice()))
+                .cloned()
+                .collect();
+            session.ensure_accepting_votes()?;
+
+            let mut staged = session.clone();
+            if staged.is_active()
+                && remote.expiration_timestamp > staged.proposal.expiration_timestamp
+            {
+                // The owner extended the proposal; its signature, verified
+                // above, covers the new expiration.
+                let max_lifetime = staged.config.max_proposal_lifetime().as_secs();
+                if remote.expiration_timestamp > now.saturating_add(max_lifetime) {
+                    return Err(ConsensusError::InvalidTimeout);
+                }
+                staged.proposal.expiration_timestamp = remote.expiration_timestamp;
+                staged.proposal.proposal_signature = remote.proposal_signature.clone();
+            }
+            if fresh.is_empty() {
+                *session = staged;
+                return Ok(SessionTransition::StillActive);
+            }
+
+            let checks = self.vote_checks(&staged.config);
+            for vote in &fresh {
+                validate_embedded_vote::<Signer>(&staged.proposal, vote, now, &checks)?;
+            }
+            fresh.sort_by_key(|vote| vote.timestamp);
+
+            let mut transition = SessionTransition::StillActive;
+            for vote in fresh {
+                if !staged.accepts_votes() {
+                    break;
+                }
+                let step = add_counted_vote(&mut staged, vote, now, &mut added)?;
+                if !matches!(
+                    transition,
+                    SessionTransition::ConsensusReached(_) | SessionTransition::Failed(_)
+                ) {
+                    transition = step;
+                }
+            }
+            *session = staged;
+            Ok(transition)
+        });
+        let (transition, certificate) =
+            outcome.inspect_err(|err| self.report_equivocation(scope, err, &remote.votes, now))?;
+        self.emit_vote_events(scope, added);
+        self.handle_transition(scope, proposal_id, transition, certificate, now)?;
+        Ok(transition)
+    }
+
+    /// Apply a batch of votes for one proposal and report how each fared, plus
+    /// the proposal's status afterwards.
+    ///
+    /// Meant for anti-entropy loops that repeatedly fold in vote deltas. Each
+    /// vote goes through [`process_incoming_vote`](Self::process_incoming_vote);
+    /// votes the session already holds are reported as
+    /// [`Duplicate`](VoteApplyResult::Duplicate) without being reprocessed, and
+    /// votes for another proposal are rejected with
+    /// [`VoteProposalIdMismatch`](ConsensusError::VoteProposalIdMismatch).
+    /// Fails only if the proposal is unknown.
+    pub fn apply_votes_and_status(
+        &self,
+        scope: &Scope,
+        proposal_id: impl Into<ProposalId>,
+        votes: Vec<Vote>,
+        now: u64,
+    ) -> Result<(Vec<VoteApplyResult>, ConsensusStatus), ConsensusError> {
+        let proposal_id = proposal_id.into().0;
+        let mut session = self.get_session(scope, proposal_id)?;
+        let mut results = Vec::with_capacity(votes.len());
+        for vote in votes {
+            if vote.proposal_id != proposal_id {
+                results.push(VoteApplyResult::Rejected(
+                    ConsensusError::VoteProposalIdMismatch,
+                ));
+                continue;
+            }
+            let held = session.held_vote(&vote.vote_owner);
+            if held.is_some_and(|held| held.vote_hash == vote.vote_hash) {
+                results.push(VoteApplyResult::Duplicate);
+                continue;
+            }
+            results.push(match self.process_incoming_vote(scope, vote, now) {
+                Ok(()) => VoteApplyResult::Accepted,
+                Err(err) => VoteApplyResult::Rejected(err),
+            });
+            session = self.get_session(scope, proposal_id)?;
+        }
+        let status = ConsensusStatus {
+            state: session.state,
+            distinct_voters: session.distinct_voters(),
+            expected_voters: session.proposal.expected_voters_count,
+        };
+        Ok((results, status))
+    }
+
+    /// Process a [`wire`](crate::wire) envelope received from the network.
+    ///
+    /// Decodes the envelope and dispatches its payload to
+    /// [`process_incoming_proposal`](Self::process_incoming_proposal) or
+    /// [`process_incoming_vote`](Self::process_incoming_vote) under the scope it
+    /// carries. Returns that scope so the caller can route follow-up work.
+    pub fn process_incoming_message(&self, bytes: &[u8], now: u64) -> Result<Scope, ConsensusError>
+    where
+        Scope: WireScope,
+    {
+        let (scope, payload) = wire::decode::<Scope>(bytes)?;
+        match payload {
+            Payload::Proposal(proposal) => self.process_incoming_proposal(&scope, proposal, now)?,
+            Payload::Vote(vote) => self.process_incoming_vote(&scope, vote, now)?,
+        }
+        Ok(scope)
+    }
+
     /// Handle the timeout for a proposal.
     ///
     /// **The library does not call this automatically.** Your application MUST
@@ -317,63 +1107,500 @@ where
     /// - `liveness_criteria_yes = true` — silent peers count as YES
     /// - `liveness_criteria_yes = false` — silent peers count as NO
     ///
-    /// Returns the consensus result if determinable, or
-    /// [`InsufficientVotesAtTimeout`](ConsensusError::InsufficientVotesAtTimeout)
-    /// if the result is a tie after counting silent peers.
+    /// Returns a [`TimeoutOutcome`] distinguishing a fresh timeout-driven
+    /// decision ([`Reached`](TimeoutOutcome::Reached)), a tie that could not be
+    /// resolved ([`Failed`](TimeoutOutcome::Failed)), and a proposal that had
+    /// already reached consensus ([`AlreadyReached`](TimeoutOutcome::AlreadyReached)).
+    /// A session that already failed returns `Failed` again without emitting
+    /// another event, so repeated timeouts never double-finalize.
+    /// `Err` is reserved for genuine errors such as an unknown session, or
+    /// [`ScopePaused`](ConsensusError::ScopePaused) while the scope's
+    /// finalization is paused (the timeout then runs on resume).
     pub fn handle_consensus_timeout(
         &self,
         scope: &Scope,
-        proposal_id: u32,
+        proposal_id: impl Into<ProposalId>,
         now: u64,
-    ) -> Result<bool, ConsensusError> {
-        let timeout_result: Result<Option<bool>, ConsensusError> =
+    ) -> Result<TimeoutOutcome, ConsensusError> {
+        let proposal_id = proposal_id.into().0;
+        let (outcome, failure_reason, certificate) =
             self.update_session(scope, proposal_id, |session| {
-                if let ConsensusState::ConsensusReached(result) = session.state {
-                    return Ok(Some(result));
+                match session.state {
+                    ConsensusState::ConsensusReached(result) => {
+                        return Ok((TimeoutOutcome::AlreadyReached(result), None, None));
+                    }
+                    ConsensusState::Failed => return Ok((TimeoutOutcome::Failed, None, None)),
+                    ConsensusState::Active => {}
+                }
+                if session.finalization_paused() {
+                    return Err(ConsensusError::ScopePaused);
                 }
-                let result = calculate_consensus_result(
-                    &session.votes,
-                    session.proposal.expected_voters_count,
-                    session.config.consensus_threshold(),
-                    session.proposal.liveness_criteria_yes,
-                    true,
-                );
-                if let Some(result) = result {
+                if let Some(result) = session.timeout_result() {
                     session.state = ConsensusState::ConsensusReached(result);
-                    Ok(Some(result))
+                    session.finalized_at = Some(now);
+                    Ok((
+                        TimeoutOutcome::Reached(result),
+                        None,
+                        self.certify(session, true),
+                    ))
                 } else {
+                    let reason = timeout_failure_reason(&session.explain());
                     session.state = ConsensusState::Failed;
-                    Ok(None)
+                    session.finalized_at = Some(now);
+                    Ok((TimeoutOutcome::Failed, Some(reason), None))
+                }
+            })?;
+
+        match outcome {
+            TimeoutOutcome::Reached(result) => {
+                self.emit_reached(scope, proposal_id, result, certificate, now)
+            }
+            TimeoutOutcome::Failed => {
+                if let Some(reason) = failure_reason {
+                    self.emit_event(
+                        scope,
+                        ConsensusEvent::ConsensusFailed {
+                            proposal_id,
+                            reason,
+                            timestamp: now,
+                        },
+                    )
                 }
+            }
+            TimeoutOutcome::AlreadyReached(_) => {}
+        }
+        Ok(outcome)
+    }
+
+    /// Finalize a proposal at `now` without waiting for its timeout.
+    ///
+    /// Applies the same rules and emits the same event as
+    /// [`handle_consensus_timeout`](Self::handle_consensus_timeout), returning
+    /// the decided result, or [`ConsensusFailed`](ConsensusError::ConsensusFailed)
+    /// if the votes (with silent peers weighted by liveness) decide nothing.
+    /// Idempotent: a proposal that already reached consensus returns its
+    /// result, and one that already failed returns `ConsensusFailed`, in both
+    /// cases without emitting another event.
+    pub fn finalize_now(
+        &self,
+        scope: &Scope,
+        proposal_id: impl Into<ProposalId>,
+        now: u64,
+    ) -> Result<bool, ConsensusError> {
+        let proposal_id = proposal_id.into().0;
+        if self.get_session(scope, proposal_id)?.state == ConsensusState::Failed {
+            return Err(ConsensusError::ConsensusFailed);
+        }
+        match self.handle_consensus_timeout(scope, proposal_id, now)? {
+            TimeoutOutcome::Reached(result) | TimeoutOutcome::AlreadyReached(result) => Ok(result),
+            TimeoutOutcome::Failed => Err(ConsensusError::ConsensusFailed),
+        }
+    }
+
+    /// Get the consensus result for a proposal as of `now`.
+    ///
+    /// Like [`ConsensusStorage::get_consensus_result`], except that an `Active`
+    /// session whose expiration timestamp has passed is finalized on read (as
+    /// by [`finalize_now`](Self::finalize_now)), so the answer matches the
+    /// timeout path even if the timeout never ran. If the scope is paused the
+    /// session cannot be finalized and the read fails with
+    /// [`ConsensusExpired`](ConsensusError::ConsensusExpired).
+    pub fn get_consensus_result(
+        &self,
+        scope: &Scope,
+        proposal_id: impl Into<ProposalId>,
+        now: u64,
+    ) -> Result<bool, ConsensusError> {
+        let proposal_id = proposal_id.into().0;
+        let settled = self.storage.with_session(scope, proposal_id, |session| {
+            Ok(
+                (!session.is_active() || now < session.proposal.expiration_timestamp)
+                    .then(|| session.outcome().map(|outcome| outcome.result)),
+            )
+        })?;
+        if let Some(result) = settled {
+            return result;
+        }
+        self.finalize_now(scope, proposal_id, now)
+            .map_err(|err| match err {
+                ConsensusError::ScopePaused => ConsensusError::ConsensusExpired,
+                other => other,
+            })
+    }
+
+    /// Time left until a proposal expires, measured by the service's
+    /// [`Clock`](Self::with_clock).
+    ///
+    /// Returns [`Duration::ZERO`] once the expiration timestamp has passed
+    /// (whether or not the session was finalized), and
+    /// [`SessionNotFound`](ConsensusError::SessionNotFound) for an unknown
+    /// proposal.
+    pub fn time_until_expiry(
+        &self,
+        scope: &Scope,
+        proposal_id: impl Into<ProposalId>,
+    ) -> Result<Duration, ConsensusError> {
+        let expiration_timestamp =
+            self.storage
+                .with_session(scope, proposal_id.into().0, |session| {
+                    Ok(session.proposal.expiration_timestamp)
+                })?;
+        Ok(Duration::from_secs(
+            expiration_timestamp.saturating_sub(self.now()),
+        ))
+    }
+
+    /// Push an active proposal's expiration back by `additional`.
+    ///
+    /// Only the proposal owner can extend it, since the proposal is re-signed
+    /// with this peer's signer; any other peer gets
+    /// [`NotProposalOwner`](ConsensusError::NotProposalOwner).
+    /// Existing votes stay valid since vote hashes do not cover the
+    /// expiration. Gossip the returned proposal to peers'
+    /// [`merge_proposal`](Self::merge_proposal), which adopts the later
+    /// expiration. The library schedules no timers, so reschedule your own
+    /// timeout from the returned proposal's `expiration_timestamp`. Fails with
+    /// [`SessionTerminal`](ConsensusError::SessionTerminal) once finalized,
+    /// [`ConsensusExpired`](ConsensusError::ConsensusExpired) if the proposal
+    /// already expired at `now`, and
+    /// [`InvalidTimeout`](ConsensusError::InvalidTimeout) if the new expiration
+    /// exceeds the scope's `max_proposal_lifetime` from `now`.
+    pub fn extend_expiration(
+        &self,
+        scope: &Scope,
+        proposal_id: impl Into<ProposalId>,
+        additional: Duration,
+        now: u64,
+    ) -> Result<Proposal, ConsensusError> {
+        self.update_session(scope, proposal_id.into().0, |session| {
+            if self.signer.identity() != session.proposal.proposal_owner.as_slice() {
+                return Err(ConsensusError::NotProposalOwner);
+            }
+            if !session.is_active() {
+                return Err(ConsensusError::SessionTerminal {
+                    state: session.state,
+                });
+            }
+            if now >= session.proposal.expiration_timestamp {
+                return Err(ConsensusError::ConsensusExpired);
+            }
+            let expiration = session
+                .proposal
+                .expiration_timestamp
+                .saturating_add(additional.as_secs());
+            let max_lifetime = session.config.max_proposal_lifetime().as_secs();
+            if expiration > now.saturating_add(max_lifetime) {
+                return Err(ConsensusError::InvalidTimeout);
+            }
+            session.proposal.expiration_timestamp = expiration;
+            sign_proposal(&mut session.proposal, &self.signer)?;
+            Ok(session.proposal.clone())
+        })
+    }
+
+    /// Run timeout finalization for every active proposal in `scope` whose
+    /// expiration timestamp has passed at `now`.
+    ///
+    /// Intended for startup reconciliation: timers scheduled for
+    /// [`handle_consensus_timeout`](Self::handle_consensus_timeout) do not
+    /// survive a restart. A session that fails to finalize is recorded in
+    /// [`ExpirySweep::errors`] and the sweep moves on to the next one; only a
+    /// failure to list the scope's sessions is returned as an error. Unknown
+    /// scopes yield an empty sweep. Sessions in a paused scope are skipped.
+    pub fn finalize_expired(&self, scope: &Scope, now: u64) -> Result<ExpirySweep, ConsensusError> {
+        if self.storage.is_scope_paused(scope)? {
+            return Ok(ExpirySweep::default());
+        }
+        let mut expired: Vec<u64> = self
+            .storage
+            .list_scope_sessions(scope)?
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|s| s.is_active() && now >= s.proposal.expiration_timestamp)
+            .map(|s| s.proposal.proposal_id)
+            .collect();
+        expired.sort_unstable();
+
+        let mut sweep = ExpirySweep::default();
+        for proposal_id in expired {
+            match self.handle_consensus_timeout(scope, proposal_id, now) {
+                Ok(TimeoutOutcome::Reached(result) | TimeoutOutcome::AlreadyReached(result)) => {
+                    sweep.finalized.push((proposal_id, Some(result)))
+                }
+                Ok(TimeoutOutcome::Failed) => sweep.finalized.push((proposal_id, None)),
+                Err(err) => sweep.errors.push((proposal_id, err)),
+            }
+        }
+        Ok(sweep)
+    }
+
+    /// Remove finalized sessions whose scope [`session_ttl`](ScopeConfig::session_ttl)
+    /// has elapsed since [`finalized_at`](ConsensusSession::finalized_at).
+    ///
+    /// The TTL runs from finalization, not from proposal expiry, so a decided
+    /// session stays queryable for the whole TTL even if its proposal expired
+    /// long before. Active sessions, sessions without a `finalized_at`, and
+    /// scopes without a TTL are left alone. Each removal emits
+    /// [`SessionEvicted`](ConsensusEvent::SessionEvicted) with
+    /// [`TtlExpired`](EvictionReason::TtlExpired). Returns the removed
+    /// proposal IDs in ascending order.
+    pub fn gc_finalized_sessions(
+        &self,
+        scope: &Scope,
+        now: u64,
+    ) -> Result<Vec<u64>, ConsensusError> {
+        let Some(ttl) = self
+            .storage
+            .get_scope_config(scope)?
+            .and_then(|config| config.session_ttl)
+        else {
+            return Ok(Vec::new());
+        };
+
+        let mut collected = Vec::new();
+        self.storage.update_scope_sessions(scope, |sessions| {
+            sessions.retain(|s| {
+                let expired = !s.is_active()
+                    && s.finalized_at
+                        .is_some_and(|at| at.saturating_add(ttl.as_secs()) <= now);
+                if expired {
+                    collected.push(s.proposal.proposal_id);
+                }
+                !expired
             });
+            Ok(())
+        })?;
+        collected.sort_unstable();
+
+        self.retire_evicted(scope, &collected, EvictionReason::TtlExpired, now);
+        Ok(collected)
+    }
+
+    /// Remove a single proposal's session from a scope, returning its proposal.
+    ///
+    /// Returns `None` if the proposal is unknown. No event is emitted, but the
+    /// removed ID is reserved at `now` for the
+    /// [`proposal_id_reuse_window`](Self::with_proposal_id_reuse_window), as
+    /// for evicted sessions. Removing the last session leaves storage queries
+    /// on the scope returning empty results; its config is kept.
+    pub fn remove_proposal(
+        &self,
+        scope: &Scope,
+        proposal_id: impl Into<ProposalId>,
+        now: u64,
+    ) -> Result<Option<Proposal>, ConsensusError> {
+        let proposal_id = proposal_id.into().0;
+        let removed = self.storage.remove_session(scope, proposal_id)?;
+        if removed.is_some() {
+            self.recent_proposal_ids.retire(scope, &[proposal_id], now);
+        }
+        Ok(removed.map(|session| session.proposal))
+    }
+
+    /// Drop every session in a scope, keeping its config.
+    ///
+    /// Unlike [`delete_scope`](ConsensusStorage::delete_scope), the scope's
+    /// configuration survives. No event is emitted; the dropped IDs are
+    /// reserved at `now` as by [`remove_proposal`](Self::remove_proposal).
+    pub fn clear_scope(&self, scope: &Scope, now: u64) -> Result<(), ConsensusError> {
+        let mut cleared = Vec::new();
+        self.storage.update_scope_sessions(scope, |sessions| {
+            cleared.extend(sessions.drain(..).map(|s| s.proposal.proposal_id));
+            Ok(())
+        })?;
+        cleared.sort_unstable();
+        self.recent_proposal_ids.retire(scope, &cleared, now);
+        Ok(())
+    }
+
+    /// Move all of a scope's state to a new key.
+    ///
+    /// Wraps [`ConsensusStorage::rename_scope`] (same errors) and, once the
+    /// storage move succeeds, moves the state this service keeps in memory
+    /// for the scope: reserved proposal IDs, buffered orphan votes, and
+    /// pending vote batches. Timeout timers your application scheduled for
+    /// proposals in `old` must be re-keyed to `new`.
+    pub fn rename_scope(&self, old: &Scope, new: &Scope) -> Result<(), ConsensusError> {
+        self.storage.rename_scope(old, new)?;
+        self.recent_proposal_ids.rename_scope(old, new);
+        self.orphan_votes.rename_scope(old, new);
+        self.vote_batches.rename_scope(old, new);
+        Ok(())
+    }
 
-        match timeout_result? {
-            Some(consensus_result) => {
-                self.emit_event(
-                    scope,
-                    ConsensusEvent::ConsensusReached {
-                        proposal_id,
-                        result: consensus_result,
-                        timestamp: now,
-                    },
-                );
-                Ok(consensus_result)
+    /// Archive and clear a scope's sessions for epoch rollover, keeping its
+    /// config.
+    ///
+    /// Wraps [`ConsensusStorage::rollover_scope`] and returns its snapshot.
+    /// The archived IDs are reserved at `now` as by
+    /// [`clear_scope`](Self::clear_scope), and the scope's buffered orphan
+    /// votes and pending vote batches are dropped, since the sessions they
+    /// belong to are gone. No event is emitted.
+    pub fn rollover_scope(&self, scope: &Scope, now: u64) -> Result<ScopeSnapshot, ConsensusError> {
+        let snapshot = self.storage.rollover_scope(scope)?;
+        let archived: Vec<u64> = snapshot
+            .sessions
+            .iter()
+            .map(|session| session.proposal.proposal_id)
+            .collect();
+        self.recent_proposal_ids.retire(scope, &archived, now);
+        self.orphan_votes.clear_scope(scope);
+        self.vote_batches.clear_scope(scope);
+        Ok(snapshot)
+    }
+
+    /// Freeze finalization in a scope without losing state.
+    ///
+    /// Votes are still validated and stored, but no session transitions to
+    /// `ConsensusReached`, and timeouts fail with
+    /// [`ScopePaused`](ConsensusError::ScopePaused). Applies to existing
+    /// sessions and to proposals created or received while paused.
+    pub fn pause_scope(&self, scope: &Scope) -> Result<(), ConsensusError> {
+        self.apply_scope_pause(scope, None)?;
+        Ok(())
+    }
+
+    /// Resume finalization in a scope paused by [`pause_scope`](Self::pause_scope).
+    ///
+    /// Every active session is re-evaluated against the votes it collected
+    /// while paused, then sessions that expired meanwhile are finalized as by
+    /// [`finalize_expired`](Self::finalize_expired). Events are emitted as usual.
+    /// If an expired session fails to finalize, the rest are still finalized
+    /// and the first such error is returned; the scope stays resumed.
+    pub fn resume_scope(&self, scope: &Scope, now: u64) -> Result<(), ConsensusError> {
+        for (proposal_id, transition, certificate) in self.apply_scope_pause(scope, Some(now))? {
+            self.handle_transition(scope, proposal_id, transition, certificate, now)?;
+        }
+        match self.finalize_expired(scope, now)?.errors.into_iter().next() {
+            Some((_, err)) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    /// Pause the scope (`resumed_at: None`) or resume it at the given time.
+    /// The pause state is stored once for the scope and read whenever a
+    /// session in it could be decided. Returns the transitions produced by
+    /// re-evaluating active sessions on resume.
+    fn apply_scope_pause(
+        &self,
+        scope: &Scope,
+        resumed_at: Option<u64>,
+    ) -> Result<Vec<(u64, SessionTransition, Option<ConsensusCertificate>)>, ConsensusError> {
+        self.storage.set_scope_paused(scope, resumed_at.is_none())?;
+        let Some(now) = resumed_at else {
+            return Ok(Vec::new());
+        };
+
+        let mut transitions = Vec::new();
+        self.storage.update_scope_sessions(scope, |sessions| {
+            for session in sessions.iter_mut() {
+                let transition = session.reevaluate(now);
+                let certificate = self.certify_transition(session, transition);
+                transitions.push((session.proposal.proposal_id, transition, certificate));
             }
-            None => {
-                self.emit_event(
-                    scope,
-                    ConsensusEvent::ConsensusFailed {
-                        proposal_id,
-                        timestamp: now,
-                    },
-                );
-                Err(ConsensusError::InsufficientVotesAtTimeout)
+            Ok(())
+        })?;
+        transitions.sort_unstable_by_key(|(proposal_id, ..)| *proposal_id);
+        Ok(transitions)
+    }
+
+    /// Audit every stored session in `scope` against recomputation.
+    ///
+    /// Each proposal's votes are re-validated (hashes, signatures,
+    /// eligibility, and the hashgraph chain) at the time they were cast, so
+    /// expiry does not matter. Each session that reached consensus is checked
+    /// against what its votes decide, under the in-flight rules or, failing
+    /// that, the timeout rules; an active, unpaused session whose votes
+    /// already decide it is flagged too. Failed sessions are only
+    /// re-validated. Unknown scopes yield an empty report.
+    pub fn verify_scope_integrity(&self, scope: &Scope) -> Result<IntegrityReport, ConsensusError> {
+        let mut sessions = self.storage.list_scope_sessions(scope)?.unwrap_or_default();
+        sessions.sort_unstable_by_key(|s| s.proposal.proposal_id);
+        let paused = self.storage.is_scope_paused(scope)?;
+
+        let mut report = IntegrityReport {
+            sessions_checked: sessions.len(),
+            issues: Vec::new(),
+        };
+        for session in &sessions {
+            let proposal_id = session.proposal.proposal_id;
+            if let Err(error) = self.revalidate_votes(session) {
+                report
+                    .issues
+                    .push(IntegrityIssue::InvalidVotes { proposal_id, error });
+            }
+
+            let stored = match session.state {
+                ConsensusState::ConsensusReached(result) => Some(result),
+                ConsensusState::Active if !paused => None,
+                _ => continue,
+            };
+            let recomputed = session.recompute_result();
+            if recomputed != stored {
+                report.issues.push(IntegrityIssue::ResultMismatch {
+                    proposal_id,
+                    stored: session.state,
+                    recomputed,
+                });
+            }
+        }
+        Ok(report)
+    }
+
+    /// Re-run proposal vote validation on a stored session, checking each
+    /// vote as of its own timestamp.
+    fn revalidate_votes(&self, session: &ConsensusSession) -> Result<(), ConsensusError> {
+        let checks = self.vote_checks(&session.config);
+        let proposal = &session.proposal;
+        for vote in &proposal.votes {
+            if vote.proposal_id != proposal.proposal_id {
+                return Err(ConsensusError::VoteProposalIdMismatch);
             }
+            validate_vote::<Signer>(vote, proposal, vote.timestamp, &checks)?;
         }
+        validate_vote_chain(&proposal.votes)
+    }
+
+    // ── Annotations ──────────────────────────────────────────────────
+
+    /// Attach a local key-value annotation to a proposal's session.
+    ///
+    /// Annotations are application metadata only: they stay on this node and
+    /// play no part in consensus, vote hashing, or gossip. Setting an existing
+    /// key replaces its value. See
+    /// [`ConsensusSession::annotate`] for the size limits.
+    pub fn annotate(
+        &self,
+        scope: &Scope,
+        proposal_id: impl Into<ProposalId>,
+        key: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Result<(), ConsensusError> {
+        let (key, value) = (key.into(), value.into());
+        self.update_session(scope, proposal_id.into().0, move |session| {
+            session.annotate(key, value)
+        })
+    }
+
+    /// Get the annotations attached to a proposal's session.
+    pub fn get_annotations(
+        &self,
+        scope: &Scope,
+        proposal_id: impl Into<ProposalId>,
+    ) -> Result<HashMap<String, String>, ConsensusError> {
+        Ok(self.get_session(scope, proposal_id.into().0)?.annotations)
     }
 
     // ── Scope management ─────────────────────────────────────────────
 
+    /// List every scope that currently holds sessions.
+    ///
+    /// Returns an empty list instead of `None` when there are none. Use it to
+    /// drive monitoring or sweeps across all scopes.
+    pub fn list_scopes(&self) -> Result<Vec<Scope>, ConsensusError> {
+        Ok(self.storage.list_scopes()?.unwrap_or_default())
+    }
+
     /// Get a builder for a scope configuration.
     ///
     /// # Example
@@ -451,13 +1678,21 @@ where
         // NOTE: if a per-proposal override is provided, we should not stomp its timeout
         // from the proposal's expiration fields (the caller explicitly chose it).
         let has_explicit_override = proposal_override.is_some();
+        let scope_config = self.storage.get_scope_config(scope)?;
+        let scope_allowed_voters = scope_config.as_ref().and_then(|c| c.allowed_voters.clone());
         let base_config = if let Some(override_config) = proposal_override {
             override_config
-        } else if let Some(scope_config) = self.storage.get_scope_config(scope)? {
+        } else if let Some(scope_config) = scope_config {
             ConsensusConfig::from(scope_config)
         } else {
             ConsensusConfig::gossipsub()
         };
+        // The scope's voter allowlist applies to every session in it,
+        // overrides included.
+        let mut base_config = base_config;
+        if let Some(allowed_voters) = scope_allowed_voters {
+            base_config = base_config.with_shared_allowed_voters(Some(allowed_voters));
+        }
 
         // 2. Apply proposal field overrides if proposal is provided
         if let Some(prop) = proposal {
@@ -471,13 +1706,7 @@ where
                 base_config.consensus_timeout()
             };
 
-            Ok(ConsensusConfig::new(
-                base_config.consensus_threshold(),
-                timeout_seconds,
-                base_config.max_rounds(),
-                base_config.use_gossipsub_rounds(),
-                prop.liveness_criteria_yes,
-            ))
+            Ok(base_config.with_proposal_fields(timeout_seconds, prop.liveness_criteria_yes))
         } else {
             Ok(base_config)
         }
@@ -486,7 +1715,7 @@ where
     fn get_session(
         &self,
         scope: &Scope,
-        proposal_id: u32,
+        proposal_id: u64,
     ) -> Result<ConsensusSession, ConsensusError> {
         self.storage
             .get_session(scope, proposal_id)?
@@ -496,29 +1725,142 @@ where
     fn update_session<R, F>(
         &self,
         scope: &Scope,
-        proposal_id: u32,
+        proposal_id: u64,
         mutator: F,
     ) -> Result<R, ConsensusError>
     where
         F: FnOnce(&mut ConsensusSession) -> Result<R, ConsensusError>,
     {
-        self.storage.update_session(scope, proposal_id, mutator)
+        let paused = self.storage.is_scope_paused(scope)?;
+        self.storage.update_session(scope, proposal_id, |session| {
+            session.set_finalization_paused(paused);
+            let result = mutator(session);
+            session.set_finalization_paused(false);
+            result
+        })
     }
 
-    fn save_session(&self, scope: &Scope, session: ConsensusSession) -> Result<(), ConsensusError> {
-        self.storage.save_session(scope, session)
+    /// [`update_session`](Self::update_session) for mutators that yield a
+    /// transition, certifying the session in the same update if it decided.
+    fn update_session_certified<F>(
+        &self,
+        scope: &Scope,
+        proposal_id: u64,
+        mutator: F,
+    ) -> Result<(SessionTransition, Option<ConsensusCertificate>), ConsensusError>
+    where
+        F: FnOnce(&mut ConsensusSession) -> Result<SessionTransition, ConsensusError>,
+    {
+        self.update_session(scope, proposal_id, |session| {
+            let transition = mutator(session)?;
+            Ok((transition, self.certify_transition(session, transition)))
+        })
+    }
+
+    fn vote_checks(&self, config: &ConsensusConfig) -> VoteChecks<'_> {
+        VoteChecks {
+            eligibility: self.eligibility_verifier.as_ref(),
+            timestamps: self.timestamp_policy.as_ref(),
+            strict_signatures: config.strict_signatures(),
+            max_proposal_lifetime: config.max_proposal_lifetime(),
+            max_vote_age: config.max_vote_age(),
+        }
     }
 
-    fn trim_scope_sessions(&self, scope: &Scope) -> Result<(), ConsensusError> {
+    fn trim_scope_sessions(&self, scope: &Scope, now: u64) -> Result<(), ConsensusError> {
+        let mut evicted = Vec::new();
         self.storage.update_scope_sessions(scope, |sessions| {
             if sessions.len() <= self.max_sessions_per_scope {
                 return Ok(());
             }
 
             sessions.sort_by_key(|s| std::cmp::Reverse(s.created_at));
-            sessions.truncate(self.max_sessions_per_scope);
+            evicted = sessions
+                .split_off(self.max_sessions_per_scope)
+                .into_iter()
+                .map(|s| s.proposal.proposal_id)
+                .collect();
             Ok(())
-        })
+        })?;
+
+        self.retire_evicted(scope, &evicted, EvictionReason::CapacityTrim, now);
+        Ok(())
+    }
+
+    /// Publish [`EquivocationDetected`](ConsensusEvent::EquivocationDetected)
+    /// if `err` reports an equivocation by one of `incoming`.
+    fn report_equivocation(
+        &self,
+        scope: &Scope,
+        err: &ConsensusError,
+        incoming: &[Vote],
+        now: u64,
+    ) {
+        let ConsensusError::Equivocation {
+            owner,
+            first_hash,
+            second_hash,
+        } = err
+        else {
+            return;
+        };
+        let Some(second) = incoming.iter().find(|v| &v.vote_hash == second_hash) else {
+            return;
+        };
+        // The first vote is either stored already or earlier in the same batch.
+        let Some(first) = incoming
+            .iter()
+            .find(|v| &v.vote_hash == first_hash)
+            .cloned()
+            .or_else(|| {
+                self.get_session(scope, second.proposal_id)
+                    .ok()
+                    .and_then(|session| session.held_vote(owner).cloned())
+            })
+        else {
+            return;
+        };
+        self.emit_event(
+            scope,
+            ConsensusEvent::EquivocationDetected {
+                proposal_id: second.proposal_id,
+                first: Box::new(first),
+                second: Box::new(second.clone()),
+                timestamp: now,
+            },
+        );
+    }
+
+    /// Reserve the IDs of sessions removed from `scope` and announce each removal.
+    fn retire_evicted(&self, scope: &Scope, evicted: &[u64], reason: EvictionReason, now: u64) {
+        self.recent_proposal_ids.retire(scope, evicted, now);
+        for &proposal_id in evicted {
+            self.emit_event(
+                scope,
+                ConsensusEvent::SessionEvicted {
+                    proposal_id,
+                    reason,
+                    timestamp: now,
+                },
+            );
+        }
+    }
+
+    fn ensure_proposal_id_not_recent(
+        &self,
+        scope: &Scope,
+        proposal_id: u64,
+        now: u64,
+    ) -> Result<(), ConsensusError> {
+        let window = self.proposal_id_reuse_window.as_secs();
+        if window > 0
+            && self
+                .recent_proposal_ids
+                .is_recent(scope, proposal_id, now, window)
+        {
+            return Err(ConsensusError::ProposalIdRecentlyUsed);
+        }
+        Ok(())
     }
 
     pub(crate) fn list_scope_sessions(
@@ -530,26 +1872,192 @@ where
             .ok_or(ConsensusError::ScopeNotFound)
     }
 
+    /// Emit the event for `transition`. A session failed by the round limit
+    /// is reported to the caller as
+    /// [`MaxRoundsExceeded`](ConsensusError::MaxRoundsExceeded), after its
+    /// `Failed` state has been stored and announced.
     fn handle_transition(
         &self,
         scope: &Scope,
-        proposal_id: u32,
+        proposal_id: u64,
         transition: SessionTransition,
+        certificate: Option<ConsensusCertificate>,
         now: u64,
-    ) {
-        if let SessionTransition::ConsensusReached(result) = transition {
-            self.emit_event(
+    ) -> Result<(), ConsensusError> {
+        match transition {
+            SessionTransition::ConsensusReached(result) => {
+                self.emit_reached(scope, proposal_id, result, certificate, now)
+            }
+            SessionTransition::Failed(reason) => self.emit_event(
                 scope,
-                ConsensusEvent::ConsensusReached {
+                ConsensusEvent::ConsensusFailed {
                     proposal_id,
-                    result,
+                    reason: reason.to_string(),
                     timestamp: now,
                 },
-            );
+            ),
+            SessionTransition::StillActive | SessionTransition::LateVoteRecorded => {}
         }
+        if transition == SessionTransition::Failed(FailReason::MaxRoundsExceeded) {
+            return Err(ConsensusError::MaxRoundsExceeded);
+        }
+        Ok(())
+    }
+
+    /// Build the certificate for a session that has just reached consensus,
+    /// if certified events are enabled.
+    ///
+    /// Called from inside the storage update that decided the session, so the
+    /// certificate reflects exactly the state that was stored.
+    fn certify(
+        &self,
+        session: &ConsensusSession,
+        decided_at_timeout: bool,
+    ) -> Option<ConsensusCertificate> {
+        let ConsensusState::ConsensusReached(result) = session.state else {
+            return None;
+        };
+        self.certified_events.then(|| ConsensusCertificate {
+            proposal: session.proposal.clone(),
+            result,
+            decided_at_timeout,
+            config: session.config.clone(),
+        })
+    }
+
+    /// [`certify`](Self::certify) `session` if `transition` decided it.
+    fn certify_transition(
+        &self,
+        session: &ConsensusSession,
+        transition: SessionTransition,
+    ) -> Option<ConsensusCertificate> {
+        match transition {
+            SessionTransition::ConsensusReached(_) => self.certify(session, false),
+            _ => None,
+        }
+    }
+
+    /// Emit the consensus event for `proposal_id`, certified when a
+    /// certificate was built.
+    fn emit_reached(
+        &self,
+        scope: &Scope,
+        proposal_id: u64,
+        result: bool,
+        certificate: Option<ConsensusCertificate>,
+        now: u64,
+    ) {
+        let event = match certificate {
+            Some(certificate) => ConsensusEvent::ConsensusCertified {
+                proposal_id,
+                result,
+                timestamp: now,
+                certificate: Box::new(certificate),
+            },
+            None => ConsensusEvent::ConsensusReached {
+                proposal_id,
+                result,
+                timestamp: now,
+            },
+        };
+        self.emit_event(scope, event);
+    }
+
+    /// Publish the [`VoteAdded`](ConsensusEvent::VoteAdded) events collected
+    /// by [`add_counted_vote`], if enabled, or add them to the proposal's
+    /// pending batch when batching.
+    fn emit_vote_events(&self, scope: &Scope, added: Vec<ConsensusEvent>) {
+        if !self.vote_events {
+            return;
+        }
+        let Some(window) = self.vote_batch_window else {
+            for event in added {
+                self.emit_event(scope, event);
+            }
+            return;
+        };
+        let Some(&ConsensusEvent::VoteAdded {
+            proposal_id,
+            timestamp,
+            ..
+        }) = added.first()
+        else {
+            return;
+        };
+        let voters = added.into_iter().filter_map(|event| match event {
+            ConsensusEvent::VoteAdded { voter, .. } => Some(voter),
+            _ => None,
+        });
+        if let Some(new_voters) =
+            self.vote_batches
+                .add(scope, proposal_id, voters, timestamp, window.as_secs())
+        {
+            self.publish_vote_batch(scope, proposal_id, new_voters, timestamp);
+        }
+    }
+
+    /// Publish every pending [`VotesBatch`](ConsensusEvent::VotesBatch) whose
+    /// window has passed by `now`.
+    ///
+    /// Call this periodically so batches of proposals that stopped receiving
+    /// votes are still delivered; [`spawn_reaper`](Self::spawn_reaper) does so
+    /// on every tick. Does nothing unless the service was built
+    /// [`with_vote_batching`](Self::with_vote_batching).
+    pub fn flush_vote_batches(&self, now: u64) {
+        let Some(window) = self.vote_batch_window else {
+            return;
+        };
+        for (scope, proposal_id, new_voters) in self.vote_batches.take_due(now, window.as_secs()) {
+            self.publish_vote_batch(&scope, proposal_id, new_voters, now);
+        }
+    }
+
+    /// Publish a closed batch with the proposal's current tally size. Dropped
+    /// if the session is gone.
+    fn publish_vote_batch(
+        &self,
+        scope: &Scope,
+        proposal_id: u64,
+        new_voters: Vec<Vec<u8>>,
+        now: u64,
+    ) {
+        let Ok(Some(session)) = self.storage.get_session(scope, proposal_id) else {
+            return;
+        };
+        self.event_bus.publish(
+            scope.clone(),
+            ConsensusEvent::VotesBatch {
+                proposal_id,
+                new_voters,
+                total: session.votes().len(),
+                timestamp: now,
+            },
+        );
     }
 
     fn emit_event(&self, scope: &Scope, event: ConsensusEvent) {
+        if self.vote_batch_window.is_some() {
+            // Deliver a proposal's pending votes before its outcome.
+            if let ConsensusEvent::ConsensusReached {
+                proposal_id,
+                timestamp,
+                ..
+            }
+            | ConsensusEvent::ConsensusCertified {
+                proposal_id,
+                timestamp,
+                ..
+            }
+            | ConsensusEvent::ConsensusFailed {
+                proposal_id,
+                timestamp,
+                ..
+            } = event
+                && let Some(new_voters) = self.vote_batches.take(scope, proposal_id)
+            {
+                self.publish_vote_batch(scope, proposal_id, new_voters, timestamp);
+            }
+        }
         self.event_bus.publish(scope.clone(), event);
     }
 }
@@ -598,6 +2106,24 @@ where
         self
     }
 
+    /// Replace the fractional threshold with `mode` (e.g. an absolute YES count)
+    pub fn with_threshold_mode(mut self, mode: ThresholdMode) -> Self {
+        self.builder = self.builder.with_threshold_mode(mode);
+        self
+    }
+
+    /// Weigh each listed voter's vote by their stake
+    pub fn with_voter_weights(mut self, voter_weights: VoterWeights) -> Self {
+        self.builder = self.builder.with_voter_weights(voter_weights);
+        self
+    }
+
+    /// Hash votes of new proposals with `hash_algo`
+    pub fn with_hash_algo(mut self, hash_algo: HashAlgo) -> Self {
+        self.builder = self.builder.with_hash_algo(hash_algo);
+        self
+    }
+
     /// Set default timeout for proposals (in seconds)
     pub fn with_timeout(mut self, timeout: Duration) -> Self {
         self.builder = self.builder.with_timeout(timeout);
@@ -616,6 +2142,109 @@ where
         self
     }
 
+    /// Set the known participant set; other voters are rejected (if None, membership is open)
+    pub fn with_allowed_voters(mut self, allowed_voters: Option<HashSet<Vec<u8>>>) -> Self {
+        self.builder = self.builder.with_allowed_voters(allowed_voters);
+        self
+    }
+
+    /// Resolve partial-turnout ties at timeout to the liveness default
+    pub fn with_resolve_partial_tie_on_timeout(mut self, resolve: bool) -> Self {
+        self.builder = self.builder.with_resolve_partial_tie_on_timeout(resolve);
+        self
+    }
+
+    /// Decide YES/NO ties at full turnout with `tie_break`
+    pub fn with_tie_break(mut self, tie_break: TieBreak) -> Self {
+        self.builder = self.builder.with_tie_break(tie_break);
+        self
+    }
+
+    /// Reject votes with non-canonical signatures (e.g. high-S ECDSA)
+    pub fn with_strict_signatures(mut self, strict_signatures: bool) -> Self {
+        self.builder = self.builder.with_strict_signatures(strict_signatures);
+        self
+    }
+
+    /// Allow or forbid proposal owners voting on their own proposals
+    pub fn with_allow_owner_vote(mut self, allow_owner_vote: bool) -> Self {
+        self.builder = self.builder.with_allow_owner_vote(allow_owner_vote);
+        self
+    }
+
+    /// Cap stored votes per session at a multiple of the expected voters count
+    pub fn with_max_votes_multiplier(mut self, max_votes_multiplier: u32) -> Self {
+        self.builder = self.builder.with_max_votes_multiplier(max_votes_multiplier);
+        self
+    }
+
+    /// Cap stored votes per session at an absolute count
+    pub fn with_max_votes_per_proposal(mut self, max_votes_per_proposal: usize) -> Self {
+        self.builder = self
+            .builder
+            .with_max_votes_per_proposal(max_votes_per_proposal);
+        self
+    }
+
+    /// Record (rather than reject) votes arriving after a session is terminal
+    pub fn with_accept_late_votes(mut self, accept_late_votes: bool) -> Self {
+        self.builder = self.builder.with_accept_late_votes(accept_late_votes);
+        self
+    }
+
+    /// Let voters change their vote while a session is active
+    pub fn with_allow_vote_changes(mut self, allow_vote_changes: bool) -> Self {
+        self.builder = self.builder.with_allow_vote_changes(allow_vote_changes);
+        self
+    }
+
+    /// Reject votes whose timestamp is older than this when received
+    pub fn with_max_vote_age(mut self, max_vote_age: Duration) -> Self {
+        self.builder = self.builder.with_max_vote_age(max_vote_age);
+        self
+    }
+
+    /// Choose whether identical repeated votes fail or are ignored
+    pub fn with_duplicate_vote_policy(mut self, policy: DuplicateVotePolicy) -> Self {
+        self.builder = self.builder.with_duplicate_vote_policy(policy);
+        self
+    }
+
+    /// Reject inbound proposals expiring further than this in the future
+    pub fn with_max_proposal_lifetime(mut self, max_proposal_lifetime: Duration) -> Self {
+        self.builder = self
+            .builder
+            .with_max_proposal_lifetime(max_proposal_lifetime);
+        self
+    }
+
+    /// Limit the payload size of proposals created or received in this scope;
+    /// `0` lifts the limit
+    pub fn with_max_payload_bytes(mut self, max_payload_bytes: usize) -> Self {
+        self.builder = self.builder.with_max_payload_bytes(max_payload_bytes);
+        self
+    }
+
+    /// Cap the signature verifications an inbound proposal may require
+    pub fn with_max_validation_cost(mut self, max_signature_verifications: usize) -> Self {
+        self.builder = self
+            .builder
+            .with_max_validation_cost(max_signature_verifications);
+        self
+    }
+
+    /// Refuse inbound proposals created longer ago than `max_age`
+    pub fn with_max_inbound_proposal_age(mut self, max_age: Duration) -> Self {
+        self.builder = self.builder.with_max_inbound_proposal_age(max_age);
+        self
+    }
+
+    /// Let `gc_finalized_sessions` remove sessions finalized longer ago than `ttl`
+    pub fn with_session_ttl(mut self, ttl: Duration) -> Self {
+        self.builder = self.builder.with_session_ttl(ttl);
+        self
+    }
+
     /// Use P2P preset with common defaults
     pub fn p2p_preset(mut self) -> Self {
         self.builder = self.builder.p2p_preset();
@@ -666,3 +2295,69 @@ where
         self.builder.get_config()
     }
 }
+
+/// Describe why a timeout left a proposal undecided, for [`ConsensusEvent::ConsensusFailed`].
+fn timeout_failure_reason(explanation: &ConsensusExplanation) -> String {
+    let cast = explanation.yes_votes + explanation.no_votes;
+    match explanation.rule {
+        QuorumRule::Unanimity => format!(
+            "unanimity required but only {cast} of {} voters voted",
+            explanation.expected_voters
+        ),
+        _ if explanation.yes_weight == explanation.no_weight => format!(
+            "YES and NO tied at timeout ({} each, {cast} of {} voted)",
+            explanation.yes_weight, explanation.expected_voters
+        ),
+        _ => format!(
+            "no side reached {} weight at timeout (YES {}, NO {})",
+            explanation.required_choice_votes, explanation.yes_weight, explanation.no_weight
+        ),
+    }
+}
+
+/// Add `vote` to `session`, recording a [`ConsensusEvent::VoteAdded`] in
+/// `added` if it entered the tally (not a late vote or an ignored duplicate).
+fn add_counted_vote(
+    session: &mut ConsensusSession,
+    vote: Vote,
+    now: u64,
+    added: &mut Vec<ConsensusEvent>,
+) -> Result<SessionTransition, ConsensusError> {
+    let already_held = session
+        .votes()
+        .get(&vote.vote_owner)
+        .is_some_and(|held| held.vote_hash == vote.vote_hash);
+    let (proposal_id, voter, choice) = (vote.proposal_id, vote.vote_owner.clone(), vote.choice());
+    let transition = session.add_vote(vote, now)?;
+    if !already_held && transition != SessionTransition::LateVoteRecorded {
+        added.push(ConsensusEvent::VoteAdded {
+            proposal_id,
+            voter,
+            choice,
+            round: session.proposal.round,
+            timestamp: now,
+        });
+    }
+    Ok(transition)
+}
+
+/// [`ConsensusEvent::VoteAdded`] events for the votes a received proposal
+/// arrived with, all reported at the round the session was created at.
+fn embedded_vote_events(session: &ConsensusSession, now: u64) -> Vec<ConsensusEvent> {
+    session
+        .proposal
+        .votes
+        .iter()
+        .map(|vote| vote_added_event(vote, session.proposal.round, now))
+        .collect()
+}
+
+fn vote_added_event(vote: &Vote, round: u32, now: u64) -> ConsensusEvent {
+    ConsensusEvent::VoteAdded {
+        proposal_id: vote.proposal_id,
+        voter: vote.vote_owner.clone(),
+        choice: vote.choice(),
+        round,
+        timestamp: now,
+    }
+}
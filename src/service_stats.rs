@@ -1,12 +1,16 @@
 //! Scope-level statistics for monitoring consensus activity.
 
+use std::collections::HashMap;
+
 use crate::{
     events::ConsensusEventBus, scope::ConsensusScope, service::ConsensusService,
     session::ConsensusState, signing::ConsensusSignatureScheme, storage::ConsensusStorage,
+    types::VoteChoice,
 };
 
-/// Aggregate counters for all sessions within a single scope.
-#[derive(Debug, Clone)]
+/// Aggregate counters for all sessions within a single scope, or across all
+/// scopes for [`get_global_stats`](ConsensusService::get_global_stats).
+#[derive(Debug, Clone, Default)]
 pub struct ConsensusStats {
     /// Total number of proposals in this scope.
     pub total_sessions: usize,
@@ -18,6 +22,41 @@ pub struct ConsensusStats {
     pub consensus_reached: usize,
 }
 
+/// One voter's track record across the finalized proposals of a scope.
+///
+/// Only sessions in [`ConsensusState::ConsensusReached`] count as finalized.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParticipationStat {
+    /// Finalized proposals this voter cast a vote in.
+    pub votes_cast: usize,
+    /// Finalized proposals in the scope (the same for every voter).
+    pub finalized_total: usize,
+    /// How many of this voter's votes were YES.
+    pub yes_votes: usize,
+    /// How many of this voter's votes were NO.
+    pub no_votes: usize,
+    /// How many of this voter's votes were abstentions.
+    pub abstain_votes: usize,
+}
+
+impl ParticipationStat {
+    /// Fraction of finalized proposals the voter took part in (0.0 to 1.0).
+    pub fn participation_rate(&self) -> f64 {
+        if self.finalized_total == 0 {
+            return 0.0;
+        }
+        self.votes_cast as f64 / self.finalized_total as f64
+    }
+
+    /// Fraction of the voter's votes that were YES (0.0 to 1.0).
+    pub fn yes_ratio(&self) -> f64 {
+        if self.votes_cast == 0 {
+            return 0.0;
+        }
+        self.yes_votes as f64 / self.votes_cast as f64
+    }
+}
+
 impl<Scope, Storage, Event, Signer> ConsensusService<Scope, Storage, Event, Signer>
 where
     Scope: ConsensusScope,
@@ -50,11 +89,60 @@ where
                     failed_sessions,
                 }
             })
-            .unwrap_or(ConsensusStats {
-                total_sessions: 0,
-                active_sessions: 0,
-                consensus_reached: 0,
-                failed_sessions: 0,
+            .unwrap_or_default()
+    }
+
+    /// Get statistics summed over every scope in
+    /// [`list_scopes`](Self::list_scopes).
+    ///
+    /// Each scope is counted as by [`get_scope_stats`](Self::get_scope_stats),
+    /// so a scope removed mid-iteration contributes nothing.
+    pub fn get_global_stats(&self) -> ConsensusStats {
+        self.list_scopes()
+            .unwrap_or_default()
+            .iter()
+            .map(|scope| self.get_scope_stats(scope))
+            .fold(ConsensusStats::default(), |total, stats| ConsensusStats {
+                total_sessions: total.total_sessions + stats.total_sessions,
+                active_sessions: total.active_sessions + stats.active_sessions,
+                failed_sessions: total.failed_sessions + stats.failed_sessions,
+                consensus_reached: total.consensus_reached + stats.consensus_reached,
             })
     }
+
+    /// Get per-voter participation across the finalized proposals in a scope.
+    ///
+    /// Keys are vote owner identity bytes; only voters seen in at least one
+    /// finalized proposal appear. Late votes recorded under
+    /// [`accept_late_votes`](crate::scope_config::ScopeConfig::accept_late_votes)
+    /// count as participation. Returns an empty map for unknown scopes.
+    pub fn participation_stats(&self, scope: &Scope) -> HashMap<Vec<u8>, ParticipationStat> {
+        let finalized: Vec<_> = self
+            .list_scope_sessions(scope)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|s| matches!(s.state, ConsensusState::ConsensusReached(_)))
+            .collect();
+        let finalized_total = finalized.len();
+
+        let mut stats: HashMap<Vec<u8>, ParticipationStat> = HashMap::new();
+        for session in &finalized {
+            for (owner, vote) in session.votes().iter().chain(&session.late_votes) {
+                let stat = stats.entry(owner.clone()).or_insert(ParticipationStat {
+                    votes_cast: 0,
+                    finalized_total,
+                    yes_votes: 0,
+                    no_votes: 0,
+                    abstain_votes: 0,
+                });
+                stat.votes_cast += 1;
+                match vote.choice() {
+                    VoteChoice::Yes => stat.yes_votes += 1,
+                    VoteChoice::No => stat.no_votes += 1,
+                    VoteChoice::Abstain => stat.abstain_votes += 1,
+                }
+            }
+        }
+        stats
+    }
 }
@@ -4,29 +4,54 @@
 //! through vote collection to a final [`ConsensusState`]. Each session carries its
 //! own [`ConsensusConfig`] that governs thresholds, timeouts, and round limits.
 
-use std::{collections::HashMap, time::Duration};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+    time::Duration,
+};
 
 use crate::{
+    eligibility::{AcceptAllEligibility, EligibilityVerifier},
     error::ConsensusError,
     protos::consensus::v1::{Proposal, Vote},
-    scope_config::{NetworkType, ScopeConfig},
+    scope_config::{
+        DEFAULT_MAX_PROPOSAL_LIFETIME, DEFAULT_MAX_VOTE_AGE, DEFAULT_MAX_VOTES_MULTIPLIER,
+        DEFAULT_MAX_VOTES_PER_PROPOSAL, DuplicateVotePolicy, NetworkType, ScopeConfig,
+        ThresholdMode, TieBreak,
+    },
     signing::ConsensusSignatureScheme,
-    types::SessionTransition,
+    timestamp_policy::{RfcTimestampPolicy, TimestampPolicy},
+    types::{
+        ConsensusExplanation, ConsensusOutcome, FailReason, HashAlgo, QuorumRule, QuorumVerdict,
+        SessionTransition, VoteChoice,
+    },
     utils::{
-        calculate_consensus_result, calculate_max_rounds, validate_proposal,
-        validate_proposal_timestamp, validate_vote, validate_vote_chain,
+        VoteChecks, VoteCounts, calculate_max_rounds, consensus_result_for_counts,
+        explain_consensus_result, is_full_turnout_tie, is_weighted_tie_at_timeout,
+        validate_proposal_timestamp, validate_proposal_with, validate_vote, validate_vote_chain,
     },
+    weights::VoterWeights,
 };
 
+/// Maximum number of annotations a session can hold.
+pub const MAX_ANNOTATIONS: usize = 32;
+/// Maximum length in bytes of an annotation key or value.
+pub const MAX_ANNOTATION_LEN: usize = 256;
+
 /// Per-session configuration controlling how consensus is reached.
 ///
 /// Use [`ConsensusConfig::gossipsub()`] or [`ConsensusConfig::p2p()`] for sensible
 /// defaults, then refine with builder methods like [`with_timeout`](Self::with_timeout)
 /// and [`with_threshold`](Self::with_threshold).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConsensusConfig {
-    /// What fraction of expected voters must vote before consensus can be reached (default: 2/3).
-    consensus_threshold: f64,
+    /// How many votes decide the proposal (default: a 2/3 fraction of expected voters).
+    threshold_mode: ThresholdMode,
+    /// Per-voter stake weights; empty means every vote counts once.
+    voter_weights: VoterWeights,
+    /// Hash function for the votes of proposals created with this config.
+    hash_algo: HashAlgo,
     /// How long to wait before timing out if consensus isn't reached.
     consensus_timeout: Duration,
     /// Maximum number of voting rounds (vote increments) before giving up.
@@ -41,6 +66,37 @@ pub struct ConsensusConfig {
     use_gossipsub_rounds: bool,
     /// Whether to apply liveness criteria for silent peers (count silent as YES/NO depending on this flag).
     liveness_criteria: bool,
+    /// Resolve a YES/NO tie at timeout with partial turnout to the liveness default
+    /// instead of failing the session.
+    resolve_partial_tie_on_timeout: bool,
+    /// How a YES/NO tie at full turnout is decided; `None` follows the
+    /// proposal's liveness criteria.
+    tie_break: Option<TieBreak>,
+    /// Reject votes whose signature is valid but not in the scheme's canonical
+    /// form (e.g. high-S ECDSA).
+    strict_signatures: bool,
+    /// Accept votes from the proposal owner on their own proposal.
+    allow_owner_vote: bool,
+    /// Voter identities allowed to vote; `None` accepts every voter. Shared
+    /// with the scope config and the scope's other sessions.
+    allowed_voters: Option<Arc<HashSet<Vec<u8>>>>,
+    /// Cap on stored votes per session, as a multiple of the proposal's
+    /// expected voters count (always at least 1).
+    max_votes_multiplier: u32,
+    /// Absolute cap on stored votes per session, whatever the proposal's
+    /// expected voters count claims (always at least 1).
+    max_votes_per_proposal: usize,
+    /// Record votes arriving after the session is terminal as late votes
+    /// instead of rejecting them.
+    accept_late_votes: bool,
+    /// Let a voter replace their vote on an active session.
+    allow_vote_changes: bool,
+    /// How a repeated vote from the same owner is handled.
+    duplicate_vote_policy: DuplicateVotePolicy,
+    /// Furthest in the future (relative to receipt) an inbound proposal may expire.
+    max_proposal_lifetime: Duration,
+    /// Oldest a vote's timestamp may be (relative to receipt) to be accepted.
+    max_vote_age: Duration,
 }
 
 impl From<NetworkType> for ConsensusConfig {
@@ -64,6 +120,25 @@ impl From<ScopeConfig> for ConsensusConfig {
             use_gossipsub_rounds,
             config.default_liveness_criteria_yes,
         )
+        .with_threshold_mode_unchecked(
+            config
+                .threshold_mode
+                .unwrap_or(ThresholdMode::Fraction(config.default_consensus_threshold)),
+        )
+        .with_voter_weights(config.voter_weights)
+        .with_hash_algo(config.hash_algo)
+        .with_resolve_partial_tie_on_timeout(config.resolve_partial_tie_on_timeout)
+        .with_tie_break(config.tie_break)
+        .with_strict_signatures(config.strict_signatures)
+        .with_allow_owner_vote(config.allow_owner_vote)
+        .with_shared_allowed_voters(config.allowed_voters)
+        .with_max_votes_multiplier(config.max_votes_multiplier)
+        .with_max_votes_per_proposal(config.max_votes_per_proposal)
+        .with_accept_late_votes(config.accept_late_votes)
+        .with_allow_vote_changes(config.allow_vote_changes)
+        .with_duplicate_vote_policy(config.duplicate_vote_policy)
+        .with_max_proposal_lifetime(config.max_proposal_lifetime)
+        .with_max_vote_age(config.max_vote_age)
     }
 }
 
@@ -89,16 +164,145 @@ impl ConsensusConfig {
     /// Set consensus threshold (validated) and return the updated config.
     pub fn with_threshold(mut self, consensus_threshold: f64) -> Result<Self, ConsensusError> {
         crate::utils::validate_threshold(consensus_threshold)?;
-        self.consensus_threshold = consensus_threshold;
+        self.threshold_mode = ThresholdMode::Fraction(consensus_threshold);
         Ok(self)
     }
 
+    /// Set the threshold mode (validated) and return the updated config.
+    ///
+    /// [`ThresholdMode::AbsoluteYes`] is checked against each proposal's
+    /// expected voters count when its session is created.
+    pub fn with_threshold_mode(mut self, mode: ThresholdMode) -> Result<Self, ConsensusError> {
+        mode.validate()?;
+        self.threshold_mode = mode;
+        Ok(self)
+    }
+
+    /// Weigh each vote by its owner's entry in `voter_weights` (default 1)
+    /// and return the updated config.
+    ///
+    /// The threshold then applies to the total possible weight rather than
+    /// the expected voters count.
+    pub fn with_voter_weights(mut self, voter_weights: VoterWeights) -> Self {
+        self.voter_weights = voter_weights;
+        self
+    }
+
+    /// Hash the votes of proposals created with this config using `hash_algo`
+    /// and return the updated config.
+    ///
+    /// The algorithm is written into the proposal header, so peers validate
+    /// the votes with it whatever their own configuration says.
+    pub fn with_hash_algo(mut self, hash_algo: HashAlgo) -> Self {
+        self.hash_algo = hash_algo;
+        self
+    }
+
+    fn with_threshold_mode_unchecked(mut self, mode: ThresholdMode) -> Self {
+        self.threshold_mode = mode;
+        self
+    }
+
     /// Set liveness criteria and return the updated config.
     pub fn with_liveness_criteria(mut self, liveness_criteria: bool) -> Self {
         self.liveness_criteria = liveness_criteria;
         self
     }
 
+    /// Set whether a tie at timeout with partial turnout resolves to the liveness
+    /// default (instead of failing) and return the updated config.
+    pub fn with_resolve_partial_tie_on_timeout(mut self, resolve: bool) -> Self {
+        self.resolve_partial_tie_on_timeout = resolve;
+        self
+    }
+
+    /// Set how a YES/NO tie at full turnout is decided (`None` follows each
+    /// proposal's liveness criteria) and return the updated config.
+    pub fn with_tie_break(mut self, tie_break: Option<TieBreak>) -> Self {
+        self.tie_break = tie_break;
+        self
+    }
+
+    /// Set whether non-canonical (malleated) vote signatures are rejected and
+    /// return the updated config.
+    pub fn with_strict_signatures(mut self, strict_signatures: bool) -> Self {
+        self.strict_signatures = strict_signatures;
+        self
+    }
+
+    /// Set whether the proposal owner may vote on their own proposal and
+    /// return the updated config.
+    pub fn with_allow_owner_vote(mut self, allow_owner_vote: bool) -> Self {
+        self.allow_owner_vote = allow_owner_vote;
+        self
+    }
+
+    /// Restrict voting to `allowed_voters` (identity bytes; `None` accepts
+    /// every voter) and return the updated config.
+    pub fn with_allowed_voters(mut self, allowed_voters: Option<HashSet<Vec<u8>>>) -> Self {
+        self.allowed_voters = allowed_voters.map(Arc::new);
+        self
+    }
+
+    /// [`with_allowed_voters`](Self::with_allowed_voters) for a set shared
+    /// with the scope config, so sessions do not each hold a copy.
+    pub(crate) fn with_shared_allowed_voters(
+        mut self,
+        allowed_voters: Option<Arc<HashSet<Vec<u8>>>>,
+    ) -> Self {
+        self.allowed_voters = allowed_voters;
+        self
+    }
+
+    /// Set the per-session vote cap as a multiple of the expected voters count
+    /// and return the updated config. `0` is treated as `1`.
+    pub fn with_max_votes_multiplier(mut self, max_votes_multiplier: u32) -> Self {
+        self.max_votes_multiplier = max_votes_multiplier.max(1);
+        self
+    }
+
+    /// Set the absolute per-session vote cap and return the updated config.
+    /// `0` is treated as `1`.
+    pub fn with_max_votes_per_proposal(mut self, max_votes_per_proposal: usize) -> Self {
+        self.max_votes_per_proposal = max_votes_per_proposal.max(1);
+        self
+    }
+
+    /// Set whether votes arriving after the session is terminal are recorded
+    /// as late votes (instead of rejected) and return the updated config.
+    pub fn with_accept_late_votes(mut self, accept_late_votes: bool) -> Self {
+        self.accept_late_votes = accept_late_votes;
+        self
+    }
+
+    /// Set whether a voter may replace their vote on an active session and
+    /// return the updated config.
+    pub fn with_allow_vote_changes(mut self, allow_vote_changes: bool) -> Self {
+        self.allow_vote_changes = allow_vote_changes;
+        self
+    }
+
+    /// Set how a repeated vote from the same owner is handled and return the
+    /// updated config.
+    pub fn with_duplicate_vote_policy(mut self, policy: DuplicateVotePolicy) -> Self {
+        self.duplicate_vote_policy = policy;
+        self
+    }
+
+    /// Set how far in the future an inbound proposal may expire and return
+    /// the updated config.
+    pub fn with_max_proposal_lifetime(mut self, max_proposal_lifetime: Duration) -> Self {
+        self.max_proposal_lifetime = max_proposal_lifetime;
+        self
+    }
+
+    /// Set how old a vote's timestamp may be when it is received and return
+    /// the updated config.
+    pub fn with_max_vote_age(mut self, max_vote_age: Duration) -> Self {
+        self.max_vote_age = max_vote_age;
+        self
+    }
+
     /// Create a new ConsensusConfig with the given values.
     /// This is used internally for scope configuration conversion.
     pub(crate) fn new(
@@ -109,19 +313,47 @@ impl ConsensusConfig {
         liveness_criteria: bool,
     ) -> Self {
         Self {
-            consensus_threshold,
+            threshold_mode: ThresholdMode::Fraction(consensus_threshold),
+            voter_weights: VoterWeights::default(),
+            hash_algo: HashAlgo::default(),
             consensus_timeout,
             max_rounds,
             use_gossipsub_rounds,
             liveness_criteria,
+            resolve_partial_tie_on_timeout: false,
+            tie_break: None,
+            strict_signatures: true,
+            allow_owner_vote: true,
+            allowed_voters: None,
+            max_votes_multiplier: DEFAULT_MAX_VOTES_MULTIPLIER,
+            max_votes_per_proposal: DEFAULT_MAX_VOTES_PER_PROPOSAL,
+            accept_late_votes: false,
+            allow_vote_changes: false,
+            duplicate_vote_policy: DuplicateVotePolicy::Reject,
+            max_proposal_lifetime: DEFAULT_MAX_PROPOSAL_LIFETIME,
+            max_vote_age: DEFAULT_MAX_VOTE_AGE,
         }
     }
 
+    /// Apply the proposal-derived timeout and liveness criteria, keeping every other setting.
+    pub(crate) fn with_proposal_fields(
+        mut self,
+        consensus_timeout: Duration,
+        liveness_criteria: bool,
+    ) -> Self {
+        self.consensus_timeout = consensus_timeout;
+        self.liveness_criteria = liveness_criteria;
+        self
+    }
+
     fn max_round_limit(&self, expected_voters_count: u32) -> u32 {
         if self.use_gossipsub_rounds {
             self.max_rounds
+        } else if self.max_rounds == 0 && !self.voter_weights.is_empty() {
+            // Low-weight voters may all be needed to reach a weighted threshold.
+            expected_voters_count
         } else if self.max_rounds == 0 {
-            calculate_max_rounds(expected_voters_count, self.consensus_threshold)
+            calculate_max_rounds(expected_voters_count, self.threshold_mode)
         } else {
             self.max_rounds
         }
@@ -133,8 +365,29 @@ impl ConsensusConfig {
     }
 
     /// Fraction of expected voters required before consensus can be determined.
+    ///
+    /// Under [`ThresholdMode::AbsoluteYes`] there is no fraction and this
+    /// returns `1.0`; read [`threshold_mode`](Self::threshold_mode) instead.
     pub fn consensus_threshold(&self) -> f64 {
-        self.consensus_threshold
+        match self.threshold_mode {
+            ThresholdMode::Fraction(fraction) => fraction,
+            ThresholdMode::AbsoluteYes(_) => 1.0,
+        }
+    }
+
+    /// How many votes decide the proposal.
+    pub fn threshold_mode(&self) -> ThresholdMode {
+        self.threshold_mode
+    }
+
+    /// Per-voter stake weights (empty when every vote counts once).
+    pub fn voter_weights(&self) -> &VoterWeights {
+        &self.voter_weights
+    }
+
+    /// Hash function for the votes of this config's proposals.
+    pub fn hash_algo(&self) -> HashAlgo {
+        self.hash_algo
     }
 
     /// Whether silent peers are counted as YES (`true`) or NO (`false`).
@@ -151,9 +404,121 @@ impl ConsensusConfig {
     pub fn use_gossipsub_rounds(&self) -> bool {
         self.use_gossipsub_rounds
     }
+
+    /// Whether a tie at timeout with partial turnout resolves to the liveness default.
+    pub fn resolve_partial_tie_on_timeout(&self) -> bool {
+        self.resolve_partial_tie_on_timeout
+    }
+
+    /// How a YES/NO tie at full turnout is decided (`None` when each
+    /// proposal's liveness criteria decide).
+    pub fn tie_break(&self) -> Option<TieBreak> {
+        self.tie_break
+    }
+
+    /// What `votes` on `proposal` decide under this config, with or without
+    /// the timeout weighting. A YES/NO tie at full turnout goes to the
+    /// [`TieBreak`]; under [`TieBreak::Fail`] it stays undecided.
+    pub(crate) fn result_for_votes(
+        &self,
+        proposal: &Proposal,
+        votes: &HashMap<Vec<u8>, Vote>,
+        counts: VoteCounts,
+        is_timeout: bool,
+    ) -> Option<bool> {
+        let expected_voters = proposal.expected_voters_count;
+        let liveness = proposal.liveness_criteria_yes;
+        if !is_full_turnout_tie(
+            votes,
+            counts,
+            expected_voters,
+            &self.voter_weights,
+            self.threshold_mode,
+        ) {
+            return consensus_result_for_counts(
+                votes,
+                counts,
+                expected_voters,
+                &self.voter_weights,
+                self.threshold_mode,
+                liveness,
+                is_timeout,
+            );
+        }
+        match self.tie_break.unwrap_or(TieBreak::from(liveness)) {
+            TieBreak::LivenessYes => Some(true),
+            TieBreak::LivenessNo => Some(false),
+            TieBreak::OwnerVote => match votes.get(&proposal.proposal_owner).map(Vote::choice) {
+                Some(VoteChoice::Yes) => Some(true),
+                Some(VoteChoice::No) => Some(false),
+                _ => Some(liveness),
+            },
+            TieBreak::Fail => None,
+        }
+    }
+
+    /// Whether votes with non-canonical signatures are rejected.
+    pub fn strict_signatures(&self) -> bool {
+        self.strict_signatures
+    }
+
+    /// Whether the proposal owner may vote on their own proposal.
+    pub fn allow_owner_vote(&self) -> bool {
+        self.allow_owner_vote
+    }
+
+    /// Voter identities allowed to vote (`None` when every voter is accepted).
+    pub fn allowed_voters(&self) -> Option<&HashSet<Vec<u8>>> {
+        self.allowed_voters.as_deref()
+    }
+
+    /// Per-session vote cap as a multiple of the expected voters count.
+    pub fn max_votes_multiplier(&self) -> u32 {
+        self.max_votes_multiplier
+    }
+
+    /// Absolute per-session vote cap, independent of the expected voters count.
+    pub fn max_votes_per_proposal(&self) -> usize {
+        self.max_votes_per_proposal
+    }
+
+    /// Whether votes on a terminal session are recorded as late votes.
+    pub fn accept_late_votes(&self) -> bool {
+        self.accept_late_votes
+    }
+
+    /// Whether a voter may replace their vote on an active session.
+    pub fn allow_vote_changes(&self) -> bool {
+        self.allow_vote_changes
+    }
+
+    /// How a repeated vote from the same owner is handled.
+    pub fn duplicate_vote_policy(&self) -> DuplicateVotePolicy {
+        self.duplicate_vote_policy
+    }
+
+    /// Furthest in the future (relative to receipt) an inbound proposal may expire.
+    pub fn max_proposal_lifetime(&self) -> Duration {
+        self.max_proposal_lifetime
+    }
+
+    /// Oldest a vote's timestamp may be (relative to receipt) to be accepted.
+    pub fn max_vote_age(&self) -> Duration {
+        self.max_vote_age
+    }
+
+    /// Maximum number of votes a session with `expected_voters_count` may store:
+    /// the multiplier-based cap, bounded by
+    /// [`max_votes_per_proposal`](Self::max_votes_per_proposal).
+    pub fn max_votes(&self, expected_voters_count: u32) -> usize {
+        (expected_voters_count as usize)
+            .saturating_mul(self.max_votes_multiplier as usize)
+            .min(self.max_votes_per_proposal)
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ConsensusState {
     /// Votes still accepted.
     Active,
@@ -164,17 +529,41 @@ pub enum ConsensusState {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConsensusSession {
     /// Current snapshot of the proposal including aggregated votes.
     pub proposal: Proposal,
     /// Session state tracking whether voting is still open.
     pub state: ConsensusState,
     /// Map of vote owner -> vote to enforce single vote per participant.
-    pub votes: HashMap<Vec<u8>, Vote>, // vote_owner -> Vote
+    /// Private so every change goes through the methods that keep
+    /// `vote_counts` in sync; read it with [`votes`](Self::votes).
+    #[cfg_attr(feature = "serde", serde(with = "votes_by_owner"))]
+    votes: HashMap<Vec<u8>, Vote>, // vote_owner -> Vote
+    /// Votes received after the session became terminal, kept only when
+    /// [`ConsensusConfig::accept_late_votes`] is set. They never affect the
+    /// result and are not added to the proposal.
+    #[cfg_attr(feature = "serde", serde(with = "votes_by_owner"))]
+    pub late_votes: HashMap<Vec<u8>, Vote>,
+    /// Local application metadata (correlation IDs, source topic, ...).
+    /// Never gossiped, hashed, or consulted for consensus.
+    pub annotations: HashMap<String, String>,
     /// Seconds since Unix epoch when the session was created.
     pub created_at: u64,
+    /// Seconds since Unix epoch when the session reached consensus or failed.
+    /// `None` while active.
+    pub finalized_at: Option<u64>,
     /// Per-session runtime configuration.
     pub config: ConsensusConfig,
+    /// Per-choice counts of `votes`, updated as votes are recorded; `None`
+    /// until first needed after deserialization.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    vote_counts: Option<VoteCounts>,
+    /// Whether finalization is paused in the session's scope. Never stored:
+    /// the service reads the scope's pause state from storage and sets this
+    /// only for the operation at hand.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    finalization_paused: bool,
 }
 
 impl ConsensusSession {
@@ -186,8 +575,13 @@ impl ConsensusSession {
             proposal,
             state: ConsensusState::Active,
             votes: HashMap::new(),
+            late_votes: HashMap::new(),
+            annotations: HashMap::new(),
             created_at: now,
+            finalized_at: None,
             config,
+            vote_counts: Some(VoteCounts::default()),
+            finalization_paused: false,
         }
     }
 
@@ -200,7 +594,88 @@ impl ConsensusSession {
         config: ConsensusConfig,
         now: u64,
     ) -> Result<(Self, SessionTransition), ConsensusError> {
-        validate_proposal::<Signer>(&proposal, now)?;
+        Self::from_proposal_with_eligibility::<Signer>(proposal, config, now, &AcceptAllEligibility)
+    }
+
+    /// Like [`from_proposal`](Self::from_proposal), but checks every embedded
+    /// vote's eligibility proof with `eligibility`.
+    pub fn from_proposal_with_eligibility<Signer: ConsensusSignatureScheme>(
+        proposal: Proposal,
+        config: ConsensusConfig,
+        now: u64,
+        eligibility: &dyn EligibilityVerifier,
+    ) -> Result<(Self, SessionTransition), ConsensusError> {
+        Self::from_proposal_with_policies::<Signer>(
+            proposal,
+            config,
+            now,
+            eligibility,
+            &RfcTimestampPolicy,
+        )
+    }
+
+    /// Like [`from_proposal_with_eligibility`](Self::from_proposal_with_eligibility),
+    /// but also checks every embedded vote's timestamp with `timestamps`.
+    pub fn from_proposal_with_policies<Signer: ConsensusSignatureScheme>(
+        proposal: Proposal,
+        config: ConsensusConfig,
+        now: u64,
+        eligibility: &dyn EligibilityVerifier,
+        timestamps: &dyn TimestampPolicy,
+    ) -> Result<(Self, SessionTransition), ConsensusError> {
+        Self::from_proposal_in_scope::<Signer>(
+            proposal,
+            config,
+            now,
+            eligibility,
+            timestamps,
+            false,
+        )
+    }
+
+    /// [`from_proposal_with_policies`](Self::from_proposal_with_policies) for
+    /// a scope whose finalization may be paused, in which case the embedded
+    /// votes are recorded without deciding the session.
+    pub(crate) fn from_proposal_in_scope<Signer: ConsensusSignatureScheme>(
+        proposal: Proposal,
+        config: ConsensusConfig,
+        now: u64,
+        eligibility: &dyn EligibilityVerifier,
+        timestamps: &dyn TimestampPolicy,
+        finalization_paused: bool,
+    ) -> Result<(Self, SessionTransition), ConsensusError> {
+        // The header, not local config, decides how this proposal's votes hash.
+        let config = config.with_hash_algo(proposal.hash_algo()?);
+        // Refuse oversized vote lists before verifying a single signature.
+        let max_votes = config.max_votes(proposal.expected_voters_count);
+        if proposal.votes.len() > max_votes {
+            return Err(ConsensusError::TooManyVotes { max_votes });
+        }
+        let checks = VoteChecks {
+            eligibility,
+            timestamps,
+            strict_signatures: config.strict_signatures,
+            max_proposal_lifetime: config.max_proposal_lifetime,
+            max_vote_age: config.max_vote_age,
+        };
+        validate_proposal_with::<Signer>(&proposal, now, &checks)?;
+        validate_declared_round(&proposal, &config)?;
+        if let Some(allowed_voters) = &config.allowed_voters
+            && proposal.expected_voters_count as usize > allowed_voters.len()
+        {
+            return Err(ConsensusError::ExpectedVotersExceedAllowlist {
+                expected_voters_count: proposal.expected_voters_count,
+                allowed_voters: allowed_voters.len(),
+            });
+        }
+        if config.voter_weights.is_empty() {
+            config
+                .threshold_mode
+                .validate_for(proposal.expected_voters_count)?;
+        } else {
+            // Weighted: an absolute threshold is a YES weight, not a voter count.
+            config.threshold_mode.validate()?;
+        }
 
         // Create clean proposal for session (votes will be added via initialize_with_votes)
         let existing_votes = proposal.votes.clone();
@@ -210,104 +685,285 @@ impl ConsensusSession {
         clean_proposal.round = 1;
 
         let mut session = Self::new(clean_proposal, config, now);
-        let transition = session.initialize_with_votes::<Signer>(
-            existing_votes,
-            proposal.expiration_timestamp,
-            proposal.timestamp,
-            now,
-        )?;
+        session.set_finalization_paused(finalization_paused);
+        let transition = session.initialize_with_votes::<Signer>(existing_votes, now, &checks)?;
+        session.set_finalization_paused(false);
 
         Ok((session, transition))
     }
 
     /// Add a vote to the session. Expiration is checked against `now`
     /// (seconds since Unix epoch).
+    ///
+    /// If the session has already reached consensus or failed, the vote is
+    /// stored in [`late_votes`](Self::late_votes) when the config accepts late
+    /// votes, and rejected with
+    /// [`SessionTerminal`](ConsensusError::SessionTerminal) otherwise.
+    ///
+    /// Under [`DuplicateVotePolicy::Ignore`], a vote identical to the one the
+    /// owner already cast on an active session (same `vote_hash`) is a no-op
+    /// returning [`StillActive`](SessionTransition::StillActive).
+    ///
+    /// A vote that would exceed the round limit is not counted: the session
+    /// fails and [`Failed`](SessionTransition::Failed) with
+    /// [`FailReason::MaxRoundsExceeded`] is returned, so the failure is stored
+    /// along with the session.
     pub(crate) fn add_vote(
         &mut self,
         vote: Vote,
         now: u64,
     ) -> Result<SessionTransition, ConsensusError> {
-        match self.state {
-            ConsensusState::Active => {
-                validate_proposal_timestamp(self.proposal.expiration_timestamp, now)?;
-
-                // Check if adding this vote would exceed round limits
-                self.check_round_limit(1)?;
-
-                if self.votes.contains_key(&vote.vote_owner) {
-                    return Err(ConsensusError::DuplicateVote);
-                }
-                self.votes.insert(vote.vote_owner.clone(), vote.clone());
-                self.proposal.votes.push(vote.clone());
+        self.ensure_accepting_votes()?;
+        validate_proposal_timestamp(self.proposal.expiration_timestamp, now)?;
+        if !self.is_active() {
+            return self.add_late_vote(vote);
+        }
+        if self.config.duplicate_vote_policy == DuplicateVotePolicy::Ignore
+            && self
+                .votes
+                .get(&vote.vote_owner)
+                .is_some_and(|held| held.vote_hash == vote.vote_hash)
+        {
+            return Ok(SessionTransition::StillActive);
+        }
+        if self.config.allow_vote_changes && self.votes.contains_key(&vote.vote_owner) {
+            return self.replace_vote(vote, now);
+        }
+        self.check_voter(&vote.vote_owner)?;
+        self.check_equivocation(&vote)?;
 
-                self.update_round(1);
-                Ok(self.check_consensus())
-            }
-            ConsensusState::ConsensusReached(res) => Ok(SessionTransition::ConsensusReached(res)),
-            _ => Err(ConsensusError::SessionNotActive),
+        self.check_vote_capacity(1)?;
+        if self.votes.contains_key(&vote.vote_owner) {
+            return Err(ConsensusError::DuplicateVote);
+        }
+        // A vote past the round limit fails the session instead of counting.
+        if self.exceeds_round_limit(1) {
+            self.state = ConsensusState::Failed;
+            self.finalized_at = Some(now);
+            return Ok(SessionTransition::Failed(FailReason::MaxRoundsExceeded));
         }
+        self.record_vote(vote.clone());
+        self.proposal.votes.push(vote);
+
+        self.update_round(1);
+        Ok(self.check_consensus(now))
     }
 
     /// Initialize session with multiple votes, validating all before adding any.
     /// Validates duplicates, vote chain, and individual votes, then adds all atomically.
+    /// Vote changes in the list replace the owner's earlier vote, as
+    /// [`replace_vote`](Self::replace_vote) does, and the round counts distinct voters.
     pub(crate) fn initialize_with_votes<Signer: ConsensusSignatureScheme>(
         &mut self,
         votes: Vec<Vote>,
-        expiration_timestamp: u64,
-        creation_time: u64,
         now: u64,
+        checks: &VoteChecks,
     ) -> Result<SessionTransition, ConsensusError> {
-        if !matches!(self.state, ConsensusState::Active) {
-            return Err(ConsensusError::SessionNotActive);
-        }
+        self.ensure_active()?;
 
-        validate_proposal_timestamp(expiration_timestamp, now)?;
+        validate_proposal_timestamp(self.proposal.expiration_timestamp, now)?;
 
         if votes.is_empty() {
             return Ok(SessionTransition::StillActive);
         }
 
-        let mut seen_owners = std::collections::HashSet::new();
+        // An owner may appear again only as a vote change (see
+        // `replace_vote`): allowed by the config and chained to the owner's
+        // previous vote through its `parent_hash`.
+        let mut latest_by_owner: HashMap<&[u8], &[u8]> = HashMap::new();
         for vote in &votes {
-            if !seen_owners.insert(&vote.vote_owner) {
-                return Err(ConsensusError::DuplicateVote);
+            match latest_by_owner.insert(&vote.vote_owner, &vote.vote_hash) {
+                Some(previous)
+                    if self.config.allow_vote_changes && vote.parent_hash == previous => {}
+                Some(_) => return Err(ConsensusError::DuplicateVote),
+                None => {}
             }
         }
+        let voter_count = latest_by_owner.len();
 
-        // Each distinct voter can vote at most once, so the batch size
+        // Each distinct voter counts once, so the number of voters
         // is bounded by expected_voters_count (u32). Reject early if violated.
-        if votes.len() > self.proposal.expected_voters_count as usize {
-            self.state = ConsensusState::Failed;
-            return Err(ConsensusError::MaxRoundsExceeded);
+        if voter_count > self.proposal.expected_voters_count as usize {
+            return Err(ConsensusError::TooManyVotes {
+                max_votes: self.proposal.expected_voters_count as usize,
+            });
         }
 
+        self.check_vote_capacity(votes.len())?;
         validate_vote_chain(&votes)?;
         for vote in &votes {
-            validate_vote::<Signer>(vote, expiration_timestamp, creation_time, now)?;
+            self.check_voter(&vote.vote_owner)?;
+            validate_vote::<Signer>(vote, &self.proposal, now, checks)?;
         }
 
-        self.check_round_limit(votes.len())?;
-        self.update_round(votes.len());
+        if self.exceeds_round_limit(voter_count) {
+            return Err(ConsensusError::MaxRoundsExceeded);
+        }
+        self.update_round(voter_count);
 
         for vote in votes {
-            self.votes.insert(vote.vote_owner.clone(), vote.clone());
+            self.record_vote(vote.clone());
             self.proposal.votes.push(vote);
         }
 
-        Ok(self.check_consensus())
+        Ok(self.check_consensus(now))
+    }
+
+    /// Replace the owner's current vote with `vote` and re-check consensus.
+    ///
+    /// Requires [`ConsensusConfig::allow_vote_changes`] (otherwise
+    /// [`UserAlreadyVoted`](ConsensusError::UserAlreadyVoted)) and that `vote`
+    /// chains to the replaced vote through its `parent_hash`. The new vote is
+    /// appended to the proposal so the chain stays valid; the round is left
+    /// unchanged since no new voter joined. The proposal's vote list is still
+    /// capped by [`ConsensusConfig::max_votes`].
+    pub(crate) fn replace_vote(
+        &mut self,
+        vote: Vote,
+        now: u64,
+    ) -> Result<SessionTransition, ConsensusError> {
+        self.ensure_active()?;
+        validate_proposal_timestamp(self.proposal.expiration_timestamp, now)?;
+        let previous = self
+            .votes
+            .get(&vote.vote_owner)
+            .ok_or(ConsensusError::VoteNotFound)?;
+        if !self.config.allow_vote_changes {
+            return Err(ConsensusError::UserAlreadyVoted);
+        }
+        if vote.parent_hash != previous.vote_hash {
+            return Err(ConsensusError::ParentHashMismatch);
+        }
+        let max_votes = self.config.max_votes(self.proposal.expected_voters_count);
+        if self.proposal.votes.len() >= max_votes {
+            return Err(ConsensusError::TooManyVotes { max_votes });
+        }
+
+        self.record_vote(vote.clone());
+        self.proposal.votes.push(vote);
+        Ok(self.check_consensus(now))
+    }
+
+    /// Remove `vote_owner`'s vote and re-check consensus at `now`.
+    ///
+    /// Only the most recent vote can be retracted: any earlier vote is the
+    /// `received_hash` of a later one, so removing it would break the chain
+    /// and fails with [`VoteReferenced`](ConsensusError::VoteReferenced).
+    /// The round is rolled back as if the vote had never been added. If the
+    /// retracted vote replaced an earlier one (see
+    /// [`replace_vote`](Self::replace_vote)), that earlier vote counts again.
+    pub(crate) fn retract_vote(
+        &mut self,
+        vote_owner: &[u8],
+        now: u64,
+    ) -> Result<SessionTransition, ConsensusError> {
+        self.ensure_active()?;
+        let vote_hash = match self.votes.get(vote_owner) {
+            Some(vote) => vote.vote_hash.clone(),
+            None => return Err(ConsensusError::VoteNotFound),
+        };
+        if self.proposal.votes.last().map(|v| &v.vote_hash) != Some(&vote_hash) {
+            return Err(ConsensusError::VoteReferenced);
+        }
+
+        self.proposal.votes.pop();
+        let earlier = self
+            .proposal
+            .votes
+            .iter()
+            .rfind(|v| v.vote_owner == vote_owner)
+            .cloned();
+        if let Some(earlier) = earlier {
+            self.record_vote(earlier);
+            return Ok(self.check_consensus(now));
+        }
+        self.forget_vote(vote_owner);
+        if self.config.use_gossipsub_rounds {
+            if self.proposal.votes.is_empty() {
+                self.proposal.round = 1;
+            }
+        } else {
+            self.proposal.round = self.proposal.round.saturating_sub(1).max(1);
+        }
+        Ok(self.check_consensus(now))
+    }
+
+    fn add_late_vote(&mut self, vote: Vote) -> Result<SessionTransition, ConsensusError> {
+        self.check_voter(&vote.vote_owner)?;
+        self.check_equivocation(&vote)?;
+        if self.has_voted(&vote.vote_owner) {
+            return Err(ConsensusError::DuplicateVote);
+        }
+        self.check_vote_capacity(1)?;
+        self.late_votes.insert(vote.vote_owner.clone(), vote);
+        Ok(SessionTransition::LateVoteRecorded)
+    }
+
+    /// Set (or replace) a local annotation on this session.
+    ///
+    /// Returns [`AnnotationLimitExceeded`](ConsensusError::AnnotationLimitExceeded)
+    /// if the key or value is longer than [`MAX_ANNOTATION_LEN`] bytes, or a new
+    /// key would exceed [`MAX_ANNOTATIONS`].
+    pub fn annotate(&mut self, key: String, value: String) -> Result<(), ConsensusError> {
+        if key.len() > MAX_ANNOTATION_LEN
+            || value.len() > MAX_ANNOTATION_LEN
+            || (self.annotations.len() >= MAX_ANNOTATIONS && !self.annotations.contains_key(&key))
+        {
+            return Err(ConsensusError::AnnotationLimitExceeded);
+        }
+        self.annotations.insert(key, value);
+        Ok(())
+    }
+
+    /// Run the checks a vote from `vote_owner` must pass at `now`, short of
+    /// building and verifying the vote itself.
+    ///
+    /// Returns [`UserAlreadyVoted`](ConsensusError::UserAlreadyVoted) for a
+    /// repeat voter, and the same errors [`add_vote`](Self::add_vote) would for
+    /// a terminal or expired session, a forbidden owner vote, or a full session.
+    pub(crate) fn precheck_vote(&self, vote_owner: &[u8], now: u64) -> Result<(), ConsensusError> {
+        self.ensure_accepting_votes()?;
+        validate_proposal_timestamp(self.proposal.expiration_timestamp, now)?;
+        if self.has_voted(vote_owner) {
+            return Err(ConsensusError::UserAlreadyVoted);
+        }
+        self.check_voter(vote_owner)?;
+        self.check_vote_capacity(1)
+    }
+
+    /// Current vote of each voter, keyed by vote owner. Late votes are in
+    /// [`late_votes`](Self::late_votes).
+    pub fn votes(&self) -> &HashMap<Vec<u8>, Vote> {
+        &self.votes
+    }
+
+    /// Whether `vote_owner` already has a vote (on time or late) in this session.
+    pub fn has_voted(&self, vote_owner: &[u8]) -> bool {
+        self.votes.contains_key(vote_owner) || self.late_votes.contains_key(vote_owner)
+    }
+
+    /// Reject votes that would grow the session past
+    /// [`ConsensusConfig::max_votes`]. Unlike the round limit, this leaves the
+    /// session state untouched: flooding is refused, not treated as failure.
+    fn check_vote_capacity(&self, vote_count: usize) -> Result<(), ConsensusError> {
+        let max_votes = self.config.max_votes(self.proposal.expected_voters_count);
+        let stored = self.votes.len() + self.late_votes.len();
+        if stored.saturating_add(vote_count) > max_votes {
+            return Err(ConsensusError::TooManyVotes { max_votes });
+        }
+        Ok(())
     }
 
-    /// Check if adding votes would exceed round limits.
+    /// Whether adding `vote_count` votes would exceed the round limit.
     ///
     /// Unifies logic for both single-vote and batch processing:
     /// - For a single vote, pass `vote_count: 1`.
     /// - For P2P: Calculates `(current_round - 1) + vote_count`.
     /// - For Gossipsub: Moves to Round 2 if `vote_count > 0`.
-    fn check_round_limit(&mut self, vote_count: usize) -> Result<(), ConsensusError> {
-        // vote_count cannot exceed expected_voters_count (u32); reject if it does
+    fn exceeds_round_limit(&self, vote_count: usize) -> bool {
+        // vote_count cannot exceed expected_voters_count (u32)
         if vote_count > self.proposal.expected_voters_count as usize {
-            self.state = ConsensusState::Failed;
-            return Err(ConsensusError::MaxRoundsExceeded);
+            return true;
         }
 
         // Determine the value to compare against the limit based on configuration
@@ -331,16 +987,10 @@ impl ConsensusSession {
             current_votes.saturating_add(vote_count as u32)
         };
 
-        if projected_value
+        projected_value
             > self
                 .config
                 .max_round_limit(self.proposal.expected_voters_count)
-        {
-            self.state = ConsensusState::Failed;
-            return Err(ConsensusError::MaxRoundsExceeded);
-        }
-
-        Ok(())
     }
 
     /// Update round after adding votes.
@@ -367,18 +1017,31 @@ impl ConsensusSession {
 
     /// RFC Section 4 (Liveness): Check if consensus reached
     /// - n > 2: need >n/2 YES votes among at least 2n/3 distinct peers
-    /// - n ≤ 2: require unanimous YES votes
-    /// - Equality: use liveness_criteria_yes
-    fn check_consensus(&mut self) -> SessionTransition {
-        let expected_voters = self.proposal.expected_voters_count;
-        let threshold = self.config.consensus_threshold;
-        let liveness = self.proposal.liveness_criteria_yes;
-
-        match calculate_consensus_result(&self.votes, expected_voters, threshold, liveness, false) {
+    /// - n = 1: the single voter's choice decides (YES or NO)
+    /// - n = 2: require unanimous YES votes
+    /// - Equality at full turnout: the config's [`TieBreak`], by default
+    ///   liveness_criteria_yes; [`TieBreak::Fail`] fails the session
+    /// - [`ThresholdMode::AbsoluteYes`]`(k)`: YES at `k` YES votes, NO once
+    ///   fewer than `k` peers could still vote YES
+    ///
+    /// While finalization is paused the session always stays active.
+    /// Reaching consensus, or failing on a tie, records `now` as
+    /// [`finalized_at`](Self::finalized_at).
+    fn check_consensus(&mut self, now: u64) -> SessionTransition {
+        if self.finalization_paused {
+            return SessionTransition::StillActive;
+        }
+        match self.current_result(false) {
             Some(result) => {
                 self.state = ConsensusState::ConsensusReached(result);
+                self.finalized_at = Some(now);
                 SessionTransition::ConsensusReached(result)
             }
+            None if self.config.tie_break == Some(TieBreak::Fail) && self.is_full_turnout_tie() => {
+                self.state = ConsensusState::Failed;
+                self.finalized_at = Some(now);
+                SessionTransition::Failed(FailReason::Tie)
+            }
             None => {
                 self.state = ConsensusState::Active;
                 SessionTransition::StillActive
@@ -386,11 +1049,108 @@ impl ConsensusSession {
         }
     }
 
+    /// Whether finalization is paused for the operation at hand.
+    pub(crate) fn finalization_paused(&self) -> bool {
+        self.finalization_paused
+    }
+
+    /// Pause or resume finalization for the operation at hand. The service
+    /// sets this from the scope's stored pause state and clears it afterwards.
+    pub(crate) fn set_finalization_paused(&mut self, paused: bool) {
+        self.finalization_paused = paused;
+    }
+
+    /// Re-check an active session's recorded votes, e.g. after finalization
+    /// is resumed. Terminal sessions are left untouched.
+    pub(crate) fn reevaluate(&mut self, now: u64) -> SessionTransition {
+        if !self.is_active() {
+            return SessionTransition::StillActive;
+        }
+        self.check_consensus(now)
+    }
+
+    /// Number of distinct peers with a recorded vote.
+    ///
+    /// This is the count quorum is measured against. It can only be lower than
+    /// [`vote_events`](Self::vote_events), never higher.
+    pub fn distinct_voters(&self) -> u32 {
+        self.votes.len() as u32
+    }
+
+    /// Number of raw vote events recorded in the proposal's vote list.
+    pub fn vote_events(&self) -> usize {
+        self.proposal.votes.len()
+    }
+
+    /// Return [`VoterNotEligible`](ConsensusError::VoterNotEligible) if the
+    /// config has an allowlist without `vote_owner`, and
+    /// [`OwnerCannotVote`](ConsensusError::OwnerCannotVote) if `vote_owner`
+    /// owns the proposal and the config forbids owner votes.
+    pub(crate) fn check_voter(&self, vote_owner: &[u8]) -> Result<(), ConsensusError> {
+        if let Some(allowed_voters) = &self.config.allowed_voters
+            && !allowed_voters.contains(vote_owner)
+        {
+            return Err(ConsensusError::VoterNotEligible);
+        }
+        if !self.config.allow_owner_vote && vote_owner == self.proposal.proposal_owner {
+            return Err(ConsensusError::OwnerCannotVote);
+        }
+        Ok(())
+    }
+
+    /// Reject `vote` with [`Equivocation`](ConsensusError::Equivocation) if
+    /// its owner already holds a vote with the opposite choice.
+    fn check_equivocation(&self, vote: &Vote) -> Result<(), ConsensusError> {
+        match self.held_vote(&vote.vote_owner) {
+            Some(held) if held.choice() != vote.choice() && held.vote_hash != vote.vote_hash => {
+                Err(ConsensusError::Equivocation {
+                    owner: vote.vote_owner.clone(),
+                    first_hash: held.vote_hash.clone(),
+                    second_hash: vote.vote_hash.clone(),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// The vote `vote_owner` currently has recorded, on time or late.
+    pub fn held_vote(&self, vote_owner: &[u8]) -> Option<&Vote> {
+        self.votes
+            .get(vote_owner)
+            .or_else(|| self.late_votes.get(vote_owner))
+    }
+
     /// Check if this proposal is still accepting votes.
     pub fn is_active(&self) -> bool {
         matches!(self.state, ConsensusState::Active)
     }
 
+    /// Return [`SessionTerminal`](ConsensusError::SessionTerminal) carrying the
+    /// current state unless the session is still accepting votes.
+    pub fn ensure_active(&self) -> Result<(), ConsensusError> {
+        if self.is_active() {
+            Ok(())
+        } else {
+            Err(ConsensusError::SessionTerminal { state: self.state })
+        }
+    }
+
+    /// Whether [`add_vote`](Self::add_vote) can still take a vote: the session
+    /// is active, or it is terminal and records late votes.
+    pub fn accepts_votes(&self) -> bool {
+        self.is_active() || self.config.accept_late_votes
+    }
+
+    /// Like [`ensure_active`](Self::ensure_active), but also passes terminal
+    /// sessions that record late votes.
+    pub(crate) fn ensure_accepting_votes(&self) -> Result<(), ConsensusError> {
+        if self.accepts_votes() {
+            Ok(())
+        } else {
+            Err(ConsensusError::SessionTerminal { state: self.state })
+        }
+    }
+
     /// Get the consensus result if one has been reached.
     ///
     /// Returns `Ok(true)` for YES, `Ok(false)` for NO, or `Err(ConsensusError::ConsensusNotReached)` if consensus
@@ -402,6 +1162,230 @@ impl ConsensusSession {
             Err(ConsensusError::ConsensusNotReached)
         }
     }
+
+    /// Get the decided result together with the vote counts behind it.
+    ///
+    /// `decided_by_liveness` is set when the YES and NO weights (silent peers
+    /// counted toward the liveness side) were tied, whether the tie was
+    /// broken in flight or at timeout. Returns
+    /// [`ConsensusNotReached`](ConsensusError::ConsensusNotReached) while
+    /// active and [`ConsensusFailed`](ConsensusError::ConsensusFailed) for a
+    /// failed session.
+    pub fn outcome(&self) -> Result<ConsensusOutcome, ConsensusError> {
+        let result = match self.state {
+            ConsensusState::ConsensusReached(result) => result,
+            ConsensusState::Failed => return Err(ConsensusError::ConsensusFailed),
+            ConsensusState::Active => return Err(ConsensusError::ConsensusNotReached),
+        };
+        let explanation = self.explain();
+        Ok(ConsensusOutcome {
+            result,
+            yes: explanation.yes_votes,
+            no: explanation.no_votes,
+            abstain: explanation.abstain_votes,
+            silent: explanation.silent_votes,
+            decided_by_liveness: matches!(
+                explanation.rule,
+                QuorumRule::WeightedMajority | QuorumRule::LivenessTieBreak
+            ) && explanation.yes_weight == explanation.no_weight,
+            round: self.proposal.round,
+        })
+    }
+
+    /// Get the fewest recorded votes that, applied to a fresh session, reach
+    /// the same consensus result.
+    ///
+    /// Votes on the deciding side come first (in arrival order), followed by
+    /// any opposing votes needed to meet quorum. Returns
+    /// [`ConsensusNotReached`](ConsensusError::ConsensusNotReached) if the
+    /// session has not reached consensus or its votes alone do not decide it
+    /// (e.g. the result came from a timeout).
+    pub fn minimal_finalizing_set(&self) -> Result<Vec<Vote>, ConsensusError> {
+        let result = self.get_consensus_result()?;
+        let (mut ordered, opposing): (Vec<&Vote>, Vec<&Vote>) = self
+            .proposal
+            .votes
+            .iter()
+            .partition(|vote| vote.choice() == VoteChoice::from(result));
+        ordered.extend(opposing);
+
+        let mut candidate = HashMap::new();
+        for (idx, vote) in ordered.iter().enumerate() {
+            candidate.insert(vote.vote_owner.clone(), (*vote).clone());
+            if self.result_for(&candidate, false) == Some(result) {
+                return Ok(ordered[..=idx].iter().map(|vote| (*vote).clone()).collect());
+            }
+        }
+        Err(ConsensusError::ConsensusNotReached)
+    }
+
+    /// What the recorded votes decide at timeout: the timeout weighting, then
+    /// (if configured) a partial-turnout tie resolved to the liveness default.
+    pub(crate) fn timeout_result(&self) -> Option<bool> {
+        let liveness = self.proposal.liveness_criteria_yes;
+        let expected_voters = self.proposal.expected_voters_count;
+        self.current_result(true).or_else(|| {
+            (self.config.resolve_partial_tie_on_timeout
+                && !self.is_full_turnout_tie()
+                && is_weighted_tie_at_timeout(
+                    &self.votes,
+                    expected_voters,
+                    &self.config.voter_weights,
+                    liveness,
+                ))
+            .then_some(liveness)
+        })
+    }
+
+    /// Recompute the result from the recorded votes: for an active session by
+    /// the in-flight rules only, and for a decided one falling back to
+    /// [`timeout_result`](Self::timeout_result) in case a timeout decided it.
+    pub(crate) fn recompute_result(&self) -> Option<bool> {
+        let in_flight = self.current_result(false);
+        if self.is_active() {
+            return in_flight;
+        }
+        in_flight.or_else(|| self.timeout_result())
+    }
+
+    /// Break down the quorum math for the recorded votes: turnout and choice
+    /// requirements, current weights, the rule in effect, and why the votes
+    /// have not decided the proposal yet (if they haven't).
+    pub fn explain(&self) -> ConsensusExplanation {
+        let mut explanation = explain_consensus_result(
+            &self.votes,
+            self.proposal.expected_voters_count,
+            &self.config.voter_weights,
+            self.config.threshold_mode,
+            self.proposal.liveness_criteria_yes,
+            self.state,
+        );
+        if self.is_full_turnout_tie() {
+            // The tie-break, not necessarily the liveness criteria, decides.
+            explanation.verdict = match self.current_result(false) {
+                Some(result) => QuorumVerdict::Decided(result),
+                None => QuorumVerdict::NoQualifiedMajority,
+            };
+        }
+        explanation
+    }
+
+    /// Record `vote` as its owner's current vote, replacing any earlier one.
+    fn record_vote(&mut self, vote: Vote) {
+        let counts = self
+            .vote_counts
+            .get_or_insert_with(|| VoteCounts::recount(&self.votes));
+        counts.add(vote.choice());
+        if let Some(previous) = self.votes.insert(vote.vote_owner.clone(), vote) {
+            counts.remove(previous.choice());
+        }
+    }
+
+    /// Drop `vote_owner`'s current vote.
+    fn forget_vote(&mut self, vote_owner: &[u8]) {
+        let counts = self
+            .vote_counts
+            .get_or_insert_with(|| VoteCounts::recount(&self.votes));
+        if let Some(previous) = self.votes.remove(vote_owner) {
+            counts.remove(previous.choice());
+        }
+    }
+
+    /// Whether the recorded votes are a YES/NO tie with every expected voter's
+    /// weight cast, the case the config's [`TieBreak`] decides.
+    fn is_full_turnout_tie(&self) -> bool {
+        is_full_turnout_tie(
+            &self.votes,
+            self.vote_counts(),
+            self.proposal.expected_voters_count,
+            &self.config.voter_weights,
+            self.config.threshold_mode,
+        )
+    }
+
+    /// Per-choice counts of the recorded votes, from the cache when present.
+    fn vote_counts(&self) -> VoteCounts {
+        let counts = self
+            .vote_counts
+            .unwrap_or_else(|| VoteCounts::recount(&self.votes));
+        debug_assert_eq!(counts, VoteCounts::recount(&self.votes));
+        counts
+    }
+
+    /// What the recorded votes decide under this session's threshold,
+    /// weights, liveness criteria, and tie-break, with or without the timeout
+    /// weighting.
+    fn current_result(&self, is_timeout: bool) -> Option<bool> {
+        self.config
+            .result_for_votes(&self.proposal, &self.votes, self.vote_counts(), is_timeout)
+    }
+
+    /// What `votes` decide under this session's threshold, weights, liveness
+    /// criteria, and tie-break, with or without the timeout weighting.
+    fn result_for(&self, votes: &HashMap<Vec<u8>, Vote>, is_timeout: bool) -> Option<bool> {
+        self.config.result_for_votes(
+            &self.proposal,
+            votes,
+            VoteCounts::recount(votes),
+            is_timeout,
+        )
+    }
+}
+
+/// Reject a received proposal whose `round` does not match its votes: at most
+/// 2 under Gossipsub rounds, exactly `1 +` the number of distinct voters under
+/// P2P rounds (a vote change does not advance the round).
+///
+/// The session recomputes the round from the votes either way; this only
+/// refuses proposals that were built inconsistently or crafted to push the
+/// counter toward overflow.
+fn validate_declared_round(
+    proposal: &Proposal,
+    config: &ConsensusConfig,
+) -> Result<(), ConsensusError> {
+    let consistent = if config.use_gossipsub_rounds {
+        proposal.round <= 2
+    } else {
+        let voters: HashSet<&[u8]> = proposal
+            .votes
+            .iter()
+            .map(|vote| vote.vote_owner.as_slice())
+            .collect();
+        u64::from(proposal.round) == 1 + voters.len() as u64
+    };
+    if consistent {
+        Ok(())
+    } else {
+        Err(ConsensusError::InvalidRound)
+    }
+}
+
+/// Serializes an owner-keyed vote map as a plain list of votes, since formats
+/// like JSON only accept string map keys. The keys are rebuilt from
+/// `vote_owner` on deserialization.
+#[cfg(feature = "serde")]
+mod votes_by_owner {
+    use std::collections::HashMap;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use crate::protos::consensus::v1::Vote;
+
+    pub fn serialize<S: Serializer>(
+        votes: &HashMap<Vec<u8>, Vote>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(votes.values())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<HashMap<Vec<u8>, Vote>, D::Error> {
+        Ok(Vec::<Vote>::deserialize(deserializer)?
+            .into_iter()
+            .map(|vote| (vote.vote_owner.clone(), vote))
+            .collect())
+    }
 }
 
 #[cfg(test)]
@@ -415,14 +1399,82 @@ mod tests {
         session::{ConsensusConfig, ConsensusSession, ConsensusState},
         signing::EthereumConsensusSigner,
         test_utils::now_ts,
-        types::CreateProposalRequest,
-        utils::build_vote,
+        types::{CreateProposalRequest, FailReason, SessionTransition, VoteChoice},
+        utils::{VoteChecks, VoteCounts, build_vote, calculate_consensus_result},
     };
 
     fn wrap(signer: PrivateKeySigner) -> EthereumConsensusSigner {
         EthereumConsensusSigner::new(signer)
     }
 
+    #[test]
+    fn cached_vote_counts_match_full_recount() {
+        let assert_in_sync = |session: &ConsensusSession| {
+            assert_eq!(
+                session.vote_counts,
+                Some(VoteCounts::recount(&session.votes))
+            );
+        };
+        let proposal =
+            CreateProposalRequest::new("Test".into(), "".into(), vec![1u8; 20], 10, 60, true)
+                .unwrap()
+                .into_proposal(now_ts())
+                .unwrap();
+        let config = ConsensusConfig::gossipsub().with_allow_vote_changes(true);
+        let mut session = ConsensusSession::new(proposal.clone(), config.clone(), now_ts());
+        let (alice, bob, carol) = (
+            wrap(PrivateKeySigner::random()),
+            wrap(PrivateKeySigner::random()),
+            wrap(PrivateKeySigner::random()),
+        );
+
+        for (signer, choice) in [
+            (&alice, VoteChoice::Yes),
+            (&bob, VoteChoice::No),
+            (&carol, VoteChoice::Abstain),
+            (&alice, VoteChoice::No),
+        ] {
+            let vote = build_vote(&session.proposal, choice, signer, now_ts()).unwrap();
+            session.add_vote(vote, now_ts()).unwrap();
+            assert_in_sync(&session);
+        }
+        assert_eq!(
+            session.vote_counts,
+            Some(VoteCounts {
+                yes: 0,
+                no: 2,
+                abstain: 1
+            })
+        );
+
+        // Retracting alice's change restores her earlier YES.
+        let alice_id = session.proposal.votes.last().unwrap().vote_owner.clone();
+        session.retract_vote(&alice_id, now_ts()).unwrap();
+        assert_in_sync(&session);
+        assert_eq!(session.vote_counts.unwrap().yes, 1);
+        let carol_id = session.proposal.votes.last().unwrap().vote_owner.clone();
+        session.retract_vote(&carol_id, now_ts()).unwrap();
+        assert_in_sync(&session);
+
+        // A session restored without the cache (e.g. deserialized) rebuilds it.
+        let mut restored = session.clone();
+        restored.vote_counts = None;
+        let vote = build_vote(&restored.proposal, true, &carol, now_ts()).unwrap();
+        restored.add_vote(vote, now_ts()).unwrap();
+        assert_in_sync(&restored);
+
+        let mut batch = ConsensusSession::new(proposal, config, now_ts());
+        batch
+            .initialize_with_votes::<EthereumConsensusSigner>(
+                session.proposal.votes.clone(),
+                now_ts(),
+                &VoteChecks::default(),
+            )
+            .unwrap();
+        assert_in_sync(&batch);
+        assert_eq!(batch.vote_counts, session.vote_counts);
+    }
+
     #[test]
     fn enforce_max_rounds_gossipsub() {
         // Gossipsub: max_rounds = 2 means round 1 (proposal) and round 2 (all votes)
@@ -517,10 +1569,63 @@ mod tests {
         assert_eq!(session.proposal.round, 5);
         assert_eq!(session.votes.len(), 4);
 
-        // Fifth vote would exceed dynamic max_round_limit (=4 votes)
+        // Fifth vote would exceed dynamic max_round_limit (=4 votes) and fails the session
         let vote5 = build_vote(&session.proposal, true, &wrap(signer5), now_ts()).unwrap();
-        let err = session.add_vote(vote5, now_ts()).unwrap_err();
+        assert_eq!(
+            session.add_vote(vote5, now_ts()).unwrap(),
+            SessionTransition::Failed(FailReason::MaxRoundsExceeded)
+        );
+        assert_eq!(session.state, ConsensusState::Failed);
+        assert!(session.finalized_at.is_some());
+        assert_eq!(session.votes.len(), 4);
+    }
+
+    #[test]
+    fn enforce_explicit_max_rounds_p2p() {
+        // An explicit override replaces the dynamic ceil(2n/3) cap (7 for n = 10).
+        let request = CreateProposalRequest::new(
+            "Test".into(),
+            "".into(),
+            PrivateKeySigner::random().address().as_slice().to_vec(),
+            10,
+            60,
+            false,
+        )
+        .unwrap();
+        let proposal = request.into_proposal(now_ts()).unwrap();
+        let config = ConsensusConfig::new(2.0 / 3.0, Duration::from_secs(60), 2, false, false);
+
+        let mut chained = proposal.clone();
+        let votes: Vec<_> = (0..3)
+            .map(|_| {
+                let signer = wrap(PrivateKeySigner::random());
+                let vote = build_vote(&chained, true, &signer, now_ts()).unwrap();
+                chained.votes.push(vote.clone());
+                vote
+            })
+            .collect();
+
+        let mut session = ConsensusSession::new(proposal.clone(), config.clone(), now_ts());
+        session.add_vote(votes[0].clone(), now_ts()).unwrap();
+        session.add_vote(votes[1].clone(), now_ts()).unwrap();
+        assert_eq!(session.proposal.round, 3);
+        assert_eq!(
+            session.add_vote(votes[2].clone(), now_ts()).unwrap(),
+            SessionTransition::Failed(FailReason::MaxRoundsExceeded)
+        );
+        assert_eq!(session.proposal.round, 3);
+
+        // A batch over the cap is rejected as a whole.
+        let mut batch = ConsensusSession::new(proposal.clone(), config, now_ts());
+        let err = batch
+            .initialize_with_votes::<EthereumConsensusSigner>(
+                votes,
+                now_ts(),
+                &VoteChecks::default(),
+            )
+            .unwrap_err();
         assert!(matches!(err, ConsensusError::MaxRoundsExceeded));
+        assert!(batch.votes.is_empty());
     }
 
     #[test]
@@ -552,7 +1657,7 @@ mod tests {
     }
 
     #[test]
-    fn add_vote_rejects_non_active_and_reports_reached_when_finalized() {
+    fn add_vote_rejects_failed_and_finalized_sessions_as_terminal() {
         let signer = PrivateKeySigner::random();
         let request = CreateProposalRequest::new(
             "Test".into(),
@@ -577,17 +1682,24 @@ mod tests {
         )
         .unwrap();
         let err = failed_session.add_vote(vote, now_ts()).unwrap_err();
-        assert!(matches!(err, ConsensusError::SessionNotActive));
+        assert!(matches!(
+            err,
+            ConsensusError::SessionTerminal {
+                state: ConsensusState::Failed
+            }
+        ));
 
-        // Finalized sessions return existing transition/result.
+        // Finalized sessions reject new votes with the same terminal error.
         let mut finalized_session =
             ConsensusSession::new(proposal, ConsensusConfig::gossipsub(), now_ts());
         finalized_session.state = ConsensusState::ConsensusReached(true);
         let vote = build_vote(&finalized_session.proposal, true, &wrap(signer), now_ts()).unwrap();
-        let transition = finalized_session.add_vote(vote, now_ts()).unwrap();
+        let err = finalized_session.add_vote(vote, now_ts()).unwrap_err();
         assert!(matches!(
-            transition,
-            crate::types::SessionTransition::ConsensusReached(true)
+            err,
+            ConsensusError::SessionTerminal {
+                state: ConsensusState::ConsensusReached(true)
+            }
         ));
     }
 
@@ -612,12 +1724,16 @@ mod tests {
         let err = inactive
             .initialize_with_votes::<EthereumConsensusSigner>(
                 vec![],
-                proposal.expiration_timestamp,
-                proposal.timestamp,
                 now_ts(),
+                &VoteChecks::default(),
             )
             .unwrap_err();
-        assert!(matches!(err, ConsensusError::SessionNotActive));
+        assert!(matches!(
+            err,
+            ConsensusError::SessionTerminal {
+                state: ConsensusState::Failed
+            }
+        ));
 
         // Duplicate owners are rejected before chain/signature checks.
         let mut dup_session =
@@ -628,17 +1744,51 @@ mod tests {
         let err = dup_session
             .initialize_with_votes::<EthereumConsensusSigner>(
                 vec![vote1, vote2],
-                proposal.expiration_timestamp,
-                proposal.timestamp,
                 now_ts(),
+                &VoteChecks::default(),
             )
             .unwrap_err();
         assert!(matches!(err, ConsensusError::DuplicateVote));
 
         // Explicitly exercise gossipsub projected round branch where vote_count == 0.
-        let mut zero_votes =
-            ConsensusSession::new(proposal, ConsensusConfig::gossipsub(), now_ts());
-        zero_votes.check_round_limit(0).unwrap();
+        let zero_votes = ConsensusSession::new(proposal, ConsensusConfig::gossipsub(), now_ts());
+        assert!(!zero_votes.exceeds_round_limit(0));
+    }
+
+    #[test]
+    fn initialize_with_votes_rejects_more_voters_than_expected() {
+        let proposal = CreateProposalRequest::new(
+            "Test".into(),
+            "".into(),
+            PrivateKeySigner::random().address().as_slice().to_vec(),
+            2,
+            60,
+            true,
+        )
+        .unwrap()
+        .into_proposal(now_ts())
+        .unwrap();
+
+        let mut chained = proposal.clone();
+        let votes: Vec<_> = (0..3)
+            .map(|_| {
+                let vote = build_vote(&chained, false, &wrap(PrivateKeySigner::random()), now_ts())
+                    .unwrap();
+                chained.votes.push(vote.clone());
+                vote
+            })
+            .collect();
+
+        let mut session = ConsensusSession::new(proposal, ConsensusConfig::gossipsub(), now_ts());
+        let err = session
+            .initialize_with_votes::<EthereumConsensusSigner>(
+                votes,
+                now_ts(),
+                &VoteChecks::default(),
+            )
+            .unwrap_err();
+        assert!(matches!(err, ConsensusError::TooManyVotes { max_votes: 2 }));
+        assert!(session.votes.is_empty());
     }
 
     #[test]
@@ -659,18 +1809,53 @@ mod tests {
         .unwrap();
 
         let proposal = request.into_proposal(now_ts()).unwrap();
-        let mut session = ConsensusSession::new(proposal, ConsensusConfig::p2p(), now_ts());
+        let session = ConsensusSession::new(proposal, ConsensusConfig::p2p(), now_ts());
 
         let wrapped_vote_count = (u32::MAX as usize) + 1;
 
         // Desired behavior: an effectively huge batch must be rejected by round-limit checks.
-        let result = session.check_round_limit(wrapped_vote_count);
         assert!(
-            result.is_err(),
+            session.exceeds_round_limit(wrapped_vote_count),
             "effectively huge vote_count should not pass round-limit checks"
         );
     }
 
+    #[test]
+    fn from_proposal_rejects_round_inconsistent_with_votes() {
+        let owner = PrivateKeySigner::random();
+        let request = CreateProposalRequest::new(
+            "RoundCheck".into(),
+            vec![],
+            owner.address().as_slice().to_vec(),
+            5,
+            60,
+            true,
+        )
+        .unwrap();
+        let mut proposal = request.into_proposal(now_ts()).unwrap();
+        let vote = build_vote(&proposal, true, &wrap(owner), now_ts()).unwrap();
+        proposal.votes.push(vote);
+
+        for (round, config, accepted) in [
+            (2, ConsensusConfig::gossipsub(), true),
+            (u32::MAX, ConsensusConfig::gossipsub(), false),
+            (2, ConsensusConfig::p2p(), true),
+            (5, ConsensusConfig::p2p(), false),
+        ] {
+            proposal.round = round;
+            let result = ConsensusSession::from_proposal::<EthereumConsensusSigner>(
+                proposal.clone(),
+                config,
+                now_ts(),
+            );
+            if accepted {
+                assert_eq!(result.unwrap().0.proposal.round, 2);
+            } else {
+                assert!(matches!(result, Err(ConsensusError::InvalidRound)));
+            }
+        }
+    }
+
     #[test]
     fn p2p_update_round_should_advance_for_max_u32_vote_count() {
         let signer = PrivateKeySigner::random();
@@ -697,4 +1882,40 @@ mod tests {
         );
         assert_eq!(session.proposal.round, u32::MAX);
     }
+
+    #[test]
+    fn single_voter_choice_is_authoritative_in_both_paths() {
+        for choice in [true, false] {
+            for liveness in [true, false] {
+                let voter = PrivateKeySigner::random();
+                let proposal = CreateProposalRequest::new(
+                    "Solo".into(),
+                    "".into(),
+                    voter.address().as_slice().to_vec(),
+                    1,
+                    60,
+                    liveness,
+                )
+                .unwrap()
+                .into_proposal(now_ts())
+                .unwrap();
+                let mut session =
+                    ConsensusSession::new(proposal, ConsensusConfig::gossipsub(), now_ts());
+
+                let vote = build_vote(&session.proposal, choice, &wrap(voter), now_ts()).unwrap();
+                let transition = session.add_vote(vote, now_ts()).unwrap();
+
+                let direct = calculate_consensus_result(
+                    &session.votes,
+                    1,
+                    session.config.threshold_mode,
+                    liveness,
+                    false,
+                );
+                assert_eq!(direct, Some(choice));
+                assert_eq!(transition, SessionTransition::ConsensusReached(choice));
+                assert_eq!(session.state, ConsensusState::ConsensusReached(choice));
+            }
+        }
+    }
 }
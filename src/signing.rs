@@ -54,7 +54,8 @@ pub trait ConsensusSignatureScheme: Clone + Send + Sync + 'static {
 
     /// Sign `payload` and return the raw signature bytes.
     ///
-    /// Length and encoding are scheme-specific.
+    /// Length and encoding are scheme-specific; the library never assumes a
+    /// fixed signature length, so [`verify`](Self::verify) must check it.
     fn sign(&self, payload: &[u8]) -> Result<Vec<u8>, ConsensusSchemeError>;
 
     /// Verify that `signature` over `payload` was produced by the holder of
@@ -71,6 +72,16 @@ pub trait ConsensusSignatureScheme: Clone + Send + Sync + 'static {
         payload: &[u8],
         signature: &[u8],
     ) -> Result<bool, ConsensusSchemeError>;
+
+    /// Whether `signature` is in the scheme's canonical encoding.
+    ///
+    /// Schemes with malleable signatures (e.g. ECDSA, where flipping `s` to
+    /// `n - s` yields a second valid signature) override this so the service
+    /// can reject the non-canonical form. [`sign`](Self::sign) must always
+    /// produce canonical signatures. Defaults to `true`.
+    fn is_canonical(_signature: &[u8]) -> bool {
+        true
+    }
 }
 
 /// Error returned by [`ConsensusSignatureScheme`] operations.
@@ -60,7 +60,7 @@ impl ConsensusSignatureScheme for EthereumConsensusSigner {
             .inner
             .sign_message_sync(payload)
             .map_err(|e| ConsensusSchemeError::Sign(e.to_string()))?;
-        Ok(signature.as_bytes().to_vec())
+        Ok(signature.normalized_s().as_bytes().to_vec())
     }
 
     fn verify(
@@ -95,4 +95,13 @@ impl ConsensusSignatureScheme for EthereumConsensusSigner {
 
         Ok(recovered == expected)
     }
+
+    /// Canonical signatures are 65 bytes with a low-S value (`s <= n/2`).
+    fn is_canonical(signature: &[u8]) -> bool {
+        let Ok(sig_bytes) = <&[u8; ETHEREUM_SIGNATURE_LENGTH]>::try_from(signature) else {
+            return false;
+        };
+        Signature::from_raw_array(sig_bytes)
+            .is_ok_and(|signature| signature.normalize_s().is_none())
+    }
 }
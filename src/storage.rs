@@ -2,24 +2,39 @@
 //!
 //! Implement [`ConsensusStorage`] to persist consensus sessions to a database or
 //! other durable backend. The provided [`InMemoryConsensusStorage`] keeps everything
-//! in RAM and is suitable for testing or single-node deployments.
+//! in RAM and is suitable for testing or single-node deployments. With the `redb`
+//! feature, `RedbConsensusStorage` persists them to a local database file.
 
 use parking_lot::RwLock;
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::Arc,
+};
 
 use crate::{
     error::ConsensusError,
-    protos::consensus::v1::Proposal,
+    protos::consensus::v1::{Proposal, Vote},
     scope::ConsensusScope,
     scope_config::ScopeConfig,
-    session::{ConsensusConfig, ConsensusSession},
+    session::{ConsensusConfig, ConsensusSession, ConsensusState},
+    types::{ConsensusExplanation, ConsensusOutcome, ConsensusProgress, VoteTally},
 };
 
+#[cfg(feature = "redb")]
+mod redb_backend;
+#[cfg(feature = "redb")]
+pub use redb_backend::RedbConsensusStorage;
+
 /// Trait for storing and retrieving consensus sessions.
 ///
 /// Implement this to use your own storage backend (database, file system, etc.).
 /// The default `InMemoryConsensusStorage` stores everything in RAM, which is fine
 /// for testing or single-node setups but won't persist across restarts.
+///
+/// Backends report their own failures (I/O errors, lost connections, ...) as
+/// [`ConsensusError::StorageBackend`] wrapping the underlying error; the
+/// service passes it through to the caller unchanged. The in-memory backend
+/// never returns it.
 pub trait ConsensusStorage<Scope>: Clone + Send + Sync + 'static
 where
     Scope: ConsensusScope,
@@ -27,18 +42,50 @@ where
     /// Persist a session (insert or overwrite by `proposal_id`).
     fn save_session(&self, scope: &Scope, session: ConsensusSession) -> Result<(), ConsensusError>;
 
+    /// Atomically persist a session only if no session with its `proposal_id` exists.
+    ///
+    /// Returns `Ok(false)` (leaving the stored session untouched) when one does,
+    /// so concurrent deliveries of the same proposal cannot overwrite each other.
+    fn insert_session_if_absent(
+        &self,
+        scope: &Scope,
+        session: ConsensusSession,
+    ) -> Result<bool, ConsensusError>;
+
     /// Retrieve a session by proposal ID, or `None` if it doesn't exist.
     fn get_session(
         &self,
         scope: &Scope,
-        proposal_id: u32,
+        proposal_id: u64,
     ) -> Result<Option<ConsensusSession>, ConsensusError>;
 
+    /// Run `reader` on a session without taking ownership of it.
+    ///
+    /// Returns [`SessionNotFound`](ConsensusError::SessionNotFound) if the
+    /// proposal doesn't exist. The default clones the session via
+    /// [`get_session`](Self::get_session); backends holding sessions in memory
+    /// should override it to lend the stored session instead. `reader` may run
+    /// under the backend's read lock, so it must not call back into storage.
+    fn with_session<R, F>(
+        &self,
+        scope: &Scope,
+        proposal_id: u64,
+        reader: F,
+    ) -> Result<R, ConsensusError>
+    where
+        F: FnOnce(&ConsensusSession) -> Result<R, ConsensusError>,
+    {
+        let session = self
+            .get_session(scope, proposal_id)?
+            .ok_or(ConsensusError::SessionNotFound)?;
+        reader(&session)
+    }
+
     /// Remove and return a session, or `None` if not found.
     fn remove_session(
         &self,
         scope: &Scope,
-        proposal_id: u32,
+        proposal_id: u64,
     ) -> Result<Option<ConsensusSession>, ConsensusError>;
 
     /// List all sessions in a scope, or `None` if the scope doesn't exist.
@@ -63,11 +110,16 @@ where
     /// List all known scopes, or `None` if no scopes exist.
     fn list_scopes(&self) -> Result<Option<Vec<Scope>>, ConsensusError>;
 
-    /// Apply a mutation to a single session in place.
+    /// Apply a mutation to a single session and store the result.
+    ///
+    /// Backends may run `mutator` on a decoded copy rather than the stored
+    /// value, and whether changes made before it returns an error are kept is
+    /// backend-specific (the redb backend rolls them back). A state change
+    /// that must be stored, such as a session failing, is returned as `Ok`.
     fn update_session<R, F>(
         &self,
         scope: &Scope,
-        proposal_id: u32,
+        proposal_id: u64,
         mutator: F,
     ) -> Result<R, ConsensusError>
     where
@@ -91,11 +143,44 @@ where
     /// starts fresh.
     fn delete_scope(&self, scope: &Scope) -> Result<(), ConsensusError>;
 
+    /// Atomically move all data for a scope (sessions and config) to a new key.
+    ///
+    /// Returns [`ScopeNotFound`](ConsensusError::ScopeNotFound) if `old` holds
+    /// no data, or [`ScopeAlreadyExists`](ConsensusError::ScopeAlreadyExists)
+    /// if `new` already holds sessions or a config. After this call `old`
+    /// behaves as if it was never initialized. Any timeout timers your
+    /// application scheduled for proposals in `old` must be re-keyed to `new`.
+    /// Call it through
+    /// [`ConsensusService::rename_scope`](crate::service::ConsensusService::rename_scope)
+    /// so the service's in-memory state for the scope moves too.
+    fn rename_scope(&self, old: &Scope, new: &Scope) -> Result<(), ConsensusError>;
+
+    /// Atomically archive a scope's sessions and clear them, keeping its config.
+    ///
+    /// Used for epoch rollover: the returned [`ScopeSnapshot`] holds every
+    /// session the scope had (ordered by `proposal_id`) and the config in
+    /// effect. An unknown scope yields an empty snapshot. Timeout timers for
+    /// the archived proposals can be dropped. Call it through
+    /// [`ConsensusService::rollover_scope`](crate::service::ConsensusService::rollover_scope)
+    /// so the service's in-memory state for the scope is cleared too.
+    fn rollover_scope(&self, scope: &Scope) -> Result<ScopeSnapshot, ConsensusError>;
+
     /// Apply a mutation to an existing scope configuration.
     fn update_scope_config<F>(&self, scope: &Scope, updater: F) -> Result<(), ConsensusError>
     where
         F: FnOnce(&mut ScopeConfig) -> Result<(), ConsensusError>;
 
+    /// Whether finalization is paused in `scope` (see
+    /// [`pause_scope`](crate::service::ConsensusService::pause_scope)).
+    fn is_scope_paused(&self, scope: &Scope) -> Result<bool, ConsensusError>;
+
+    /// Pause or resume finalization in `scope`.
+    ///
+    /// Kept apart from the [`ScopeConfig`], so writing a config never pauses
+    /// or resumes a scope. [`delete_scope`](Self::delete_scope) clears the
+    /// flag and [`rename_scope`](Self::rename_scope) moves it.
+    fn set_scope_paused(&self, scope: &Scope, paused: bool) -> Result<(), ConsensusError>;
+
     // ── Query helpers (default implementations) ────────────────────────
     //
     // These are derived from the primitives above. Storage implementors
@@ -108,20 +193,47 @@ where
     /// proposal doesn't exist,
     /// [`ConsensusFailed`](ConsensusError::ConsensusFailed) if the session
     /// failed, or [`ConsensusNotReached`](ConsensusError::ConsensusNotReached)
-    /// if voting is still active.
+    /// if voting is still active, even past expiration. Use
+    /// [`ConsensusService::get_consensus_result`](crate::service::ConsensusService::get_consensus_result)
+    /// to finalize expired sessions on read.
     fn get_consensus_result(
         &self,
         scope: &Scope,
-        proposal_id: u32,
+        proposal_id: u64,
     ) -> Result<bool, ConsensusError> {
-        use crate::session::ConsensusState;
-        let session = self
-            .get_session(scope, proposal_id)?
-            .ok_or(ConsensusError::SessionNotFound)?;
-        match session.state {
-            ConsensusState::ConsensusReached(result) => Ok(result),
-            ConsensusState::Failed => Err(ConsensusError::ConsensusFailed),
-            ConsensusState::Active => Err(ConsensusError::ConsensusNotReached),
+        Ok(self.get_consensus_outcome(scope, proposal_id)?.result)
+    }
+
+    /// Get the consensus result with the vote counts behind it and whether a
+    /// tie was broken by the liveness criteria.
+    ///
+    /// See [`ConsensusSession::outcome`]. Fails like
+    /// [`get_consensus_result`](Self::get_consensus_result).
+    fn get_consensus_outcome(
+        &self,
+        scope: &Scope,
+        proposal_id: u64,
+    ) -> Result<ConsensusOutcome, ConsensusError> {
+        self.with_session(scope, proposal_id, ConsensusSession::outcome)
+    }
+
+    /// Get the consensus outcome for a proposal, treating "still voting" as a
+    /// normal state rather than an error.
+    ///
+    /// Returns `Ok(Some(result))` once consensus is reached and `Ok(None)`
+    /// while voting is still active. Returns
+    /// [`SessionNotFound`](ConsensusError::SessionNotFound) if the proposal
+    /// doesn't exist, or [`ConsensusFailed`](ConsensusError::ConsensusFailed)
+    /// if the session failed.
+    fn consensus_outcome(
+        &self,
+        scope: &Scope,
+        proposal_id: u64,
+    ) -> Result<Option<bool>, ConsensusError> {
+        match self.get_consensus_result(scope, proposal_id) {
+            Ok(result) => Ok(Some(result)),
+            Err(ConsensusError::ConsensusNotReached) => Ok(None),
+            Err(err) => Err(err),
         }
     }
 
@@ -129,11 +241,8 @@ where
     ///
     /// Returns [`SessionNotFound`](ConsensusError::SessionNotFound) if the
     /// proposal doesn't exist.
-    fn get_proposal(&self, scope: &Scope, proposal_id: u32) -> Result<Proposal, ConsensusError> {
-        let session = self
-            .get_session(scope, proposal_id)?
-            .ok_or(ConsensusError::SessionNotFound)?;
-        Ok(session.proposal)
+    fn get_proposal(&self, scope: &Scope, proposal_id: u64) -> Result<Proposal, ConsensusError> {
+        self.with_session(scope, proposal_id, |session| Ok(session.proposal.clone()))
     }
 
     /// Get the resolved configuration for a proposal.
@@ -143,12 +252,101 @@ where
     fn get_proposal_config(
         &self,
         scope: &Scope,
-        proposal_id: u32,
+        proposal_id: u64,
     ) -> Result<ConsensusConfig, ConsensusError> {
+        self.with_session(scope, proposal_id, |session| Ok(session.config.clone()))
+    }
+
+    /// Get the known voters who have not yet voted on a proposal.
+    ///
+    /// Uses the scope's [`allowed_voters`](ScopeConfig::allowed_voters) as the
+    /// participant set and returns its members without a recorded vote, sorted
+    /// by identity bytes. Returns [`VoterSetUnknown`](ConsensusError::VoterSetUnknown)
+    /// if the scope has no known voter set, or
+    /// [`SessionNotFound`](ConsensusError::SessionNotFound) if the proposal
+    /// doesn't exist.
+    fn non_voters(&self, scope: &Scope, proposal_id: u64) -> Result<Vec<Vec<u8>>, ConsensusError> {
         let session = self
             .get_session(scope, proposal_id)?
             .ok_or(ConsensusError::SessionNotFound)?;
-        Ok(session.config)
+        let allowed_voters = self
+            .get_scope_config(scope)?
+            .and_then(|config| config.allowed_voters)
+            .ok_or(ConsensusError::VoterSetUnknown)?;
+        let mut pending: Vec<Vec<u8>> = allowed_voters
+            .iter()
+            .filter(|voter| !session.votes().contains_key(*voter))
+            .cloned()
+            .collect();
+        pending.sort();
+        Ok(pending)
+    }
+
+    /// Get every distinct voter seen in a scope.
+    ///
+    /// Returns the union of vote owners across all sessions in the scope,
+    /// active or finalized (late votes included), deduplicated and sorted by
+    /// identity bytes. An unknown scope yields an empty list.
+    fn known_voters(&self, scope: &Scope) -> Result<Vec<Vec<u8>>, ConsensusError> {
+        let mut voters = std::collections::BTreeSet::new();
+        for session in self.stream_scope_sessions(scope) {
+            let session = session?;
+            voters.extend(session.votes().keys().cloned());
+            voters.extend(session.late_votes.into_keys());
+        }
+        Ok(voters.into_iter().collect())
+    }
+
+    /// Get the fewest votes a lagging peer needs to finalize a proposal.
+    ///
+    /// See [`ConsensusSession::minimal_finalizing_set`]. Returns
+    /// [`SessionNotFound`](ConsensusError::SessionNotFound) if the proposal
+    /// doesn't exist.
+    fn minimal_finalizing_set(
+        &self,
+        scope: &Scope,
+        proposal_id: u64,
+    ) -> Result<Vec<Vote>, ConsensusError> {
+        self.with_session(scope, proposal_id, ConsensusSession::minimal_finalizing_set)
+    }
+
+    /// Explain the quorum math behind a proposal's current state.
+    ///
+    /// See [`ConsensusSession::explain`]. Returns
+    /// [`SessionNotFound`](ConsensusError::SessionNotFound) if the proposal
+    /// doesn't exist.
+    fn explain_consensus(
+        &self,
+        scope: &Scope,
+        proposal_id: u64,
+    ) -> Result<ConsensusExplanation, ConsensusError> {
+        self.with_session(scope, proposal_id, |session| Ok(session.explain()))
+    }
+
+    /// Get the live YES / NO / silent counts and the votes required to decide.
+    ///
+    /// Works for active and terminal sessions. Returns
+    /// [`SessionNotFound`](ConsensusError::SessionNotFound) if the proposal
+    /// doesn't exist.
+    fn get_vote_tally(&self, scope: &Scope, proposal_id: u64) -> Result<VoteTally, ConsensusError> {
+        Ok(VoteTally::from(
+            &self.explain_consensus(scope, proposal_id)?,
+        ))
+    }
+
+    /// Get how many more votes, and YES votes in particular, a proposal needs.
+    ///
+    /// See [`ConsensusProgress`]. Returns
+    /// [`SessionNotFound`](ConsensusError::SessionNotFound) if the proposal
+    /// doesn't exist.
+    fn votes_needed(
+        &self,
+        scope: &Scope,
+        proposal_id: u64,
+    ) -> Result<ConsensusProgress, ConsensusError> {
+        Ok(ConsensusProgress::from(
+            &self.explain_consensus(scope, proposal_id)?,
+        ))
     }
 
     /// Get all proposals that are still accepting votes.
@@ -167,7 +365,7 @@ where
     ///
     /// Returns a map from `proposal_id` to result (`true` = YES, `false` = NO).
     /// Returns an empty map if no proposals reached consensus or the scope is unknown.
-    fn get_reached_proposals(&self, scope: &Scope) -> Result<HashMap<u32, bool>, ConsensusError> {
+    fn get_reached_proposals(&self, scope: &Scope) -> Result<HashMap<u64, bool>, ConsensusError> {
         let sessions = self.list_scope_sessions(scope)?.unwrap_or_default();
         Ok(sessions
             .into_iter()
@@ -178,6 +376,136 @@ where
             })
             .collect())
     }
+
+    /// Like [`get_reached_proposals`](Self::get_reached_proposals), but as a
+    /// list in a stable order: by [`finalized_at`](ConsensusSession::finalized_at),
+    /// then by `proposal_id`.
+    fn list_reached_proposals(&self, scope: &Scope) -> Result<Vec<(u64, bool)>, ConsensusError> {
+        let sessions = self.list_scope_sessions(scope)?.unwrap_or_default();
+        let mut reached: Vec<_> = sessions
+            .into_iter()
+            .filter_map(|s| {
+                s.get_consensus_result()
+                    .ok()
+                    .map(|result| (s.finalized_at, s.proposal.proposal_id, result))
+            })
+            .collect();
+        reached.sort_unstable_by_key(|&(finalized_at, proposal_id, _)| (finalized_at, proposal_id));
+        Ok(reached
+            .into_iter()
+            .map(|(_, proposal_id, result)| (proposal_id, result))
+            .collect())
+    }
+}
+
+/// A scope's archived state, returned by [`ConsensusStorage::rollover_scope`].
+///
+/// Use [`wire::encode_snapshot`](crate::wire::encode_snapshot) to export its
+/// proposals with a checksum and
+/// [`wire::decode_snapshot`](crate::wire::decode_snapshot) to verify and read
+/// them back in another process.
+#[derive(Debug, Clone)]
+pub struct ScopeSnapshot {
+    /// Every session the scope held, ordered by `proposal_id`.
+    pub sessions: Vec<ConsensusSession>,
+    /// The scope config at the time of the snapshot, or `None` if never initialized.
+    pub config: Option<ScopeConfig>,
+}
+
+/// Structured difference between two [`ScopeSnapshot`]s, from [`diff_snapshots`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ScopeDiff {
+    /// Proposals present only in the first snapshot, ascending.
+    pub only_in_a: Vec<u64>,
+    /// Proposals present only in the second snapshot, ascending.
+    pub only_in_b: Vec<u64>,
+    /// Proposals present in both that differ in state or votes, by `proposal_id`.
+    pub changed: Vec<ProposalDiff>,
+}
+
+impl ScopeDiff {
+    /// Whether the two snapshots hold the same proposals, states, and votes.
+    pub fn is_empty(&self) -> bool {
+        self.only_in_a.is_empty() && self.only_in_b.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// How one proposal differs between two snapshots.
+///
+/// Voter lists hold vote owner identity bytes, sorted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProposalDiff {
+    pub proposal_id: u64,
+    /// Session state in the first snapshot.
+    pub state_a: ConsensusState,
+    /// Session state in the second snapshot.
+    pub state_b: ConsensusState,
+    /// Voters with a vote only in the first snapshot.
+    pub votes_only_in_a: Vec<Vec<u8>>,
+    /// Voters with a vote only in the second snapshot.
+    pub votes_only_in_b: Vec<Vec<u8>>,
+    /// Voters whose recorded vote differs between the snapshots.
+    pub votes_differ: Vec<Vec<u8>>,
+}
+
+/// Compare two scope snapshots, e.g. one node's state over time or two peers'
+/// views of the same scope, to debug convergence.
+///
+/// Only counted votes are compared; configs and late votes are ignored.
+pub fn diff_snapshots(a: &ScopeSnapshot, b: &ScopeSnapshot) -> ScopeDiff {
+    let by_id = |snapshot: &ScopeSnapshot| -> BTreeMap<u64, ConsensusSession> {
+        snapshot
+            .sessions
+            .iter()
+            .map(|s| (s.proposal.proposal_id, s.clone()))
+            .collect()
+    };
+    let (a, b) = (by_id(a), by_id(b));
+
+    let mut diff = ScopeDiff {
+        only_in_a: a.keys().filter(|id| !b.contains_key(id)).copied().collect(),
+        only_in_b: b.keys().filter(|id| !a.contains_key(id)).copied().collect(),
+        changed: Vec::new(),
+    };
+    for (proposal_id, session_a) in &a {
+        let Some(session_b) = b.get(proposal_id) else {
+            continue;
+        };
+        let mut votes_only_in_a = Vec::new();
+        let mut votes_differ = Vec::new();
+        for (owner, vote) in session_a.votes() {
+            match session_b.votes().get(owner) {
+                None => votes_only_in_a.push(owner.clone()),
+                Some(other) if other != vote => votes_differ.push(owner.clone()),
+                Some(_) => {}
+            }
+        }
+        let mut votes_only_in_b: Vec<Vec<u8>> = session_b
+            .votes()
+            .keys()
+            .filter(|owner| !session_a.votes().contains_key(*owner))
+            .cloned()
+            .collect();
+        if session_a.state == session_b.state
+            && votes_only_in_a.is_empty()
+            && votes_only_in_b.is_empty()
+            && votes_differ.is_empty()
+        {
+            continue;
+        }
+        votes_only_in_a.sort();
+        votes_only_in_b.sort();
+        votes_differ.sort();
+        diff.changed.push(ProposalDiff {
+            proposal_id: *proposal_id,
+            state_a: session_a.state,
+            state_b: session_b.state,
+            votes_only_in_a,
+            votes_only_in_b,
+            votes_differ,
+        });
+    }
+    diff
 }
 
 /// In-memory storage for consensus sessions.
@@ -189,8 +517,83 @@ pub struct InMemoryConsensusStorage<Scope>
 where
     Scope: ConsensusScope,
 {
-    sessions: Arc<RwLock<HashMap<Scope, HashMap<u32, ConsensusSession>>>>,
+    sessions: Arc<RwLock<HashMap<Scope, HashMap<u64, StoredSession>>>>,
     scope_configs: Arc<RwLock<HashMap<Scope, ScopeConfig>>>,
+    paused_scopes: Arc<RwLock<HashSet<Scope>>>,
+    #[cfg(feature = "compression")]
+    compression_threshold: usize,
+}
+
+/// Payloads at least this large are zstd-compressed in [`InMemoryConsensusStorage`]
+/// (requires the `compression` feature).
+#[cfg(feature = "compression")]
+pub const DEFAULT_COMPRESSION_THRESHOLD: usize = 1024;
+
+#[cfg(feature = "compression")]
+const COMPRESSION_LEVEL: i32 = 3;
+
+/// A session as held in memory, with its proposal payload possibly compressed.
+///
+/// The compressed bytes replace `session.proposal.payload` while stored; callers
+/// only ever see the original payload, so hashing and signature checks are
+/// unaffected.
+#[derive(Clone)]
+struct StoredSession {
+    session: ConsensusSession,
+    compressed_payload: Option<Vec<u8>>,
+}
+
+impl StoredSession {
+    #[cfg(feature = "compression")]
+    fn pack(mut session: ConsensusSession, threshold: usize) -> Self {
+        let payload = &session.proposal.payload;
+        if payload.len() >= threshold
+            && let Ok(compressed) = zstd::encode_all(payload.as_slice(), COMPRESSION_LEVEL)
+            && compressed.len() < payload.len()
+        {
+            session.proposal.payload = Vec::new();
+            return Self {
+                session,
+                compressed_payload: Some(compressed),
+            };
+        }
+        Self {
+            session,
+            compressed_payload: None,
+        }
+    }
+
+    #[cfg(not(feature = "compression"))]
+    fn pack(session: ConsensusSession) -> Self {
+        Self {
+            session,
+            compressed_payload: None,
+        }
+    }
+
+    fn unpack(&self) -> Result<ConsensusSession, ConsensusError> {
+        let mut session = self.session.clone();
+        if let Some(compressed) = &self.compressed_payload {
+            session.proposal.payload = decompress(compressed)?;
+        }
+        Ok(session)
+    }
+
+    fn payload_footprint(&self) -> usize {
+        self.compressed_payload
+            .as_ref()
+            .map_or(self.session.proposal.payload.len(), Vec::len)
+    }
+}
+
+#[cfg(feature = "compression")]
+fn decompress(compressed: &[u8]) -> Result<Vec<u8>, ConsensusError> {
+    zstd::decode_all(compressed).map_err(|_| ConsensusError::StorageCorrupted)
+}
+
+#[cfg(not(feature = "compression"))]
+fn decompress(_compressed: &[u8]) -> Result<Vec<u8>, ConsensusError> {
+    Err(ConsensusError::StorageCorrupted)
 }
 
 impl<Scope> Default for InMemoryConsensusStorage<Scope>
@@ -201,6 +604,9 @@ where
         Self {
             sessions: Arc::new(RwLock::new(HashMap::new())),
             scope_configs: Arc::new(RwLock::new(HashMap::new())),
+            paused_scopes: Arc::new(RwLock::new(HashSet::new())),
+            #[cfg(feature = "compression")]
+            compression_threshold: DEFAULT_COMPRESSION_THRESHOLD,
         }
     }
 }
@@ -216,6 +622,35 @@ where
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Compress proposal payloads of at least `threshold` bytes (default
+    /// [`DEFAULT_COMPRESSION_THRESHOLD`]). Applies to sessions saved afterwards.
+    #[cfg(feature = "compression")]
+    pub fn with_compression_threshold(mut self, threshold: usize) -> Self {
+        self.compression_threshold = threshold;
+        self
+    }
+
+    /// Total bytes currently used to hold proposal payloads, after compression.
+    pub fn payload_footprint(&self) -> usize {
+        self.sessions
+            .read()
+            .values()
+            .flat_map(HashMap::values)
+            .map(StoredSession::payload_footprint)
+            .sum()
+    }
+
+    fn pack(&self, session: ConsensusSession) -> StoredSession {
+        #[cfg(feature = "compression")]
+        {
+            StoredSession::pack(session, self.compression_threshold)
+        }
+        #[cfg(not(feature = "compression"))]
+        {
+            StoredSession::pack(session)
+        }
+    }
 }
 
 impl<Scope> ConsensusStorage<Scope> for InMemoryConsensusStorage<Scope>
@@ -225,31 +660,71 @@ where
     fn save_session(&self, scope: &Scope, session: ConsensusSession) -> Result<(), ConsensusError> {
         let mut sessions = self.sessions.write();
         let entry = sessions.entry(scope.clone()).or_default();
-        entry.insert(session.proposal.proposal_id, session);
+        entry.insert(session.proposal.proposal_id, self.pack(session));
         Ok(())
     }
 
+    fn insert_session_if_absent(
+        &self,
+        scope: &Scope,
+        session: ConsensusSession,
+    ) -> Result<bool, ConsensusError> {
+        let mut sessions = self.sessions.write();
+        let entry = sessions.entry(scope.clone()).or_default();
+        match entry.entry(session.proposal.proposal_id) {
+            std::collections::hash_map::Entry::Occupied(_) => Ok(false),
+            std::collections::hash_map::Entry::Vacant(slot) => {
+                slot.insert(self.pack(session));
+                Ok(true)
+            }
+        }
+    }
+
     fn get_session(
         &self,
         scope: &Scope,
-        proposal_id: u32,
+        proposal_id: u64,
     ) -> Result<Option<ConsensusSession>, ConsensusError> {
         let sessions = self.sessions.read();
-        Ok(sessions
+        sessions
             .get(scope)
             .and_then(|scope| scope.get(&proposal_id))
-            .cloned())
+            .map(StoredSession::unpack)
+            .transpose()
+    }
+
+    fn with_session<R, F>(
+        &self,
+        scope: &Scope,
+        proposal_id: u64,
+        reader: F,
+    ) -> Result<R, ConsensusError>
+    where
+        F: FnOnce(&ConsensusSession) -> Result<R, ConsensusError>,
+    {
+        let sessions = self.sessions.read();
+        let stored = sessions
+            .get(scope)
+            .and_then(|scope_sessions| scope_sessions.get(&proposal_id))
+            .ok_or(ConsensusError::SessionNotFound)?;
+        // A compressed payload has to be restored, which needs a copy.
+        if stored.compressed_payload.is_some() {
+            return reader(&stored.unpack()?);
+        }
+        reader(&stored.session)
     }
 
     fn remove_session(
         &self,
         scope: &Scope,
-        proposal_id: u32,
+        proposal_id: u64,
     ) -> Result<Option<ConsensusSession>, ConsensusError> {
         let mut sessions = self.sessions.write();
-        Ok(sessions
+        sessions
             .get_mut(scope)
-            .and_then(|scope| scope.remove(&proposal_id)))
+            .and_then(|scope| scope.remove(&proposal_id))
+            .map(|stored| stored.unpack())
+            .transpose()
     }
 
     fn list_scope_sessions(
@@ -257,10 +732,10 @@ where
         scope: &Scope,
     ) -> Result<Option<Vec<ConsensusSession>>, ConsensusError> {
         let sessions = self.sessions.read();
-        let result = sessions
+        sessions
             .get(scope)
-            .map(|scope| scope.values().cloned().collect::<Vec<ConsensusSession>>());
-        Ok(result)
+            .map(|scope| scope.values().map(StoredSession::unpack).collect())
+            .transpose()
     }
 
     fn stream_scope_sessions(
@@ -272,7 +747,7 @@ where
             .get(scope)
             .map(|inner_map| inner_map.values().cloned().collect::<Vec<_>>())
             .unwrap_or_default();
-        sessions.into_iter().map(Ok)
+        sessions.into_iter().map(|stored| stored.unpack())
     }
 
     fn replace_scope_sessions(
@@ -283,7 +758,7 @@ where
         let mut sessions = self.sessions.write();
         let new_map = sessions_list
             .into_iter()
-            .map(|session| (session.proposal.proposal_id, session))
+            .map(|session| (session.proposal.proposal_id, self.pack(session)))
             .collect();
         sessions.insert(scope.clone(), new_map);
         Ok(())
@@ -301,20 +776,27 @@ where
     fn update_session<R, F>(
         &self,
         scope: &Scope,
-        proposal_id: u32,
+        proposal_id: u64,
         mutator: F,
     ) -> Result<R, ConsensusError>
     where
         F: FnOnce(&mut ConsensusSession) -> Result<R, ConsensusError>,
     {
         let mut sessions = self.sessions.write();
-        let session = sessions
+        let stored = sessions
             .get_mut(scope)
             .and_then(|scope_sessions| scope_sessions.get_mut(&proposal_id))
             .ok_or(ConsensusError::SessionNotFound)?;
 
-        let result = mutator(session)?;
-        Ok(result)
+        // Only a compressed payload needs a restored copy; write it back
+        // either way so both paths keep what the mutator changed.
+        if stored.compressed_payload.is_none() {
+            return mutator(&mut stored.session);
+        }
+        let mut session = stored.unpack()?;
+        let result = mutator(&mut session);
+        *stored = self.pack(session);
+        result
     }
 
     fn update_scope_sessions<F>(&self, scope: &Scope, mutator: F) -> Result<(), ConsensusError>
@@ -324,7 +806,10 @@ where
         let mut sessions = self.sessions.write();
         let scope_sessions = sessions.entry(scope.clone()).or_default();
 
-        let mut sessions_vec: Vec<ConsensusSession> = scope_sessions.values().cloned().collect();
+        let mut sessions_vec = scope_sessions
+            .values()
+            .map(StoredSession::unpack)
+            .collect::<Result<Vec<_>, _>>()?;
         mutator(&mut sessions_vec)?;
 
         if sessions_vec.is_empty() {
@@ -332,9 +817,9 @@ where
             return Ok(());
         }
 
-        let new_map: HashMap<u32, ConsensusSession> = sessions_vec
+        let new_map: HashMap<u64, StoredSession> = sessions_vec
             .into_iter()
-            .map(|session| (session.proposal.proposal_id, session))
+            .map(|session| (session.proposal.proposal_id, self.pack(session)))
             .collect();
 
         *scope_sessions = new_map;
@@ -360,9 +845,54 @@ where
 
         let mut configs = self.scope_configs.write();
         configs.remove(scope);
+        drop(configs);
+
+        self.paused_scopes.write().remove(scope);
+        Ok(())
+    }
+
+    fn rename_scope(&self, old: &Scope, new: &Scope) -> Result<(), ConsensusError> {
+        // Hold both locks for the whole move so no reader observes a half-renamed scope.
+        let mut sessions = self.sessions.write();
+        let mut configs = self.scope_configs.write();
+
+        if sessions.contains_key(new) || configs.contains_key(new) {
+            return Err(ConsensusError::ScopeAlreadyExists);
+        }
+        if !sessions.contains_key(old) && !configs.contains_key(old) {
+            return Err(ConsensusError::ScopeNotFound);
+        }
+
+        if let Some(scope_sessions) = sessions.remove(old) {
+            sessions.insert(new.clone(), scope_sessions);
+        }
+        if let Some(config) = configs.remove(old) {
+            configs.insert(new.clone(), config);
+        }
+        let mut paused_scopes = self.paused_scopes.write();
+        if paused_scopes.remove(old) {
+            paused_scopes.insert(new.clone());
+        }
         Ok(())
     }
 
+    fn rollover_scope(&self, scope: &Scope) -> Result<ScopeSnapshot, ConsensusError> {
+        // Hold the sessions lock across the read and the clear so no vote lands in between.
+        let mut sessions = self.sessions.write();
+        let configs = self.scope_configs.read();
+
+        let mut archived: Vec<ConsensusSession> = sessions
+            .remove(scope)
+            .map(|scope_sessions| scope_sessions.values().map(StoredSession::unpack).collect())
+            .transpose()?
+            .unwrap_or_default();
+        archived.sort_by_key(|session| session.proposal.proposal_id);
+        Ok(ScopeSnapshot {
+            sessions: archived,
+            config: configs.get(scope).cloned(),
+        })
+    }
+
     fn update_scope_config<F>(&self, scope: &Scope, updater: F) -> Result<(), ConsensusError>
     where
         F: FnOnce(&mut ScopeConfig) -> Result<(), ConsensusError>,
@@ -373,4 +903,18 @@ where
         config.validate()?;
         Ok(())
     }
+
+    fn is_scope_paused(&self, scope: &Scope) -> Result<bool, ConsensusError> {
+        Ok(self.paused_scopes.read().contains(scope))
+    }
+
+    fn set_scope_paused(&self, scope: &Scope, paused: bool) -> Result<(), ConsensusError> {
+        let mut paused_scopes = self.paused_scopes.write();
+        if paused {
+            paused_scopes.insert(scope.clone());
+        } else {
+            paused_scopes.remove(scope);
+        }
+        Ok(())
+    }
 }
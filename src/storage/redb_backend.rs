@@ -0,0 +1,492 @@
+//! Persistent [`ConsensusStorage`] backed by a [redb](https://docs.rs/redb) database.
+
+use std::{marker::PhantomData, path::Path, sync::Arc};
+
+use redb::{
+    Database, ReadableTable, ReadableTableMetadata, Table, TableDefinition, WriteTransaction,
+};
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::{
+    error::ConsensusError,
+    scope::ConsensusScope,
+    scope_config::ScopeConfig,
+    session::ConsensusSession,
+    storage::{ConsensusStorage, ScopeSnapshot},
+};
+
+/// Sessions keyed by `(encoded scope, proposal_id)`, so one scope is a contiguous range.
+const SESSIONS: TableDefinition<(&[u8], u64), &[u8]> = TableDefinition::new("sessions");
+/// Scopes that hold sessions (possibly an empty set), keyed by encoded scope.
+const SCOPES: TableDefinition<&[u8], ()> = TableDefinition::new("scopes");
+/// Scope configurations keyed by encoded scope.
+const SCOPE_CONFIGS: TableDefinition<&[u8], &[u8]> = TableDefinition::new("scope_configs");
+/// Scopes whose finalization is paused, keyed by encoded scope.
+const PAUSED_SCOPES: TableDefinition<&[u8], ()> = TableDefinition::new("paused_scopes");
+
+/// Durable storage for consensus sessions and scope configs (requires the
+/// `redb` feature).
+///
+/// Sessions and configs are bincode-encoded into a single redb file and survive
+/// restarts. Every trait method runs in its own transaction, so
+/// [`update_session`](ConsensusStorage::update_session) and the scope-wide
+/// operations are atomic read-modify-write cycles; redb serializes writers.
+/// Backend failures surface as
+/// [`StorageBackend`](ConsensusError::StorageBackend) and undecodable records
+/// as [`StorageCorrupted`](ConsensusError::StorageCorrupted).
+pub struct RedbConsensusStorage<Scope> {
+    db: Arc<Database>,
+    _scope: PhantomData<fn() -> Scope>,
+}
+
+impl<Scope> Clone for RedbConsensusStorage<Scope> {
+    fn clone(&self) -> Self {
+        Self {
+            db: Arc::clone(&self.db),
+            _scope: PhantomData,
+        }
+    }
+}
+
+impl<Scope> RedbConsensusStorage<Scope> {
+    /// Open the database at `path`, creating it if it does not exist.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ConsensusError> {
+        Self::from_database(Database::create(path).map_err(backend)?)
+    }
+
+    /// Use an already opened database (e.g. one with an in-memory backend).
+    pub fn from_database(db: Database) -> Result<Self, ConsensusError> {
+        let txn = db.begin_write().map_err(backend)?;
+        txn.open_table(SESSIONS).map_err(backend)?;
+        txn.open_table(SCOPES).map_err(backend)?;
+        txn.open_table(SCOPE_CONFIGS).map_err(backend)?;
+        txn.open_table(PAUSED_SCOPES).map_err(backend)?;
+        txn.commit().map_err(backend)?;
+        Ok(Self {
+            db: Arc::new(db),
+            _scope: PhantomData,
+        })
+    }
+
+    fn write<R>(
+        &self,
+        body: impl FnOnce(&WriteTransaction) -> Result<R, ConsensusError>,
+    ) -> Result<R, ConsensusError> {
+        let txn = self.db.begin_write().map_err(backend)?;
+        let result = body(&txn)?;
+        txn.commit().map_err(backend)?;
+        Ok(result)
+    }
+}
+
+fn backend(err: impl Into<redb::Error>) -> ConsensusError {
+    ConsensusError::StorageBackend(Box::new(err.into()))
+}
+
+fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>, ConsensusError> {
+    bincode::serialize(value).map_err(|err| ConsensusError::StorageBackend(err))
+}
+
+fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, ConsensusError> {
+    bincode::deserialize(bytes).map_err(|_| ConsensusError::StorageCorrupted)
+}
+
+type SessionTable<'txn> = Table<'txn, (&'static [u8], u64), &'static [u8]>;
+
+/// Proposal IDs of every session stored under `key`, in ascending order.
+fn scope_ids(sessions: &SessionTable<'_>, key: &[u8]) -> Result<Vec<u64>, ConsensusError> {
+    sessions
+        .range((key, 0)..=(key, u64::MAX))
+        .map_err(backend)?
+        .map(|entry| Ok(entry.map_err(backend)?.0.value().1))
+        .collect()
+}
+
+/// Decode every session stored under `key`, ordered by proposal ID.
+fn read_scope(
+    sessions: &SessionTable<'_>,
+    key: &[u8],
+) -> Result<Vec<ConsensusSession>, ConsensusError> {
+    sessions
+        .range((key, 0)..=(key, u64::MAX))
+        .map_err(backend)?
+        .map(|entry| decode(entry.map_err(backend)?.1.value()))
+        .collect()
+}
+
+/// Remove every session stored under `key` and return them, ordered by proposal ID.
+fn take_scope(
+    sessions: &mut SessionTable<'_>,
+    key: &[u8],
+) -> Result<Vec<ConsensusSession>, ConsensusError> {
+    let taken = read_scope(sessions, key)?;
+    for id in scope_ids(sessions, key)? {
+        sessions.remove((key, id)).map_err(backend)?;
+    }
+    Ok(taken)
+}
+
+fn put_sessions(
+    sessions: &mut SessionTable<'_>,
+    key: &[u8],
+    list: &[ConsensusSession],
+) -> Result<(), ConsensusError> {
+    for session in list {
+        sessions
+            .insert(
+                (key, session.proposal.proposal_id),
+                encode(session)?.as_slice(),
+            )
+            .map_err(backend)?;
+    }
+    Ok(())
+}
+
+impl<Scope> ConsensusStorage<Scope> for RedbConsensusStorage<Scope>
+where
+    Scope: ConsensusScope + Serialize + DeserializeOwned,
+{
+    fn save_session(&self, scope: &Scope, session: ConsensusSession) -> Result<(), ConsensusError> {
+        let key = encode(scope)?;
+        self.write(|txn| {
+            let mut sessions = txn.open_table(SESSIONS).map_err(backend)?;
+            put_sessions(&mut sessions, &key, std::slice::from_ref(&session))?;
+            txn.open_table(SCOPES)
+                .map_err(backend)?
+                .insert(key.as_slice(), ())
+                .map_err(backend)?;
+            Ok(())
+        })
+    }
+
+    fn insert_session_if_absent(
+        &self,
+        scope: &Scope,
+        session: ConsensusSession,
+    ) -> Result<bool, ConsensusError> {
+        let key = encode(scope)?;
+        self.write(|txn| {
+            let mut sessions = txn.open_table(SESSIONS).map_err(backend)?;
+            let id = session.proposal.proposal_id;
+            if sessions
+                .get((key.as_slice(), id))
+                .map_err(backend)?
+                .is_some()
+            {
+                return Ok(false);
+            }
+            put_sessions(&mut sessions, &key, std::slice::from_ref(&session))?;
+            txn.open_table(SCOPES)
+                .map_err(backend)?
+                .insert(key.as_slice(), ())
+                .map_err(backend)?;
+            Ok(true)
+        })
+    }
+
+    fn get_session(
+        &self,
+        scope: &Scope,
+        proposal_id: u64,
+    ) -> Result<Option<ConsensusSession>, ConsensusError> {
+        let key = encode(scope)?;
+        let txn = self.db.begin_read().map_err(backend)?;
+        let sessions = txn.open_table(SESSIONS).map_err(backend)?;
+        sessions
+            .get((key.as_slice(), proposal_id))
+            .map_err(backend)?
+            .map(|stored| decode(stored.value()))
+            .transpose()
+    }
+
+    fn remove_session(
+        &self,
+        scope: &Scope,
+        proposal_id: u64,
+    ) -> Result<Option<ConsensusSession>, ConsensusError> {
+        let key = encode(scope)?;
+        self.write(|txn| {
+            let mut sessions = txn.open_table(SESSIONS).map_err(backend)?;
+            let removed = match sessions
+                .remove((key.as_slice(), proposal_id))
+                .map_err(backend)?
+            {
+                Some(stored) => Some(decode(stored.value())?),
+                None => None,
+            };
+            Ok(removed)
+        })
+    }
+
+    fn list_scope_sessions(
+        &self,
+        scope: &Scope,
+    ) -> Result<Option<Vec<ConsensusSession>>, ConsensusError> {
+        let key = encode(scope)?;
+        let txn = self.db.begin_read().map_err(backend)?;
+        let scopes = txn.open_table(SCOPES).map_err(backend)?;
+        if scopes.get(key.as_slice()).map_err(backend)?.is_none() {
+            return Ok(None);
+        }
+        let sessions = txn.open_table(SESSIONS).map_err(backend)?;
+        sessions
+            .range((key.as_slice(), 0)..=(key.as_slice(), u64::MAX))
+            .map_err(backend)?
+            .map(|entry| decode(entry.map_err(backend)?.1.value()))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Some)
+    }
+
+    fn stream_scope_sessions(
+        &self,
+        scope: &Scope,
+    ) -> impl Iterator<Item = Result<ConsensusSession, ConsensusError>> {
+        let range = encode(scope).and_then(|key| {
+            let txn = self.db.begin_read().map_err(backend)?;
+            let sessions = txn.open_table(SESSIONS).map_err(backend)?;
+            sessions
+                .range((key.as_slice(), 0)..=(key.as_slice(), u64::MAX))
+                .map_err(backend)
+        });
+        let (range, error) = match range {
+            Ok(range) => (Some(range), None),
+            Err(err) => (None, Some(Err(err))),
+        };
+        error.into_iter().chain(
+            range
+                .into_iter()
+                .flatten()
+                .map(|entry| decode(entry.map_err(backend)?.1.value())),
+        )
+    }
+
+    fn replace_scope_sessions(
+        &self,
+        scope: &Scope,
+        sessions_list: Vec<ConsensusSession>,
+    ) -> Result<(), ConsensusError> {
+        let key = encode(scope)?;
+        self.write(|txn| {
+            let mut sessions = txn.open_table(SESSIONS).map_err(backend)?;
+            take_scope(&mut sessions, &key)?;
+            put_sessions(&mut sessions, &key, &sessions_list)?;
+            txn.open_table(SCOPES)
+                .map_err(backend)?
+                .insert(key.as_slice(), ())
+                .map_err(backend)?;
+            Ok(())
+        })
+    }
+
+    fn list_scopes(&self) -> Result<Option<Vec<Scope>>, ConsensusError> {
+        let txn = self.db.begin_read().map_err(backend)?;
+        let scopes = txn.open_table(SCOPES).map_err(backend)?;
+        if scopes.is_empty().map_err(backend)? {
+            return Ok(None);
+        }
+        scopes
+            .iter()
+            .map_err(backend)?
+            .map(|entry| decode(entry.map_err(backend)?.0.value()))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Some)
+    }
+
+    fn update_session<R, F>(
+        &self,
+        scope: &Scope,
+        proposal_id: u64,
+        mutator: F,
+    ) -> Result<R, ConsensusError>
+    where
+        F: FnOnce(&mut ConsensusSession) -> Result<R, ConsensusError>,
+    {
+        let key = encode(scope)?;
+        self.write(|txn| {
+            let mut sessions = txn.open_table(SESSIONS).map_err(backend)?;
+            let mut session: ConsensusSession = match sessions
+                .get((key.as_slice(), proposal_id))
+                .map_err(backend)?
+            {
+                Some(stored) => decode(stored.value())?,
+                None => return Err(ConsensusError::SessionNotFound),
+            };
+            let result = mutator(&mut session)?;
+            sessions
+                .insert((key.as_slice(), proposal_id), encode(&session)?.as_slice())
+                .map_err(backend)?;
+            Ok(result)
+        })
+    }
+
+    fn update_scope_sessions<F>(&self, scope: &Scope, mutator: F) -> Result<(), ConsensusError>
+    where
+        F: FnOnce(&mut Vec<ConsensusSession>) -> Result<(), ConsensusError>,
+    {
+        let key = encode(scope)?;
+        self.write(|txn| {
+            let mut sessions = txn.open_table(SESSIONS).map_err(backend)?;
+            let mut list = take_scope(&mut sessions, &key)?;
+            mutator(&mut list)?;
+            put_sessions(&mut sessions, &key, &list)?;
+            let mut scopes = txn.open_table(SCOPES).map_err(backend)?;
+            if list.is_empty() {
+                scopes.remove(key.as_slice()).map_err(backend)?;
+            } else {
+                scopes.insert(key.as_slice(), ()).map_err(backend)?;
+            }
+            Ok(())
+        })
+    }
+
+    fn get_scope_config(&self, scope: &Scope) -> Result<Option<ScopeConfig>, ConsensusError> {
+        let key = encode(scope)?;
+        let txn = self.db.begin_read().map_err(backend)?;
+        let configs = txn.open_table(SCOPE_CONFIGS).map_err(backend)?;
+        configs
+            .get(key.as_slice())
+            .map_err(backend)?
+            .map(|stored| decode(stored.value()))
+            .transpose()
+    }
+
+    fn set_scope_config(&self, scope: &Scope, config: ScopeConfig) -> Result<(), ConsensusError> {
+        config.validate()?;
+        let key = encode(scope)?;
+        let value = encode(&config)?;
+        self.write(|txn| {
+            txn.open_table(SCOPE_CONFIGS)
+                .map_err(backend)?
+                .insert(key.as_slice(), value.as_slice())
+                .map_err(backend)?;
+            Ok(())
+        })
+    }
+
+    fn delete_scope(&self, scope: &Scope) -> Result<(), ConsensusError> {
+        let key = encode(scope)?;
+        self.write(|txn| {
+            take_scope(&mut txn.open_table(SESSIONS).map_err(backend)?, &key)?;
+            txn.open_table(SCOPES)
+                .map_err(backend)?
+                .remove(key.as_slice())
+                .map_err(backend)?;
+            txn.open_table(SCOPE_CONFIGS)
+                .map_err(backend)?
+                .remove(key.as_slice())
+                .map_err(backend)?;
+            txn.open_table(PAUSED_SCOPES)
+                .map_err(backend)?
+                .remove(key.as_slice())
+                .map_err(backend)?;
+            Ok(())
+        })
+    }
+
+    fn rename_scope(&self, old: &Scope, new: &Scope) -> Result<(), ConsensusError> {
+        let old_key = encode(old)?;
+        let new_key = encode(new)?;
+        self.write(|txn| {
+            let mut scopes = txn.open_table(SCOPES).map_err(backend)?;
+            let mut configs = txn.open_table(SCOPE_CONFIGS).map_err(backend)?;
+            let holds = |key: &[u8]| -> Result<bool, ConsensusError> {
+                Ok(scopes.get(key).map_err(backend)?.is_some()
+                    || configs.get(key).map_err(backend)?.is_some())
+            };
+            if holds(&new_key)? {
+                return Err(ConsensusError::ScopeAlreadyExists);
+            }
+            if !holds(&old_key)? {
+                return Err(ConsensusError::ScopeNotFound);
+            }
+
+            if scopes
+                .remove(old_key.as_slice())
+                .map_err(backend)?
+                .is_some()
+            {
+                scopes.insert(new_key.as_slice(), ()).map_err(backend)?;
+                let mut sessions = txn.open_table(SESSIONS).map_err(backend)?;
+                let moved = take_scope(&mut sessions, &old_key)?;
+                put_sessions(&mut sessions, &new_key, &moved)?;
+            }
+            let config = configs
+                .remove(old_key.as_slice())
+                .map_err(backend)?
+                .map(|stored| stored.value().to_vec());
+            if let Some(config) = config {
+                configs
+                    .insert(new_key.as_slice(), config.as_slice())
+                    .map_err(backend)?;
+            }
+            let mut paused = txn.open_table(PAUSED_SCOPES).map_err(backend)?;
+            if paused
+                .remove(old_key.as_slice())
+                .map_err(backend)?
+                .is_some()
+            {
+                paused.insert(new_key.as_slice(), ()).map_err(backend)?;
+            }
+            Ok(())
+        })
+    }
+
+    fn rollover_scope(&self, scope: &Scope) -> Result<ScopeSnapshot, ConsensusError> {
+        let key = encode(scope)?;
+        self.write(|txn| {
+            let sessions = take_scope(&mut txn.open_table(SESSIONS).map_err(backend)?, &key)?;
+            txn.open_table(SCOPES)
+                .map_err(backend)?
+                .remove(key.as_slice())
+                .map_err(backend)?;
+            let config = txn
+                .open_table(SCOPE_CONFIGS)
+                .map_err(backend)?
+                .get(key.as_slice())
+                .map_err(backend)?
+                .map(|stored| decode(stored.value()))
+                .transpose()?;
+            Ok(ScopeSnapshot { sessions, config })
+        })
+    }
+
+    fn update_scope_config<F>(&self, scope: &Scope, updater: F) -> Result<(), ConsensusError>
+    where
+        F: FnOnce(&mut ScopeConfig) -> Result<(), ConsensusError>,
+    {
+        let key = encode(scope)?;
+        self.write(|txn| {
+            let mut configs = txn.open_table(SCOPE_CONFIGS).map_err(backend)?;
+            let mut config: ScopeConfig = match configs.get(key.as_slice()).map_err(backend)? {
+                Some(stored) => decode(stored.value())?,
+                None => ScopeConfig::default(),
+            };
+            updater(&mut config)?;
+            config.validate()?;
+            configs
+                .insert(key.as_slice(), encode(&config)?.as_slice())
+                .map_err(backend)?;
+            Ok(())
+        })
+    }
+
+    fn is_scope_paused(&self, scope: &Scope) -> Result<bool, ConsensusError> {
+        let key = encode(scope)?;
+        let txn = self.db.begin_read().map_err(backend)?;
+        let paused = txn.open_table(PAUSED_SCOPES).map_err(backend)?;
+        Ok(paused.get(key.as_slice()).map_err(backend)?.is_some())
+    }
+
+    fn set_scope_paused(&self, scope: &Scope, paused: bool) -> Result<(), ConsensusError> {
+        let key = encode(scope)?;
+        self.write(|txn| {
+            let mut table = txn.open_table(PAUSED_SCOPES).map_err(backend)?;
+            if paused {
+                table.insert(key.as_slice(), ()).map_err(backend)?;
+            } else {
+                table.remove(key.as_slice()).map_err(backend)?;
+            }
+            Ok(())
+        })
+    }
+}
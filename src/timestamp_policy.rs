@@ -0,0 +1,57 @@
+//! Pluggable timestamp rules for incoming votes.
+//!
+//! RFC Section 3.4 rejects votes timestamped before the proposal was created
+//! (replay protection) or after it expired. Networks with different freshness
+//! needs can swap these rules by installing their own [`TimestampPolicy`].
+
+use crate::error::ConsensusError;
+
+/// Decides whether a vote's timestamp is acceptable.
+///
+/// Consulted for every incoming vote after the hash, signature, and
+/// eligibility checks pass. All values are seconds since Unix epoch. Install
+/// one with
+/// [`ConsensusService::with_timestamp_policy`](crate::service::ConsensusService::with_timestamp_policy).
+pub trait TimestampPolicy: Send + Sync + 'static {
+    /// Return `Ok(())` if a vote cast at `vote_timestamp` is acceptable for a
+    /// proposal created at `creation_time` that expires at `expiration_timestamp`,
+    /// evaluated at `now`.
+    fn validate(
+        &self,
+        vote_timestamp: u64,
+        creation_time: u64,
+        expiration_timestamp: u64,
+        now: u64,
+    ) -> Result<(), ConsensusError>;
+}
+
+/// Default policy implementing the RFC Section 3.4 rules.
+///
+/// Rejects votes older than the proposal with
+/// [`TimestampOlderThanCreationTime`](ConsensusError::TimestampOlderThanCreationTime),
+/// and votes timestamped after expiration or arriving after it with
+/// [`VoteExpired`](ConsensusError::VoteExpired).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RfcTimestampPolicy;
+
+impl TimestampPolicy for RfcTimestampPolicy {
+    fn validate(
+        &self,
+        vote_timestamp: u64,
+        creation_time: u64,
+        expiration_timestamp: u64,
+        now: u64,
+    ) -> Result<(), ConsensusError> {
+        // RFC Section 3.4:  Check the `timestamp` against the replay attack.
+        // In particular, the `timestamp` cannot be the old in the determined threshold.
+        if vote_timestamp < creation_time {
+            return Err(ConsensusError::TimestampOlderThanCreationTime);
+        }
+
+        if vote_timestamp > expiration_timestamp || now > expiration_timestamp {
+            return Err(ConsensusError::VoteExpired);
+        }
+
+        Ok(())
+    }
+}
@@ -2,26 +2,358 @@
 //!
 //! [`CreateProposalRequest`] is the input for creating new proposals.
 //! [`ConsensusEvent`] represents outcomes emitted via the event bus.
+//! [`ProposalId`] and [`VoteId`] give the wire's `u64` identifiers distinct types.
 
-use std::time::Duration;
+use std::{collections::HashMap, fmt, time::Duration};
 
 use crate::{
     error::ConsensusError,
-    protos::consensus::v1::Proposal,
-    utils::{generate_id, validate_expected_voters_count, validate_timeout},
+    protos::consensus::v1::{Proposal, Vote},
+    session::{ConsensusConfig, ConsensusState},
+    signing::ConsensusSignatureScheme,
+    utils::{
+        VoteChecks, VoteCounts, deterministic_proposal_id, generate_id, is_full_turnout_tie,
+        is_weighted_tie_at_timeout, sign_proposal, validate_expected_voters_count,
+        validate_timeout, validate_vote, validate_vote_chain,
+    },
 };
 
-/// Events emitted by the consensus service when a proposal reaches a terminal state.
+/// Identifier of a proposal (the wire's `proposal_id`).
+///
+/// Service methods accept anything convertible into it, so both a bare `u64`
+/// and [`Proposal::id`] work; a [`VoteId`] does not.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ProposalId(pub u64);
+
+/// Identifier of a single vote (the wire's `vote_id`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct VoteId(pub u64);
+
+impl From<u64> for ProposalId {
+    fn from(id: u64) -> Self {
+        Self(id)
+    }
+}
+
+impl From<ProposalId> for u64 {
+    fn from(id: ProposalId) -> Self {
+        id.0
+    }
+}
+
+impl fmt::Display for ProposalId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl From<u64> for VoteId {
+    fn from(id: u64) -> Self {
+        Self(id)
+    }
+}
+
+impl From<VoteId> for u64 {
+    fn from(id: VoteId) -> Self {
+        id.0
+    }
+}
+
+impl fmt::Display for VoteId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Proposal {
+    /// This proposal's typed identifier.
+    pub fn id(&self) -> ProposalId {
+        ProposalId(self.proposal_id)
+    }
+
+    /// The algorithm this proposal's votes are hashed with.
+    ///
+    /// Fails with [`UnsupportedHashAlgo`](ConsensusError::UnsupportedHashAlgo)
+    /// if the header names an algorithm this version does not know.
+    pub fn hash_algo(&self) -> Result<HashAlgo, ConsensusError> {
+        HashAlgo::try_from(self.hash_algo)
+    }
+}
+
+/// Hash function used for vote hashes (see
+/// [`compute_vote_hash_with`](crate::utils::compute_vote_hash_with)).
+///
+/// Vote hashes are signed and chained, so every peer must hash a proposal's
+/// votes the same way: the algorithm is chosen when the proposal is created
+/// and carried in its header (`hash_algo`), which its signature covers.
+/// Proposals that predate the field read as [`Sha256`](Self::Sha256).
+/// [`Blake3`](Self::Blake3) needs the `blake3` feature; without it, proposals
+/// that select it fail with
+/// [`UnsupportedHashAlgo`](ConsensusError::UnsupportedHashAlgo).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HashAlgo {
+    /// SHA-256 (wire value 0).
+    #[default]
+    Sha256,
+    /// BLAKE3 (wire value 1), faster on large proposals.
+    #[cfg(feature = "blake3")]
+    Blake3,
+}
+
+impl From<HashAlgo> for u32 {
+    fn from(algo: HashAlgo) -> Self {
+        match algo {
+            HashAlgo::Sha256 => 0,
+            #[cfg(feature = "blake3")]
+            HashAlgo::Blake3 => 1,
+        }
+    }
+}
+
+impl TryFrom<u32> for HashAlgo {
+    type Error = ConsensusError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(HashAlgo::Sha256),
+            #[cfg(feature = "blake3")]
+            1 => Ok(HashAlgo::Blake3),
+            other => Err(ConsensusError::UnsupportedHashAlgo(other)),
+        }
+    }
+}
+
+/// What a vote says: YES, NO, or an explicit abstention.
+///
+/// On the wire a vote is the `vote` bool plus the `abstain` flag, which
+/// votes from before abstentions existed leave unset, so they read as YES or
+/// NO exactly as before. An abstention counts toward turnout but toward
+/// neither side; silent peers are still weighted by the liveness criteria.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VoteChoice {
+    Yes,
+    No,
+    Abstain,
+}
+
+impl From<bool> for VoteChoice {
+    fn from(vote: bool) -> Self {
+        if vote {
+            VoteChoice::Yes
+        } else {
+            VoteChoice::No
+        }
+    }
+}
+
+impl Vote {
+    /// What this vote says. The `abstain` flag takes precedence over `vote`.
+    pub fn choice(&self) -> VoteChoice {
+        if self.abstain {
+            VoteChoice::Abstain
+        } else {
+            VoteChoice::from(self.vote)
+        }
+    }
+
+    /// This vote's typed identifier.
+    pub fn id(&self) -> VoteId {
+        VoteId(self.vote_id)
+    }
+
+    /// Typed identifier of the proposal this vote is cast on.
+    pub fn for_proposal(&self) -> ProposalId {
+        ProposalId(self.proposal_id)
+    }
+}
+
+/// Events emitted by the consensus service when a proposal reaches a terminal state,
+/// is removed from storage, or receives conflicting votes from one owner.
+///
+/// By default individual votes are not published, so event volume does not
+/// grow with voting load: a proposal yields at most one outcome event however
+/// many votes it receives. Services built
+/// [`with_vote_events`](crate::service::ConsensusService::with_vote_events)
+/// also emit [`VoteAdded`](Self::VoteAdded) for every vote that enters a tally,
+/// or coalesce them into [`VotesBatch`](Self::VotesBatch) events when built
+/// [`with_vote_batching`](crate::service::ConsensusService::with_vote_batching).
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ConsensusEvent {
     /// Consensus was reached! The proposal has a final result (yes or no).
     ConsensusReached {
-        proposal_id: u32,
+        proposal_id: u64,
         result: bool,
         timestamp: u64,
     },
     /// Consensus failed - not enough votes were collected before the timeout.
-    ConsensusFailed { proposal_id: u32, timestamp: u64 },
+    ConsensusFailed {
+        proposal_id: u64,
+        /// Human-readable explanation of why no side won (e.g. a tie at timeout).
+        reason: String,
+        timestamp: u64,
+    },
+    /// Like [`ConsensusReached`](Self::ConsensusReached), with the votes and
+    /// config that decided it attached. Emitted instead of `ConsensusReached`
+    /// when the service is built
+    /// [`with_certified_events`](crate::service::ConsensusService::with_certified_events).
+    ConsensusCertified {
+        proposal_id: u64,
+        result: bool,
+        timestamp: u64,
+        certificate: Box<ConsensusCertificate>,
+    },
+    /// The session was removed by the service; later lookups return `SessionNotFound`.
+    SessionEvicted {
+        proposal_id: u64,
+        reason: EvictionReason,
+        timestamp: u64,
+    },
+    /// A vote entered the proposal's tally (cast locally or received), with the
+    /// round after counting it. Only emitted by services built
+    /// [`with_vote_events`](crate::service::ConsensusService::with_vote_events);
+    /// ignored duplicates and late votes emit nothing.
+    VoteAdded {
+        proposal_id: u64,
+        voter: Vec<u8>,
+        choice: VoteChoice,
+        round: u32,
+        timestamp: u64,
+    },
+    /// The voters that entered the proposal's tally during one batching
+    /// window, each listed once, and `total`, the number of voters in the
+    /// tally after them. Emitted instead of [`VoteAdded`](Self::VoteAdded) by
+    /// services built
+    /// [`with_vote_batching`](crate::service::ConsensusService::with_vote_batching).
+    VotesBatch {
+        proposal_id: u64,
+        new_voters: Vec<Vec<u8>>,
+        total: usize,
+        timestamp: u64,
+    },
+    /// An owner signed both a YES and a NO vote on the proposal. `first` is
+    /// the vote the session holds and `second` the rejected conflicting one;
+    /// both carry valid signatures, so together they prove the equivocation.
+    EquivocationDetected {
+        proposal_id: u64,
+        first: Box<Vote>,
+        second: Box<Vote>,
+        timestamp: u64,
+    },
+}
+
+impl ConsensusEvent {
+    /// Whether this event reports a proposal's final outcome
+    /// ([`ConsensusReached`](Self::ConsensusReached),
+    /// [`ConsensusCertified`](Self::ConsensusCertified), or
+    /// [`ConsensusFailed`](Self::ConsensusFailed)).
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            Self::ConsensusReached { .. }
+                | Self::ConsensusCertified { .. }
+                | Self::ConsensusFailed { .. }
+        )
+    }
+}
+
+/// A finalized proposal with everything needed to check its result offline,
+/// carried by [`ConsensusEvent::ConsensusCertified`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConsensusCertificate {
+    /// The proposal as finalized, including every on-time vote in arrival order.
+    pub proposal: Proposal,
+    /// The decided result.
+    pub result: bool,
+    /// Whether the timeout decided the result (silent peers weighted by the
+    /// liveness criteria) rather than the votes alone.
+    pub decided_at_timeout: bool,
+    /// The session's configuration at finalization.
+    pub config: ConsensusConfig,
+}
+
+// The config's threshold is validated to [0.0, 1.0], never NaN, and every
+// other field compares totally.
+impl Eq for ConsensusCertificate {}
+
+impl ConsensusCertificate {
+    /// The votes that decided the proposal, in arrival order. When the config
+    /// allows vote changes, a vote replaced by its owner's later one stays in
+    /// the list (it anchors the hash chain) but only the later one counts.
+    pub fn deciding_votes(&self) -> &[Vote] {
+        &self.proposal.votes
+    }
+
+    /// Check the certificate without any local state: every vote must belong
+    /// to the proposal, carry a valid hash and signature under `Signer`, and
+    /// chain correctly, and the votes must decide [`result`](Self::result)
+    /// under the attached config. An owner may vote again only if the config
+    /// allows vote changes and the later vote names the earlier one as its
+    /// `parent_hash`; each owner's last vote is counted. Eligibility proofs
+    /// are not checked (that policy is local to each peer).
+    ///
+    /// Returns [`CertificateMismatch`](ConsensusError::CertificateMismatch) if
+    /// the votes are valid but do not decide the stated result.
+    pub fn verify<Signer: ConsensusSignatureScheme>(&self) -> Result<(), ConsensusError> {
+        let proposal = &self.proposal;
+        let checks = VoteChecks {
+            strict_signatures: self.config.strict_signatures(),
+            ..VoteChecks::default()
+        };
+        let mut votes = HashMap::new();
+        for vote in &proposal.votes {
+            if vote.proposal_id != proposal.proposal_id {
+                return Err(ConsensusError::VoteProposalIdMismatch);
+            }
+            validate_vote::<Signer>(vote, proposal, vote.timestamp, &checks)?;
+            match votes.insert(vote.vote_owner.clone(), vote.clone()) {
+                Some(previous)
+                    if self.config.allow_vote_changes()
+                        && vote.parent_hash == previous.vote_hash => {}
+                Some(_) => return Err(ConsensusError::DuplicateVote),
+                None => {}
+            }
+        }
+        validate_vote_chain(&proposal.votes)?;
+
+        let expected_voters = proposal.expected_voters_count;
+        let liveness = proposal.liveness_criteria_yes;
+        let weights = self.config.voter_weights();
+        let counts = VoteCounts::recount(&votes);
+        let mut decided =
+            self.config
+                .result_for_votes(proposal, &votes, counts, self.decided_at_timeout);
+        if decided.is_none()
+            && self.decided_at_timeout
+            && self.config.resolve_partial_tie_on_timeout()
+            && is_weighted_tie_at_timeout(&votes, expected_voters, weights, liveness)
+            && !is_full_turnout_tie(
+                &votes,
+                counts,
+                expected_voters,
+                weights,
+                self.config.threshold_mode(),
+            )
+        {
+            decided = Some(liveness);
+        }
+        if decided != Some(self.result) {
+            return Err(ConsensusError::CertificateMismatch);
+        }
+        Ok(())
+    }
+}
+
+/// Why a session was evicted (see [`ConsensusEvent::SessionEvicted`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EvictionReason {
+    /// The scope exceeded `max_sessions_per_scope` and the oldest sessions were trimmed.
+    CapacityTrim,
+    /// The session was finalized longer ago than the scope's `session_ttl`
+    /// and was collected by `gc_finalized_sessions`.
+    TtlExpired,
 }
 
 /// Internal transition result returned after adding a vote to a session.
@@ -31,6 +363,303 @@ pub enum SessionTransition {
     StillActive,
     /// Session converged to a boolean result.
     ConsensusReached(bool),
+    /// Session failed on a vote, for the given reason.
+    Failed(FailReason),
+    /// Session was already terminal; the vote was kept as a late vote only.
+    LateVoteRecorded,
+}
+
+/// Why a vote failed a session (see [`SessionTransition::Failed`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FailReason {
+    /// YES and NO tied at full turnout under
+    /// [`TieBreak::Fail`](crate::scope_config::TieBreak::Fail).
+    Tie,
+    /// A vote arrived past the session's round limit before consensus.
+    MaxRoundsExceeded,
+}
+
+impl fmt::Display for FailReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FailReason::Tie => f.write_str("YES and NO tied at full turnout under TieBreak::Fail"),
+            FailReason::MaxRoundsExceeded => f.write_str("round limit exceeded before consensus"),
+        }
+    }
+}
+
+/// Outcome of [`handle_consensus_timeout`](crate::service::ConsensusService::handle_consensus_timeout).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeoutOutcome {
+    /// The timeout decided the proposal (silent peers counted per liveness criteria).
+    Reached(bool),
+    /// No decision could be made at timeout; the session is now `Failed`.
+    Failed,
+    /// Consensus had already been reached before the timeout fired. No event is emitted.
+    AlreadyReached(bool),
+}
+
+/// Result of [`finalize_expired`](crate::service::ConsensusService::finalize_expired).
+///
+/// A session that cannot be finalized does not stop the sweep: its error is
+/// recorded, the session stays active, and the next sweep retries it.
+#[derive(Debug, Default)]
+pub struct ExpirySweep {
+    /// Finalized proposals ordered by `proposal_id`: `Some(result)` if
+    /// decided, `None` if the session failed.
+    pub finalized: Vec<(u64, Option<bool>)>,
+    /// Proposals that could not be finalized, ordered by `proposal_id`.
+    pub errors: Vec<(u64, ConsensusError)>,
+}
+
+impl ExpirySweep {
+    /// Whether the sweep neither finalized nor failed on any session.
+    pub fn is_empty(&self) -> bool {
+        self.finalized.is_empty() && self.errors.is_empty()
+    }
+}
+
+/// Breakdown of the quorum math for a proposal's current votes.
+///
+/// Returned by [`ConsensusSession::explain`](crate::session::ConsensusSession::explain).
+/// Weights and the verdict use the in-flight rules (no timeout weighting), so
+/// they show what the recorded votes decide on their own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConsensusExplanation {
+    /// Stored session state (a timeout may have decided it independently of `verdict`).
+    pub state: ConsensusState,
+    /// Number of expected voters (`n`).
+    pub expected_voters: u32,
+    /// Votes that must be cast before any decision is possible.
+    pub required_votes: u32,
+    /// Weight a side needs to win under the threshold rule.
+    pub required_choice_votes: u32,
+    /// Recorded YES votes.
+    pub yes_votes: u32,
+    /// Recorded NO votes.
+    pub no_votes: u32,
+    /// Recorded abstentions (counted toward turnout, not toward either side).
+    pub abstain_votes: u32,
+    /// Expected voters who have not voted yet.
+    pub silent_votes: u32,
+    /// Whether silent peers are weighted toward YES (`true`) or NO (`false`).
+    pub liveness_criteria_yes: bool,
+    /// YES votes plus silent peers if `liveness_criteria_yes`.
+    pub yes_weight: u32,
+    /// NO votes plus silent peers unless `liveness_criteria_yes`.
+    pub no_weight: u32,
+    /// Which rule governs the decision for these votes.
+    pub rule: QuorumRule,
+    /// What the recorded votes decide, or why they don't yet.
+    pub verdict: QuorumVerdict,
+}
+
+/// How a finalized proposal was decided.
+///
+/// Returned by
+/// [`ConsensusStorage::get_consensus_outcome`](crate::storage::ConsensusStorage::get_consensus_outcome).
+/// Counts are the recorded votes; `silent` expected voters were weighted by
+/// the proposal's liveness criteria.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConsensusOutcome {
+    /// The decided result (`true` = YES).
+    pub result: bool,
+    /// Recorded YES votes.
+    pub yes: u32,
+    /// Recorded NO votes.
+    pub no: u32,
+    /// Recorded abstentions.
+    pub abstain: u32,
+    /// Expected voters who did not vote.
+    pub silent: u32,
+    /// The YES and NO weights were tied, so the liveness criteria picked the result.
+    pub decided_by_liveness: bool,
+    /// The proposal's round when it was decided.
+    pub round: u32,
+}
+
+/// Live vote counts for a proposal, e.g. "4 YES / 2 NO, need 5 to finalize".
+///
+/// A compact view of [`ConsensusExplanation`], returned by
+/// [`ConsensusStorage::get_vote_tally`](crate::storage::ConsensusStorage::get_vote_tally)
+/// for active and terminal sessions alike.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VoteTally {
+    /// Recorded YES votes.
+    pub yes: u32,
+    /// Recorded NO votes.
+    pub no: u32,
+    /// Recorded abstentions.
+    pub abstain: u32,
+    /// Expected voters who have not voted yet.
+    pub silent: u32,
+    /// Votes that must be cast before any decision is possible.
+    pub required_votes: u32,
+    /// Weight a side needs to win under the threshold rule.
+    pub required_choice_votes: u32,
+}
+
+impl From<&ConsensusExplanation> for VoteTally {
+    fn from(explanation: &ConsensusExplanation) -> Self {
+        Self {
+            yes: explanation.yes_votes,
+            no: explanation.no_votes,
+            abstain: explanation.abstain_votes,
+            silent: explanation.silent_votes,
+            required_votes: explanation.required_votes,
+            required_choice_votes: explanation.required_choice_votes,
+        }
+    }
+}
+
+/// How far a proposal is from finalizing as YES, e.g. "2 more YES needed".
+///
+/// Returned by
+/// [`ConsensusStorage::votes_needed`](crate::storage::ConsensusStorage::votes_needed).
+/// Counts are per voter, as in [`VoteTally`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConsensusProgress {
+    /// Distinct voters with a recorded vote (YES, NO, or abstain).
+    pub current_votes: u32,
+    /// Votes that must be cast before any decision is possible.
+    pub required_votes: u32,
+    /// Recorded YES votes.
+    pub current_yes: u32,
+    /// Further YES votes that guarantee YES consensus, saturating at zero:
+    /// a strict majority of `n` (`n / 2 + 1`, or the threshold if higher)
+    /// for `n > 2`, all `n` voters for `n <= 2`, and the configured count
+    /// under [`ThresholdMode::AbsoluteYes`](crate::scope_config::ThresholdMode::AbsoluteYes).
+    pub yes_needed_for_consensus: u32,
+}
+
+impl From<&ConsensusExplanation> for ConsensusProgress {
+    fn from(explanation: &ConsensusExplanation) -> Self {
+        let required_yes = match explanation.rule {
+            QuorumRule::AbsoluteYes => explanation.required_choice_votes,
+            QuorumRule::Unanimity => explanation.expected_voters,
+            QuorumRule::WeightedMajority | QuorumRule::LivenessTieBreak => {
+                (explanation.expected_voters / 2 + 1).max(explanation.required_choice_votes)
+            }
+        };
+        Self {
+            current_votes: explanation.yes_votes + explanation.no_votes + explanation.abstain_votes,
+            required_votes: explanation.required_votes,
+            current_yes: explanation.yes_votes,
+            yes_needed_for_consensus: required_yes.saturating_sub(explanation.yes_votes),
+        }
+    }
+}
+
+/// Decision rule in effect for a proposal (see [`ConsensusExplanation`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuorumRule {
+    /// `n <= 2`: every expected voter must vote, and only a unanimous YES passes
+    /// (for `n = 1` that is simply the lone voter's choice).
+    Unanimity,
+    /// `n > 2`: a side wins with at least `required_choice_votes` weight and
+    /// strictly more weight than the other side.
+    WeightedMajority,
+    /// `n > 2`, full turnout and equal weights: the liveness criteria break the tie.
+    LivenessTieBreak,
+    /// [`ThresholdMode::AbsoluteYes`](crate::scope_config::ThresholdMode::AbsoluteYes):
+    /// YES with `required_choice_votes` YES weight, NO once that is out of reach.
+    AbsoluteYes,
+}
+
+/// What the recorded votes decide (see [`ConsensusExplanation`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuorumVerdict {
+    /// The votes decide the proposal with this result.
+    Decided(bool),
+    /// Turnout is below `required_votes`; `missing` more votes are needed.
+    NeedsMoreVotes { missing: u32 },
+    /// Turnout is sufficient but neither side has a qualifying majority.
+    NoQualifiedMajority,
+}
+
+/// Work needed to validate an inbound proposal, from
+/// [`estimate_validation_cost`](crate::utils::estimate_validation_cost).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ValidationCost {
+    /// Signatures to verify: the proposal's own plus one per embedded vote.
+    pub signature_verifications: usize,
+    /// Hashgraph links between consecutive votes to check.
+    pub chain_checks: usize,
+}
+
+/// Per-vote outcome of
+/// [`apply_votes_and_status`](crate::service::ConsensusService::apply_votes_and_status).
+#[derive(Debug)]
+pub enum VoteApplyResult {
+    /// The vote was recorded (on time, or as a late vote).
+    Accepted,
+    /// The session already held this exact vote; nothing changed.
+    Duplicate,
+    /// The vote was refused with this error.
+    Rejected(ConsensusError),
+}
+
+/// A proposal's overall status after a batch of votes was applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConsensusStatus {
+    /// Session state after the batch.
+    pub state: ConsensusState,
+    /// Distinct peers with a recorded (on-time) vote.
+    pub distinct_voters: u32,
+    /// Number of expected voters (`n`).
+    pub expected_voters: u32,
+}
+
+/// Findings of [`verify_scope_integrity`](crate::service::ConsensusService::verify_scope_integrity).
+#[derive(Debug, Default)]
+pub struct IntegrityReport {
+    /// Number of sessions examined.
+    pub sessions_checked: usize,
+    /// Problems found, ordered by `proposal_id`.
+    pub issues: Vec<IntegrityIssue>,
+}
+
+impl IntegrityReport {
+    /// Whether every session matched its recomputation.
+    pub fn is_clean(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Every problem found in a proposal, returned by
+/// [`validate_proposal_report`](crate::utils::validate_proposal_report).
+#[derive(Debug, Default)]
+pub struct ProposalValidationReport {
+    /// Problems with the proposal itself (signature, expiration).
+    pub proposal_issues: Vec<ConsensusError>,
+    /// Problems with individual votes, keyed by index in `proposal.votes`
+    /// and ordered by it.
+    pub vote_issues: Vec<(usize, ConsensusError)>,
+}
+
+impl ProposalValidationReport {
+    /// Whether no problem was found, i.e.
+    /// [`validate_proposal`](crate::utils::validate_proposal) accepts the proposal.
+    pub fn is_valid(&self) -> bool {
+        self.proposal_issues.is_empty() && self.vote_issues.is_empty()
+    }
+}
+
+/// A stored session that disagrees with recomputation (see [`IntegrityReport`]).
+#[derive(Debug)]
+pub enum IntegrityIssue {
+    /// The proposal's votes no longer validate (hash, signature, eligibility, or chain).
+    InvalidVotes {
+        proposal_id: u64,
+        error: ConsensusError,
+    },
+    /// The stored state is not what the recorded votes decide.
+    ResultMismatch {
+        proposal_id: u64,
+        stored: ConsensusState,
+        recomputed: Option<bool>,
+    },
 }
 
 /// Parameters for creating a new proposal.
@@ -50,7 +679,9 @@ pub struct CreateProposalRequest {
     /// (`ceil(2n/3)` quorum, silent peer counting at timeout). Must match the
     /// actual group size — a wrong value produces wrong consensus results.
     pub expected_voters_count: u32,
-    /// The timestamp at which the proposal becomes outdated.
+    /// How long the proposal stays open, in seconds after its creation. Despite
+    /// the name this is a duration: [`into_proposal`](Self::into_proposal)
+    /// turns it into the absolute `Proposal::expiration_timestamp`.
     pub expiration_timestamp: u64,
     /// How silent peers (those who never vote) are counted at timeout:
     /// `true` = silent peers count as YES, `false` = silent peers count as NO.
@@ -82,14 +713,52 @@ impl CreateProposalRequest {
         Ok(request)
     }
 
+    /// Like [`new`](Self::new), but takes a text payload, stored as its UTF-8
+    /// bytes.
+    pub fn new_text(
+        name: String,
+        text: &str,
+        proposal_owner: Vec<u8>,
+        expected_voters_count: u32,
+        expiration_timestamp: u64,
+        liveness_criteria_yes: bool,
+    ) -> Result<Self, ConsensusError> {
+        Self::new(
+            name,
+            text.as_bytes().to_vec(),
+            proposal_owner,
+            expected_voters_count,
+            expiration_timestamp,
+            liveness_criteria_yes,
+        )
+    }
+
     /// Convert this request into an actual proposal.
     ///
     /// Generates a unique proposal ID and stamps `now` (seconds since Unix epoch)
     /// as the creation timestamp; the absolute expiration is derived from it.
-    /// The proposal starts with round 1 and no votes.
+    /// The proposal starts with round 1 and no votes, and is unsigned: peers
+    /// reject it until it is signed (see
+    /// [`into_signed_proposal`](Self::into_signed_proposal)).
     pub fn into_proposal(self, now: u64) -> Result<Proposal, ConsensusError> {
-        let proposal_id = generate_id();
+        self.into_proposal_with_id(generate_id(), now)
+    }
 
+    /// Like [`into_proposal`](Self::into_proposal), but derives the proposal
+    /// ID from a hash of the name, payload, owner, expected voter count, and
+    /// liveness criteria instead of generating a random one.
+    ///
+    /// Peers building the same proposal get the same ID, so a duplicate is
+    /// refused with [`ProposalAlreadyExist`](ConsensusError::ProposalAlreadyExist)
+    /// instead of opening a second session. The flip side is that re-creating
+    /// identical content in the same scope collides with the earlier proposal;
+    /// vary the name or payload to propose it again.
+    pub fn into_deterministic_proposal(self, now: u64) -> Result<Proposal, ConsensusError> {
+        let proposal_id = deterministic_proposal_id(&self);
+        self.into_proposal_with_id(proposal_id, now)
+    }
+
+    fn into_proposal_with_id(self, proposal_id: u64, now: u64) -> Result<Proposal, ConsensusError> {
         Ok(Proposal {
             name: self.name,
             payload: self.payload,
@@ -101,15 +770,82 @@ impl CreateProposalRequest {
             timestamp: now,
             expiration_timestamp: now.saturating_add(self.expiration_timestamp),
             liveness_criteria_yes: self.liveness_criteria_yes,
+            proposal_signature: Vec::new(),
+            hash_algo: HashAlgo::default().into(),
         })
     }
+
+    /// Like [`into_proposal`](Self::into_proposal), but signs the proposal
+    /// with `signer`, whose identity must be the `proposal_owner` for peers to
+    /// accept it.
+    pub fn into_signed_proposal<Signer: ConsensusSignatureScheme>(
+        self,
+        signer: &Signer,
+        now: u64,
+    ) -> Result<Proposal, ConsensusError> {
+        let mut proposal = self.into_proposal(now)?;
+        sign_proposal(&mut proposal, signer)?;
+        Ok(proposal)
+    }
+
+    /// Like [`into_deterministic_proposal`](Self::into_deterministic_proposal),
+    /// but signs the proposal with `signer`.
+    pub fn into_signed_deterministic_proposal<Signer: ConsensusSignatureScheme>(
+        self,
+        signer: &Signer,
+        now: u64,
+    ) -> Result<Proposal, ConsensusError> {
+        let mut proposal = self.into_deterministic_proposal(now)?;
+        sign_proposal(&mut proposal, signer)?;
+        Ok(proposal)
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::CreateProposalRequest;
+    use std::any::TypeId;
+
+    use super::{CreateProposalRequest, ProposalId, VoteId};
     use crate::test_utils::now_ts;
 
+    #[test]
+    fn id_newtypes_convert_from_u64_and_stay_distinct() {
+        let proposal_id: ProposalId = 7u64.into();
+        let vote_id: VoteId = 7u64.into();
+        assert_eq!(proposal_id, ProposalId(7));
+        assert_eq!(u64::from(proposal_id), u64::from(vote_id));
+        assert_ne!(TypeId::of::<ProposalId>(), TypeId::of::<VoteId>());
+        assert_eq!(proposal_id.to_string(), "7");
+    }
+
+    #[test]
+    fn deterministic_proposal_id_depends_only_on_content() {
+        let request = |name: &str, liveness_criteria_yes| {
+            CreateProposalRequest::new(
+                name.to_string(),
+                b"payload".to_vec(),
+                vec![1u8; 20],
+                3,
+                60,
+                liveness_criteria_yes,
+            )
+            .expect("request should be valid")
+        };
+
+        let first = request("upgrade", true)
+            .into_deterministic_proposal(100)
+            .unwrap();
+        let second = request("upgrade", true)
+            .into_deterministic_proposal(200)
+            .unwrap();
+        assert_eq!(first.proposal_id, second.proposal_id);
+
+        for other in [request("upgrade-v2", true), request("upgrade", false)] {
+            let other = other.into_deterministic_proposal(100).unwrap();
+            assert_ne!(first.proposal_id, other.proposal_id);
+        }
+    }
+
     #[test]
     fn into_proposal_should_not_overflow_expiration_timestamp() {
         let request = CreateProposalRequest::new(
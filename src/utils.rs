@@ -10,32 +10,122 @@ use std::{collections::HashMap, time::Duration};
 use uuid::Uuid;
 
 use crate::{
+    eligibility::{AcceptAllEligibility, EligibilityVerifier},
     error::ConsensusError,
     protos::consensus::v1::{Proposal, Vote},
+    scope_config::{
+        DEFAULT_MAX_PAYLOAD_BYTES, DEFAULT_MAX_PROPOSAL_LIFETIME, DEFAULT_MAX_VOTE_AGE,
+        ThresholdMode,
+    },
+    session::{ConsensusSession, ConsensusState},
     signing::ConsensusSignatureScheme,
+    timestamp_policy::{RfcTimestampPolicy, TimestampPolicy},
+    types::{
+        ConsensusExplanation, CreateProposalRequest, HashAlgo, ProposalValidationReport,
+        QuorumRule, QuorumVerdict, ValidationCost, VoteChoice,
+    },
+    weights::VoterWeights,
 };
 
-/// Fold a 128-bit value into 32 bits via XOR so every bit contributes.
-fn fold_u128_to_u32(n: u128) -> u32 {
-    ((n >> 96) as u32) ^ ((n >> 64) as u32) ^ ((n >> 32) as u32) ^ (n as u32)
+/// Per-peer policy applied by [`validate_vote`] on top of the hash and signature checks.
+pub(crate) struct VoteChecks<'a> {
+    /// Decides whether the vote's eligibility proof is acceptable.
+    pub(crate) eligibility: &'a dyn EligibilityVerifier,
+    /// Decides whether the vote's timestamp is acceptable.
+    pub(crate) timestamps: &'a dyn TimestampPolicy,
+    /// Reject signatures the scheme reports as non-canonical (e.g. high-S ECDSA).
+    pub(crate) strict_signatures: bool,
+    /// Furthest past `now` an inbound proposal may expire.
+    pub(crate) max_proposal_lifetime: Duration,
+    /// Oldest a vote's timestamp may be at `now`.
+    pub(crate) max_vote_age: Duration,
 }
 
-/// Generate a unique 32-bit ID from a UUID.
+impl Default for VoteChecks<'_> {
+    fn default() -> Self {
+        Self {
+            eligibility: &AcceptAllEligibility,
+            timestamps: &RfcTimestampPolicy,
+            strict_signatures: true,
+            max_proposal_lifetime: DEFAULT_MAX_PROPOSAL_LIFETIME,
+            max_vote_age: DEFAULT_MAX_VOTE_AGE,
+        }
+    }
+}
+
+/// Mix a 128-bit value down to 64 bits so every input bit affects the result.
 ///
-/// Uses XOR folding so all 128 bits of the UUID contribute to the result,
-/// avoiding collisions from simple truncation.
-pub(crate) fn generate_id() -> u32 {
+/// XOR-folds the two halves, then applies the SplitMix64 finalizer (a
+/// bijection on `u64`) so values that differ in a few bits spread apart.
+fn mix_u128_to_u64(n: u128) -> u64 {
+    let mut x = ((n >> 64) as u64) ^ (n as u64);
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+/// Generate a unique 64-bit ID from a UUID.
+///
+/// All 128 bits of the UUID are mixed into the result (see
+/// [`mix_u128_to_u64`]) rather than truncated. Collisions stay possible, if
+/// unlikely, so locally created proposals are stored with
+/// [`insert_session_if_absent`](crate::storage::ConsensusStorage::insert_session_if_absent)
+/// and a colliding ID is refused rather than overwriting a session.
+///
+/// There is deliberately no 128-bit mode. protobuf has no 128-bit integer,
+/// and a cargo feature that changed the ID type would not be additive:
+/// enabling it anywhere in a build would change the API for every crate
+/// using this one, and peers built with and without it could not talk to
+/// each other. Any wider ID needs a new [`PROTOCOL_VERSION`](crate::wire::PROTOCOL_VERSION).
+pub(crate) fn generate_id() -> u64 {
     let uuid = Uuid::new_v4();
-    fold_u128_to_u32(uuid.as_u128())
+    mix_u128_to_u64(uuid.as_u128())
+}
+
+/// Derive a proposal ID from the content of a proposal request.
+///
+/// Hashes the name, payload, owner, expected voter count, and liveness
+/// criteria (each variable-length field length-prefixed) and keeps the first
+/// 64 bits, so peers building the same proposal agree on its ID.
+pub(crate) fn deterministic_proposal_id(request: &CreateProposalRequest) -> u64 {
+    let mut hasher = Sha256::new();
+    hasher.update(DETERMINISTIC_PROPOSAL_ID_TAG);
+    hasher.update((request.name.len() as u64).to_le_bytes());
+    hasher.update(request.name.as_bytes());
+    hasher.update((request.payload.len() as u64).to_le_bytes());
+    hasher.update(&request.payload);
+    hasher.update((request.proposal_owner.len() as u64).to_le_bytes());
+    hasher.update(&request.proposal_owner);
+    hasher.update(request.expected_voters_count.to_le_bytes());
+    hasher.update([request.liveness_criteria_yes as u8]);
+    let digest = hasher.finalize();
+    let mut id = [0u8; 8];
+    id.copy_from_slice(&digest[..8]);
+    u64::from_le_bytes(id)
 }
 
+/// Domain tag prefixed to the content hash behind deterministic proposal IDs.
+const DETERMINISTIC_PROPOSAL_ID_TAG: &[u8] = b"consensus.v1.Proposal.proposal_id";
+
 /// Compute the hash of a vote for signing and validation.
 ///
 /// This creates a deterministic hash from all the vote's fields (ID, owner, proposal ID,
-/// timestamp, vote choice, and parent/received hashes). Everyone computes the same hash
+/// timestamp, vote choice, parent/received hashes, and eligibility proof). Everyone computes the same hash
 /// for the same vote, which is important for verification.
+///
+/// The `abstain` flag is hashed only when set, so votes from before
+/// abstentions existed keep their hashes and signatures.
+///
+/// Uses SHA-256, the default algorithm; votes on a proposal whose header
+/// selects another one are hashed with [`compute_vote_hash_with`].
 pub fn compute_vote_hash(vote: &Vote) -> Vec<u8> {
-    let mut hasher = Sha256::new();
+    compute_vote_hash_with(vote, HashAlgo::Sha256)
+}
+
+/// Like [`compute_vote_hash`], but with the given hash algorithm (see
+/// [`Proposal::hash_algo`]).
+pub fn compute_vote_hash_with(vote: &Vote, algo: HashAlgo) -> Vec<u8> {
+    let mut hasher = VoteHasher::new(algo);
     hasher.update(vote.vote_id.to_le_bytes());
     hasher.update(&vote.vote_owner);
     hasher.update(vote.proposal_id.to_le_bytes());
@@ -43,92 +133,441 @@ pub fn compute_vote_hash(vote: &Vote) -> Vec<u8> {
     hasher.update([vote.vote as u8]);
     hasher.update(&vote.parent_hash);
     hasher.update(&vote.received_hash);
+    hasher.update(&vote.eligibility_proof);
+    if vote.abstain {
+        hasher.update(ABSTAIN_HASH_TAG);
+    }
+    hasher.finalize()
+}
+
+/// Domain tag appended to the hash of an abstaining vote.
+const ABSTAIN_HASH_TAG: &[u8] = b"consensus.v1.Vote.abstain";
+
+/// Incremental hasher for the algorithms a [`HashAlgo`] can select.
+enum VoteHasher {
+    Sha256(Sha256),
+    #[cfg(feature = "blake3")]
+    Blake3(Box<blake3::Hasher>),
+}
+
+impl VoteHasher {
+    fn new(algo: HashAlgo) -> Self {
+        match algo {
+            HashAlgo::Sha256 => Self::Sha256(Sha256::new()),
+            #[cfg(feature = "blake3")]
+            HashAlgo::Blake3 => Self::Blake3(Box::new(blake3::Hasher::new())),
+        }
+    }
+
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        match self {
+            Self::Sha256(hasher) => hasher.update(data),
+            #[cfg(feature = "blake3")]
+            Self::Blake3(hasher) => {
+                hasher.update(data.as_ref());
+            }
+        }
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        match self {
+            Self::Sha256(hasher) => hasher.finalize().to_vec(),
+            #[cfg(feature = "blake3")]
+            Self::Blake3(hasher) => hasher.finalize().as_bytes().to_vec(),
+        }
+    }
+}
+
+/// Compute the hash of a proposal for signing and validation.
+///
+/// Covers the fields fixed at creation (name, payload, ID, owner, expected
+/// voters, timestamps, liveness criteria, and vote hash algorithm) but not the
+/// votes or round, which change as the proposal is gossiped. The algorithm is
+/// hashed only when it is not the default, so proposals from before it was
+/// selectable keep their hashes and signatures.
+pub fn compute_proposal_hash(proposal: &Proposal) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+    hasher.update((proposal.name.len() as u64).to_le_bytes());
+    hasher.update(proposal.name.as_bytes());
+    hasher.update((proposal.payload.len() as u64).to_le_bytes());
+    hasher.update(&proposal.payload);
+    hasher.update(proposal.proposal_id.to_le_bytes());
+    hasher.update((proposal.proposal_owner.len() as u64).to_le_bytes());
+    hasher.update(&proposal.proposal_owner);
+    hasher.update(proposal.expected_voters_count.to_le_bytes());
+    hasher.update(proposal.timestamp.to_le_bytes());
+    hasher.update(proposal.expiration_timestamp.to_le_bytes());
+    hasher.update([proposal.liveness_criteria_yes as u8]);
+    if proposal.hash_algo != u32::from(HashAlgo::default()) {
+        hasher.update(HASH_ALGO_HASH_TAG);
+        hasher.update(proposal.hash_algo.to_le_bytes());
+    }
     hasher.finalize().to_vec()
 }
 
+/// Domain tag preceding a non-default vote hash algorithm in a proposal's hash.
+const HASH_ALGO_HASH_TAG: &[u8] = b"consensus.v1.Proposal.hash_algo";
+
+/// Compute a digest of a session's consensus-relevant state.
+///
+/// Hashes the sorted vote hashes of the proposal together with the session
+/// state and round, so two honest peers that processed the same votes agree
+/// regardless of arrival order. Late votes and local bookkeeping (annotations,
+/// `finalized_at`) are not covered.
+pub fn compute_session_digest(session: &ConsensusSession) -> [u8; 32] {
+    let mut vote_hashes: Vec<&[u8]> = session
+        .proposal
+        .votes
+        .iter()
+        .map(|vote| vote.vote_hash.as_slice())
+        .collect();
+    vote_hashes.sort_unstable();
+
+    let mut hasher = Sha256::new();
+    hasher.update((vote_hashes.len() as u64).to_le_bytes());
+    for hash in vote_hashes {
+        hasher.update((hash.len() as u64).to_le_bytes());
+        hasher.update(hash);
+    }
+    match session.state {
+        ConsensusState::Active => hasher.update([0]),
+        ConsensusState::ConsensusReached(result) => hasher.update([1, result as u8]),
+        ConsensusState::Failed => hasher.update([2]),
+    }
+    hasher.update(session.proposal.round.to_le_bytes());
+    hasher.finalize().into()
+}
+
+/// Sign `proposal` with `signer`, setting its `proposal_signature`.
+///
+/// The signature covers [`compute_proposal_hash`], so it only verifies for
+/// peers if `proposal_owner` is the signer's identity.
+pub fn sign_proposal<Signer: ConsensusSignatureScheme>(
+    proposal: &mut Proposal,
+    signer: &Signer,
+) -> Result<(), ConsensusError> {
+    let signature = signer.sign(&compute_proposal_hash(proposal))?;
+    if !Signer::is_canonical(&signature) {
+        return Err(ConsensusError::NonCanonicalSignature);
+    }
+    proposal.proposal_signature = signature;
+    Ok(())
+}
+
+/// Check that `proposal_signature` was produced by `proposal_owner`.
+///
+/// Missing, malformed, or mismatched signatures fail with
+/// [`InvalidProposalSignature`](ConsensusError::InvalidProposalSignature).
+pub(crate) fn verify_proposal_signature<Signer: ConsensusSignatureScheme>(
+    proposal: &Proposal,
+) -> Result<(), ConsensusError> {
+    if proposal.proposal_signature.is_empty() {
+        return Err(ConsensusError::InvalidProposalSignature);
+    }
+    match Signer::verify(
+        &proposal.proposal_owner,
+        &compute_proposal_hash(proposal),
+        &proposal.proposal_signature,
+    ) {
+        Ok(true) => Ok(()),
+        Ok(false) | Err(_) => Err(ConsensusError::InvalidProposalSignature),
+    }
+}
+
 /// Create a new vote for a proposal with proper hash chain linking.
 ///
 /// This builds a vote that links to previous votes in the hashgraph structure.
 /// The vote is signed with the provided signer and includes all the necessary
 /// fields for validation (parent_hash, received_hash, vote_hash, signature).
 /// `now` (seconds since Unix epoch) becomes the vote's timestamp.
+///
+/// `user_vote` is a `bool` (YES/NO) or a [`VoteChoice`], e.g.
+/// [`VoteChoice::Abstain`].
 pub fn build_vote<Signer: ConsensusSignatureScheme>(
     proposal: &Proposal,
-    user_vote: bool,
+    user_vote: impl Into<VoteChoice>,
     signer: &Signer,
     now: u64,
 ) -> Result<Vote, ConsensusError> {
-    let voter_identity = signer.identity();
+    build_vote_with_eligibility_proof(proposal, user_vote, signer, Vec::new(), now)
+}
+
+/// Like [`build_vote`], but attaches an eligibility proof to the vote.
+///
+/// The proof is covered by the vote hash and signature, and is checked by the
+/// receiving peer's [`EligibilityVerifier`].
+pub fn build_vote_with_eligibility_proof<Signer: ConsensusSignatureScheme>(
+    proposal: &Proposal,
+    user_vote: impl Into<VoteChoice>,
+    signer: &Signer,
+    eligibility_proof: Vec<u8>,
+    now: u64,
+) -> Result<Vote, ConsensusError> {
     // RFC Section 2.2: Define `parent_hash` as hash of previous owner's vote (empty if none).
-    // RFC Section 2.3: Set `received_hash` to hash of immediately previous vote (last vote in list).
-    let (parent_hash, received_hash) = if let Some(latest_vote) = proposal.votes.last() {
-        let own_last_vote = proposal
-            .votes
-            .iter()
-            .rfind(|v| v.vote_owner.as_slice() == voter_identity);
-
-        if let Some(own_vote) = own_last_vote {
-            (own_vote.vote_hash.clone(), latest_vote.vote_hash.clone())
-        } else {
-            (Vec::new(), latest_vote.vote_hash.clone())
-        }
-    } else {
-        (Vec::new(), Vec::new())
-    };
+    let own_last_vote = proposal
+        .votes
+        .iter()
+        .rfind(|v| v.vote_owner.as_slice() == signer.identity());
+    sign_linked_vote(
+        proposal,
+        own_last_vote,
+        user_vote.into(),
+        signer,
+        eligibility_proof,
+        now,
+    )
+}
+
+/// Like [`build_vote`], but builds on a stored session.
+///
+/// The voter's previous vote (the `parent_hash`) is looked up in the
+/// session's per-owner vote map rather than by scanning the proposal's vote
+/// list, so building a vote stays constant-time however many votes the
+/// proposal holds. The result is identical to
+/// `build_vote(&session.proposal, ...)`.
+pub fn build_vote_from_session<Signer: ConsensusSignatureScheme>(
+    session: &ConsensusSession,
+    user_vote: impl Into<VoteChoice>,
+    signer: &Signer,
+    now: u64,
+) -> Result<Vote, ConsensusError> {
+    build_vote_from_session_with_eligibility_proof(session, user_vote, signer, Vec::new(), now)
+}
 
-    let vote_id = generate_id();
+/// Like [`build_vote_from_session`], but attaches an eligibility proof to the vote.
+pub fn build_vote_from_session_with_eligibility_proof<Signer: ConsensusSignatureScheme>(
+    session: &ConsensusSession,
+    user_vote: impl Into<VoteChoice>,
+    signer: &Signer,
+    eligibility_proof: Vec<u8>,
+    now: u64,
+) -> Result<Vote, ConsensusError> {
+    sign_linked_vote(
+        &session.proposal,
+        session.votes().get(signer.identity()),
+        user_vote.into(),
+        signer,
+        eligibility_proof,
+        now,
+    )
+}
+
+/// Build and sign a vote on `proposal` whose `parent_hash` is `own_last_vote`.
+fn sign_linked_vote<Signer: ConsensusSignatureScheme>(
+    proposal: &Proposal,
+    own_last_vote: Option<&Vote>,
+    choice: VoteChoice,
+    signer: &Signer,
+    eligibility_proof: Vec<u8>,
+    now: u64,
+) -> Result<Vote, ConsensusError> {
+    let parent_hash = own_last_vote
+        .map(|vote| vote.vote_hash.clone())
+        .unwrap_or_default();
+    // RFC Section 2.3: Set `received_hash` to hash of immediately previous vote (last vote in list).
+    let received_hash = proposal
+        .votes
+        .last()
+        .map(|vote| vote.vote_hash.clone())
+        .unwrap_or_default();
 
     let mut vote = Vote {
-        vote_id,
-        vote_owner: voter_identity.to_vec(),
+        vote_id: generate_id(),
+        vote_owner: signer.identity().to_vec(),
         proposal_id: proposal.proposal_id,
         timestamp: now,
-        vote: user_vote,
+        vote: choice == VoteChoice::Yes,
         parent_hash,
         received_hash,
         vote_hash: Vec::new(),
         signature: Vec::new(),
+        eligibility_proof,
+        abstain: choice == VoteChoice::Abstain,
     };
 
-    vote.vote_hash = compute_vote_hash(&vote);
+    vote.vote_hash = compute_vote_hash_with(&vote, proposal.hash_algo()?);
     let vote_bytes = vote.encode_to_vec();
     let signature = signer.sign(&vote_bytes)?;
+    if !Signer::is_canonical(&signature) {
+        return Err(ConsensusError::NonCanonicalSignature);
+    }
     vote.signature = signature;
     Ok(vote)
 }
 
 /// Validate a proposal and all its votes against a signature scheme.
 ///
-/// Checks that the proposal hasn't expired as of `now` (seconds since Unix epoch).
+/// Checks that the proposal hasn't expired as of `now` (seconds since Unix epoch),
+/// and that it does not expire more than 30 days after `now` (the scope's
+/// `max_proposal_lifetime` when ingested by the service), failing with
+/// [`InvalidTimeout`](ConsensusError::InvalidTimeout).
+/// Payloads over 64 KiB (the scope's `max_payload_bytes` when ingested by the
+/// service) fail with [`PayloadTooLarge`](ConsensusError::PayloadTooLarge).
+/// Rejects proposals whose `proposal_signature` was not produced by
+/// `proposal_owner` with
+/// [`InvalidProposalSignature`](ConsensusError::InvalidProposalSignature).
 /// Also validates that all votes belong to this proposal, vote signatures are valid,
 /// and the vote chain (parent_hash/received_hash) is correct.
 /// Should be called when receiving a proposal from the network.
+/// Non-canonical signatures are rejected.
 pub fn validate_proposal<Signer: ConsensusSignatureScheme>(
     proposal: &Proposal,
     now: u64,
 ) -> Result<(), ConsensusError> {
-    validate_proposal_timestamp(proposal.expiration_timestamp, now)?;
+    validate_payload_size(proposal.payload.len(), Some(DEFAULT_MAX_PAYLOAD_BYTES))?;
+    verify_proposal_signature::<Signer>(proposal)?;
+    validate_proposal_with::<Signer>(proposal, now, &VoteChecks::default())
+}
 
-    for vote in proposal.votes.iter() {
+/// Like [`validate_proposal`], but keeps going after the first problem and
+/// reports every one found.
+///
+/// Useful for debugging a malformed proposal from a peer: every failing vote
+/// (signature, hash, timestamp, or age) and every chain mismatch is listed by
+/// vote index, alongside proposal-level problems. The report is
+/// [valid](ProposalValidationReport::is_valid) exactly when
+/// [`validate_proposal`] succeeds, which stays the cheaper, fail-fast check
+/// for untrusted input.
+pub fn validate_proposal_report<Signer: ConsensusSignatureScheme>(
+    proposal: &Proposal,
+    now: u64,
+) -> ProposalValidationReport {
+    let checks = VoteChecks::default();
+    let mut report = ProposalValidationReport::default();
+    if let Err(error) =
+        validate_payload_size(proposal.payload.len(), Some(DEFAULT_MAX_PAYLOAD_BYTES))
+    {
+        report.proposal_issues.push(error);
+    }
+    if let Err(error) = verify_proposal_signature::<Signer>(proposal) {
+        report.proposal_issues.push(error);
+    }
+    if let Err(error) = validate_proposal_timestamp(proposal.expiration_timestamp, now) {
+        report.proposal_issues.push(error);
+    }
+    if proposal.expiration_timestamp > now.saturating_add(checks.max_proposal_lifetime.as_secs()) {
+        report.proposal_issues.push(ConsensusError::InvalidTimeout);
+    }
+
+    for (idx, vote) in proposal.votes.iter().enumerate() {
         if vote.proposal_id != proposal.proposal_id {
-            return Err(ConsensusError::VoteProposalIdMismatch);
+            report
+                .vote_issues
+                .push((idx, ConsensusError::VoteProposalIdMismatch));
+        }
+        if let Err(error) = validate_vote::<Signer>(vote, proposal, now, &checks) {
+            report.vote_issues.push((idx, error));
         }
-        validate_vote::<Signer>(vote, proposal.expiration_timestamp, proposal.timestamp, now)?;
+    }
+    report
+        .vote_issues
+        .extend(vote_chain_issues(&proposal.votes));
+    report.vote_issues.sort_by_key(|(idx, _)| *idx);
+    report
+}
+
+/// Estimate the work [`validate_proposal`] would do for `proposal`, without
+/// doing it.
+///
+/// Derived from the embedded vote count only, so it is cheap enough to run
+/// before deciding whether to accept an inbound proposal. The owner's
+/// proposal signature counts as one verification on top of the votes'.
+pub fn estimate_validation_cost(proposal: &Proposal) -> ValidationCost {
+    let votes = proposal.votes.len();
+    ValidationCost {
+        signature_verifications: votes + 1,
+        chain_checks: votes.saturating_sub(1),
+    }
+}
+
+/// [`validate_proposal`] with an explicit per-peer vote policy.
+pub(crate) fn validate_proposal_with<Signer: ConsensusSignatureScheme>(
+    proposal: &Proposal,
+    now: u64,
+    checks: &VoteChecks,
+) -> Result<(), ConsensusError> {
+    validate_proposal_expiration(proposal, now, checks)?;
+    for vote in proposal.votes.iter() {
+        validate_embedded_vote::<Signer>(proposal, vote, now, checks)?;
     }
     validate_vote_chain(&proposal.votes)?;
     Ok(())
 }
 
-/// Validate a single vote against a signature scheme.
+/// Like [`validate_proposal`], but verifies the embedded votes on the rayon
+/// thread pool.
+///
+/// The per-vote hash and signature checks are independent, so they run
+/// concurrently; the chain check, which relates neighbouring votes, runs once
+/// afterwards. The outcome, including which error is returned when several
+/// votes are invalid, is the same as [`validate_proposal`]'s: the first
+/// failing vote in proposal order wins. Worth it for proposals with many
+/// votes; for a handful, the sequential path is cheaper.
+#[cfg(feature = "parallel")]
+pub fn validate_proposal_parallel<Signer: ConsensusSignatureScheme>(
+    proposal: &Proposal,
+    now: u64,
+) -> Result<(), ConsensusError> {
+    use rayon::prelude::*;
+
+    validate_payload_size(proposal.payload.len(), Some(DEFAULT_MAX_PAYLOAD_BYTES))?;
+    verify_proposal_signature::<Signer>(proposal)?;
+    let checks = VoteChecks::default();
+    validate_proposal_expiration(proposal, now, &checks)?;
+    if let Some(error) = proposal
+        .votes
+        .par_iter()
+        .find_map_first(|vote| validate_embedded_vote::<Signer>(proposal, vote, now, &checks).err())
+    {
+        return Err(error);
+    }
+    validate_vote_chain(&proposal.votes)
+}
+
+/// Reject a proposal that has expired, or that expires further past `now`
+/// than `checks.max_proposal_lifetime`.
+fn validate_proposal_expiration(
+    proposal: &Proposal,
+    now: u64,
+    checks: &VoteChecks,
+) -> Result<(), ConsensusError> {
+    validate_proposal_timestamp(proposal.expiration_timestamp, now)?;
+    if proposal.expiration_timestamp > now.saturating_add(checks.max_proposal_lifetime.as_secs()) {
+        return Err(ConsensusError::InvalidTimeout);
+    }
+    Ok(())
+}
+
+/// Validate one of `proposal`'s embedded votes on its own (no chain checks).
+pub(crate) fn validate_embedded_vote<Signer: ConsensusSignatureScheme>(
+    proposal: &Proposal,
+    vote: &Vote,
+    now: u64,
+    checks: &VoteChecks,
+) -> Result<(), ConsensusError> {
+    if vote.proposal_id != proposal.proposal_id {
+        return Err(ConsensusError::VoteProposalIdMismatch);
+    }
+    validate_vote::<Signer>(vote, proposal, now, checks)
+}
+
+/// Validate a single vote on `proposal` against a signature scheme.
 ///
-/// RFC Section 3.4: Validates timestamps (reject future timestamps and votes older than 1 hour).
-/// Also checks that the vote hash is correct, the signature is valid, and the vote hasn't expired.
+/// The vote is checked against the proposal's header only (creation and
+/// expiration timestamps, vote hash algorithm), not its vote list.
+/// RFC Section 3.4: Validates timestamps (reject future timestamps and votes
+/// older than `checks.max_vote_age`, 1 hour by default, with
+/// [`VoteTooOld`](ConsensusError::VoteTooOld)).
+/// Also checks that the vote hash is correct, the signature is valid (and canonical
+/// under `checks.strict_signatures`), the voter's eligibility proof is accepted by
+/// `checks.eligibility`, and the timestamp passes `checks.timestamps` (which runs
+/// before the age check).
 /// This prevents replay attacks and ensures vote integrity.
 pub(crate) fn validate_vote<Signer: ConsensusSignatureScheme>(
     vote: &Vote,
-    expiration_timestamp: u64,
-    creation_time: u64,
+    proposal: &Proposal,
     now: u64,
+    checks: &VoteChecks,
 ) -> Result<(), ConsensusError> {
     if vote.vote_owner.is_empty() {
         return Err(ConsensusError::EmptyVoteOwner);
@@ -142,7 +581,7 @@ pub(crate) fn validate_vote<Signer: ConsensusSignatureScheme>(
         return Err(ConsensusError::EmptySignature);
     }
 
-    let expected_hash = compute_vote_hash(vote);
+    let expected_hash = compute_vote_hash_with(vote, proposal.hash_algo()?);
     if vote.vote_hash != expected_hash {
         return Err(ConsensusError::InvalidVoteHash);
     }
@@ -157,43 +596,91 @@ pub(crate) fn validate_vote<Signer: ConsensusSignatureScheme>(
         return Err(ConsensusError::InvalidVoteSignature);
     }
 
-    // RFC Section 3.4:  Check the `timestamp` against the replay attack.
-    // In particular, the `timestamp` cannot be the old in the determined threshold.
-    if vote.timestamp < creation_time {
-        return Err(ConsensusError::TimestampOlderThanCreationTime);
+    // A malleated (e.g. high-S) signature verifies but changes the vote's bytes,
+    // defeating signature-based dedup and certificate stability.
+    if checks.strict_signatures && !Signer::is_canonical(&vote.signature) {
+        return Err(ConsensusError::NonCanonicalSignature);
     }
 
-    if vote.timestamp > expiration_timestamp || now > expiration_timestamp {
-        return Err(ConsensusError::VoteExpired);
+    if !checks.eligibility.verify(vote) {
+        return Err(ConsensusError::InvalidEligibilityProof);
     }
 
+    checks.timestamps.validate(
+        vote.timestamp,
+        proposal.timestamp,
+        proposal.expiration_timestamp,
+        now,
+    )?;
+
+    let age_secs = now.saturating_sub(vote.timestamp);
+    let max_age_secs = checks.max_vote_age.as_secs();
+    if age_secs > max_age_secs {
+        return Err(ConsensusError::VoteTooOld {
+            age_secs,
+            max_age_secs,
+        });
+    }
     Ok(())
 }
 
 /// Validate that votes form a correct hashgraph chain.
 /// RFC Section 2.2 and 2.3.
+///
+/// Each `received_hash` must name an earlier vote in the list that is not
+/// newer than the vote itself. On a single chain that is the previous vote;
+/// votes cast in parallel on the same prefix, and branches joined by
+/// [`merge_proposal`](crate::service::ConsensusService::merge_proposal), name
+/// an earlier one.
 pub(crate) fn validate_vote_chain(votes: &[Vote]) -> Result<(), ConsensusError> {
-    if votes.len() <= 1 {
-        return Ok(());
+    match vote_chain_issues(votes).into_iter().next() {
+        Some((_, error)) => Err(error),
+        None => Ok(()),
+    }
+}
+
+/// Validate that `votes` extend the hashgraph chain of the `held` votes.
+///
+/// Only links leaving `votes` are checked, with the rules of
+/// [`validate_vote_chain`]: a `received_hash` or `parent_hash` may name a vote
+/// in either list. `held` itself is trusted as stored.
+pub(crate) fn validate_vote_chain_extension(
+    held: &[Vote],
+    votes: &[Vote],
+) -> Result<(), ConsensusError> {
+    let combined: Vec<Vote> = held.iter().chain(votes).cloned().collect();
+    match vote_chain_issues_from(&combined, held.len())
+        .into_iter()
+        .next()
+    {
+        Some((_, error)) => Err(error),
+        None => Ok(()),
     }
+}
 
+/// Every chain mismatch in `votes`, keyed by the index of the offending vote.
+fn vote_chain_issues(votes: &[Vote]) -> Vec<(usize, ConsensusError)> {
+    vote_chain_issues_from(votes, 0)
+}
+
+/// Chain mismatches of the votes at index `from` onwards; earlier votes are
+/// only used as link targets.
+fn vote_chain_issues_from(votes: &[Vote], from: usize) -> Vec<(usize, ConsensusError)> {
+    let mut issues = Vec::new();
     let mut hash_index: HashMap<&[u8], (&[u8], u64, usize)> = HashMap::new();
     for (idx, vote) in votes.iter().enumerate() {
         hash_index.insert(&vote.vote_hash, (&vote.vote_owner, vote.timestamp, idx));
     }
 
-    for (idx, vote) in votes.iter().enumerate() {
-        // RFC Section 2.3: If there are multiple votes in a proposal,
-        // check that the hash of a vote is equal to the `received_hash` of the next one.
-        if idx > 0 {
-            let prev_vote = &votes[idx - 1];
-            if !vote.received_hash.is_empty() {
-                if vote.received_hash != prev_vote.vote_hash {
-                    return Err(ConsensusError::ReceivedHashMismatch);
-                }
-                if prev_vote.timestamp > vote.timestamp {
-                    return Err(ConsensusError::ReceivedHashMismatch);
-                }
+    for (idx, vote) in votes.iter().enumerate().skip(from) {
+        // RFC Section 2.3: If there are multiple votes in a proposal, check
+        // that the `received_hash` of a vote names a vote received before it.
+        // Parallel votes and joined branches share a predecessor, so it may be
+        // any earlier vote rather than only the previous one.
+        if !vote.received_hash.is_empty() {
+            match hash_index.get(&vote.received_hash.as_slice()) {
+                Some((_, ts, received_idx)) if *ts <= vote.timestamp && *received_idx < idx => {}
+                Some(_) | None => issues.push((idx, ConsensusError::ReceivedHashMismatch)),
             }
         }
 
@@ -205,13 +692,12 @@ pub(crate) fn validate_vote_chain(votes: &[Vote]) -> Result<(), ConsensusError>
                     if *owner == vote.vote_owner.as_slice()
                         && *ts <= vote.timestamp
                         && *parent_idx < idx => {}
-                Some(_) => return Err(ConsensusError::ParentHashMismatch),
-                None => return Err(ConsensusError::ParentHashMismatch),
+                Some(_) | None => issues.push((idx, ConsensusError::ParentHashMismatch)),
             }
         }
     }
 
-    Ok(())
+    issues
 }
 
 /// Calculate the consensus result from collected votes.
@@ -220,96 +706,451 @@ pub(crate) fn validate_vote_chain(votes: &[Vote]) -> Result<(), ConsensusError>
 /// Returns `true` if YES wins, `false` if NO wins. If votes are tied, uses
 /// `liveness_criteria_yes` as the tie-breaker (RFC Section 4: Equality of votes).
 ///
+/// `votes` is keyed by vote owner, so turnout and the `>= 2n/3` quorum count
+/// distinct peers (as the RFC specifies), never raw vote events.
+///
 /// When `is_timeout` is `true`, silent peers are counted toward quorum so that the
 /// liveness criteria (RFC Section 4, Silent Node Management) can actually take effect.
 /// Without this, a session with offline peers would never reach the quorum gate and
 /// the silent-peer weighting logic would be unreachable.
+///
+/// `consensus_threshold` is a fraction (an `f64`) or any [`ThresholdMode`]. Under
+/// [`ThresholdMode::AbsoluteYes`]`(k)` YES wins with `k` YES votes and NO once
+/// fewer than `k` peers could still vote YES; at timeout silent peers count
+/// toward the liveness side and the result is always decided.
 pub fn calculate_consensus_result(
     votes: &HashMap<Vec<u8>, Vote>,
     expected_voters: u32,
-    consensus_threshold: f64,
+    consensus_threshold: impl Into<ThresholdMode>,
     liveness_criteria_yes: bool,
     is_timeout: bool,
 ) -> Option<bool> {
-    let total_votes = votes.len() as u32;
-    let yes_votes = votes.values().filter(|v| v.vote).count() as u32;
-    let no_votes = total_votes.saturating_sub(yes_votes);
-    let silent_votes = expected_voters.saturating_sub(total_votes);
+    calculate_weighted_consensus_result(
+        votes,
+        expected_voters,
+        &VoterWeights::default(),
+        consensus_threshold,
+        liveness_criteria_yes,
+        is_timeout,
+    )
+}
+
+/// Like [`calculate_consensus_result`], but each vote carries its owner's
+/// weight from `weights` and the threshold applies to the total possible
+/// weight ([`VoterWeights::total_weight`]) instead of `expected_voters`.
+///
+/// With every weight at the default of 1 this is exactly
+/// [`calculate_consensus_result`]. The `n <= 2` unanimity rules still count
+/// voters, not weight.
+pub fn calculate_weighted_consensus_result(
+    votes: &HashMap<Vec<u8>, Vote>,
+    expected_voters: u32,
+    weights: &VoterWeights,
+    consensus_threshold: impl Into<ThresholdMode>,
+    liveness_criteria_yes: bool,
+    is_timeout: bool,
+) -> Option<bool> {
+    consensus_result_for_counts(
+        votes,
+        VoteCounts::recount(votes),
+        expected_voters,
+        weights,
+        consensus_threshold,
+        liveness_criteria_yes,
+        is_timeout,
+    )
+}
+
+/// [`calculate_weighted_consensus_result`] with the per-choice `counts` of
+/// `votes` already known, so unweighted sessions never walk `votes`.
+pub(crate) fn consensus_result_for_counts(
+    votes: &HashMap<Vec<u8>, Vote>,
+    counts: VoteCounts,
+    expected_voters: u32,
+    weights: &VoterWeights,
+    consensus_threshold: impl Into<ThresholdMode>,
+    liveness_criteria_yes: bool,
+    is_timeout: bool,
+) -> Option<bool> {
+    let distinct_voters = counts.distinct_voters();
+    let tally = if weights.is_empty() {
+        WeightTally::from_counts(counts, expected_voters)
+    } else {
+        WeightTally::new(votes, expected_voters, weights)
+    };
+
+    let consensus_threshold = match consensus_threshold.into() {
+        ThresholdMode::AbsoluteYes(required_yes) => {
+            let required_yes = u64::from(required_yes);
+            let yes_weight = tally.yes
+                + if is_timeout && liveness_criteria_yes {
+                    tally.silent
+                } else {
+                    0
+                };
+            let reachable_yes = tally.yes + tally.silent;
+            return if yes_weight >= required_yes {
+                Some(true)
+            } else if is_timeout || reachable_yes < required_yes {
+                Some(false)
+            } else {
+                None
+            };
+        }
+        ThresholdMode::Fraction(fraction) => fraction,
+    };
+
+    // Single decider: the lone vote is authoritative either way, and a silent
+    // decider never decides (not even at timeout).
+    if expected_voters == 1 {
+        return match (counts.yes, counts.no) {
+            (0, 0) => None,
+            (yes, _) => Some(yes > 0),
+        };
+    }
 
     if expected_voters <= 2 {
-        if total_votes < expected_voters {
+        if distinct_voters < expected_voters {
             return None;
         }
-        return Some(yes_votes == expected_voters);
+        return Some(counts.yes == expected_voters);
     }
 
-    let required_votes = calculate_required_votes(expected_voters, consensus_threshold);
+    let required_weight = threshold_based_weight(tally.total, consensus_threshold);
     // At timeout, silent peers are accounted for (as YES or NO depending on liveness),
     // so the effective total includes all expected voters.
-    let effective_total = if is_timeout {
-        expected_voters
-    } else {
-        total_votes
-    };
-    if effective_total < required_votes {
+    let effective_total = if is_timeout { tally.total } else { tally.cast };
+    if effective_total < required_weight {
         return None;
     }
 
-    let required_choice_votes =
-        calculate_threshold_based_value(expected_voters, consensus_threshold);
-    let yes_weight = yes_votes
-        + if liveness_criteria_yes {
-            silent_votes
-        } else {
-            0
-        };
-    let no_weight = no_votes
-        + if liveness_criteria_yes {
-            0
-        } else {
-            silent_votes
-        };
+    let (yes_weight, no_weight) = tally.weights_with_silent(liveness_criteria_yes);
 
-    if yes_weight >= required_choice_votes && yes_weight > no_weight {
+    if yes_weight >= required_weight && yes_weight > no_weight {
         return Some(true);
     }
 
-    if no_weight >= required_choice_votes && no_weight > yes_weight {
+    if no_weight >= required_weight && no_weight > yes_weight {
         return Some(false);
     }
 
-    if total_votes == expected_voters && yes_weight == no_weight {
+    if tally.cast == tally.total && yes_weight == no_weight {
         return Some(liveness_criteria_yes);
     }
 
     None
 }
 
+/// Whether `votes` (with per-choice `counts`) are an exact YES/NO tie with
+/// every expected voter's weight cast: the case
+/// [`consensus_result_for_counts`] hands to the liveness criteria.
+///
+/// Always `false` for `n <= 2` and absolute thresholds, which never tie.
+pub(crate) fn is_full_turnout_tie(
+    votes: &HashMap<Vec<u8>, Vote>,
+    counts: VoteCounts,
+    expected_voters: u32,
+    weights: &VoterWeights,
+    consensus_threshold: ThresholdMode,
+) -> bool {
+    if expected_voters <= 2 || matches!(consensus_threshold, ThresholdMode::AbsoluteYes(_)) {
+        return false;
+    }
+    let tally = if weights.is_empty() {
+        WeightTally::from_counts(counts, expected_voters)
+    } else {
+        WeightTally::new(votes, expected_voters, weights)
+    };
+    tally.cast == tally.total && tally.yes == tally.no
+}
+
+/// Number of recorded votes per choice, one per voter.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct VoteCounts {
+    pub(crate) yes: u32,
+    pub(crate) no: u32,
+    pub(crate) abstain: u32,
+}
+
+impl VoteCounts {
+    /// Count `votes` from scratch.
+    pub(crate) fn recount(votes: &HashMap<Vec<u8>, Vote>) -> Self {
+        let mut counts = Self::default();
+        for vote in votes.values() {
+            counts.add(vote.choice());
+        }
+        counts
+    }
+
+    pub(crate) fn add(&mut self, choice: VoteChoice) {
+        *self.count_mut(choice) += 1;
+    }
+
+    pub(crate) fn remove(&mut self, choice: VoteChoice) {
+        let count = self.count_mut(choice);
+        *count = count.saturating_sub(1);
+    }
+
+    /// Number of voters with a recorded vote.
+    pub(crate) fn distinct_voters(&self) -> u32 {
+        self.yes + self.no + self.abstain
+    }
+
+    fn count_mut(&mut self, choice: VoteChoice) -> &mut u32 {
+        match choice {
+            VoteChoice::Yes => &mut self.yes,
+            VoteChoice::No => &mut self.no,
+            VoteChoice::Abstain => &mut self.abstain,
+        }
+    }
+}
+
+/// Summed vote weights for one session.
+struct WeightTally {
+    /// Weight of YES votes.
+    yes: u64,
+    /// Weight of NO votes.
+    no: u64,
+    /// Weight of every recorded vote, abstentions included.
+    cast: u64,
+    /// Weight of expected voters that have not voted.
+    silent: u64,
+    /// Total possible weight.
+    total: u64,
+}
+
+impl WeightTally {
+    /// The tally when every voter weighs [`DEFAULT_VOTER_WEIGHT`](crate::weights::DEFAULT_VOTER_WEIGHT).
+    fn from_counts(counts: VoteCounts, expected_voters: u32) -> Self {
+        let cast = u64::from(counts.distinct_voters());
+        let total = u64::from(expected_voters);
+        Self {
+            yes: u64::from(counts.yes),
+            no: u64::from(counts.no),
+            cast,
+            silent: total.saturating_sub(cast),
+            total,
+        }
+    }
+
+    fn new(votes: &HashMap<Vec<u8>, Vote>, expected_voters: u32, weights: &VoterWeights) -> Self {
+        let (mut yes, mut no, mut cast) = (0u64, 0u64, 0u64);
+        for vote in votes.values() {
+            let weight = weights.weight_of(&vote.vote_owner);
+            match vote.choice() {
+                VoteChoice::Yes => yes = yes.saturating_add(weight),
+                VoteChoice::No => no = no.saturating_add(weight),
+                VoteChoice::Abstain => {}
+            }
+            cast = cast.saturating_add(weight);
+        }
+        let total = weights.total_weight(expected_voters);
+        Self {
+            yes,
+            no,
+            cast,
+            silent: total.saturating_sub(cast),
+            total,
+        }
+    }
+
+    /// YES and NO weight with silent weight counted toward the liveness side.
+    fn weights_with_silent(&self, liveness_criteria_yes: bool) -> (u64, u64) {
+        if liveness_criteria_yes {
+            (self.yes + self.silent, self.no)
+        } else {
+            (self.yes, self.no + self.silent)
+        }
+    }
+}
+
+/// Break down [`calculate_consensus_result`] (without timeout weighting) for
+/// the given votes. `state` is passed through to the explanation.
+///
+/// The counts and weights are per voter; `weights` only affects the verdict.
+pub(crate) fn explain_consensus_result(
+    votes: &HashMap<Vec<u8>, Vote>,
+    expected_voters: u32,
+    weights: &VoterWeights,
+    consensus_threshold: ThresholdMode,
+    liveness_criteria_yes: bool,
+    state: ConsensusState,
+) -> ConsensusExplanation {
+    let distinct_voters = votes.len() as u32;
+    let yes_votes = count_choice(votes, VoteChoice::Yes);
+    let no_votes = count_choice(votes, VoteChoice::No);
+    let abstain_votes = count_choice(votes, VoteChoice::Abstain);
+    let silent_votes = expected_voters.saturating_sub(distinct_voters);
+    let required_votes = calculate_required_votes(expected_voters, consensus_threshold);
+    let required_choice_votes = match consensus_threshold {
+        ThresholdMode::Fraction(fraction) => {
+            calculate_threshold_based_value(expected_voters, fraction)
+        }
+        ThresholdMode::AbsoluteYes(required_yes) => required_yes,
+    };
+    let (yes_weight, no_weight) = if liveness_criteria_yes {
+        (yes_votes + silent_votes, no_votes)
+    } else {
+        (yes_votes, no_votes + silent_votes)
+    };
+
+    let rule = if let ThresholdMode::AbsoluteYes(_) = consensus_threshold {
+        QuorumRule::AbsoluteYes
+    } else if expected_voters <= 2 {
+        QuorumRule::Unanimity
+    } else if distinct_voters == expected_voters && yes_weight == no_weight {
+        QuorumRule::LivenessTieBreak
+    } else {
+        QuorumRule::WeightedMajority
+    };
+    let verdict = match calculate_weighted_consensus_result(
+        votes,
+        expected_voters,
+        weights,
+        consensus_threshold,
+        liveness_criteria_yes,
+        false,
+    ) {
+        Some(result) => QuorumVerdict::Decided(result),
+        None if distinct_voters < required_votes => QuorumVerdict::NeedsMoreVotes {
+            missing: required_votes - distinct_voters,
+        },
+        None => QuorumVerdict::NoQualifiedMajority,
+    };
+
+    ConsensusExplanation {
+        state,
+        expected_voters,
+        required_votes,
+        required_choice_votes,
+        yes_votes,
+        no_votes,
+        abstain_votes,
+        silent_votes,
+        liveness_criteria_yes,
+        yes_weight,
+        no_weight,
+        rule,
+        verdict,
+    }
+}
+
+/// Number of `votes` with the given choice.
+fn count_choice(votes: &HashMap<Vec<u8>, Vote>, choice: VoteChoice) -> u32 {
+    votes
+        .values()
+        .filter(|vote| vote.choice() == choice)
+        .count() as u32
+}
+
+/// Check whether YES and NO weights are equal once silent peers are counted
+/// toward the side selected by `liveness_criteria_yes` (the timeout weighting).
+///
+/// Always `false` for `n <= 2`, where unanimity rather than weighting applies.
+pub(crate) fn is_weighted_tie_at_timeout(
+    votes: &HashMap<Vec<u8>, Vote>,
+    expected_voters: u32,
+    weights: &VoterWeights,
+    liveness_criteria_yes: bool,
+) -> bool {
+    if expected_voters <= 2 {
+        return false;
+    }
+    let (yes_weight, no_weight) = WeightTally::new(votes, expected_voters, weights)
+        .weights_with_silent(liveness_criteria_yes);
+    yes_weight == no_weight
+}
+
 /// Calculate the minimum number of votes needed to potentially reach consensus.
 ///
 /// For `n <= 2`, all voters must participate. For `n > 2`, applies the threshold
-/// formula `ceil(n * threshold)`.
-fn calculate_required_votes(expected_voters: u32, consensus_threshold: f64) -> u32 {
-    // RFC Section 4: For n ≤ 2, require all votes. For n > 2, use threshold (default 2n/3)
-    if expected_voters <= 2 {
-        expected_voters
-    } else {
-        calculate_threshold_based_value(expected_voters, consensus_threshold)
+/// formula `ceil(n * threshold)`. For an absolute count `k`, it is the fewer
+/// of `k` (all YES) and `n - k + 1` (enough NO to rule YES out).
+fn calculate_required_votes(expected_voters: u32, consensus_threshold: ThresholdMode) -> u32 {
+    match consensus_threshold {
+        ThresholdMode::AbsoluteYes(required_yes) => {
+            required_yes.min(expected_voters.saturating_sub(required_yes) + 1)
+        }
+        // RFC Section 4: For n ≤ 2, require all votes. For n > 2, use threshold (default 2n/3)
+        ThresholdMode::Fraction(_) if expected_voters <= 2 => expected_voters,
+        ThresholdMode::Fraction(fraction) => {
+            calculate_threshold_based_value(expected_voters, fraction)
+        }
     }
 }
 
 /// Calculate the dynamic round cap for P2P networks (`ceil(2n/3)` by default).
-pub(crate) fn calculate_max_rounds(expected_voters: u32, consensus_threshold: f64) -> u32 {
-    calculate_threshold_based_value(expected_voters, consensus_threshold)
+///
+/// An absolute count may need every expected vote to decide, so it allows `n`.
+pub(crate) fn calculate_max_rounds(
+    expected_voters: u32,
+    consensus_threshold: ThresholdMode,
+) -> u32 {
+    match consensus_threshold {
+        ThresholdMode::Fraction(fraction) => {
+            calculate_threshold_based_value(expected_voters, fraction)
+        }
+        ThresholdMode::AbsoluteYes(_) => expected_voters,
+    }
 }
 
 /// Calculate a value based on threshold (shared logic for required votes and max rounds).
+///
+/// The threshold (clamped to `[0.0, 1.0]`) is read as the simplest fraction
+/// `p/q` within `2^-50` of it, and the value is `ceil(n * p / q)` in exact
+/// integer arithmetic. The tolerance only absorbs `f64` rounding: `2.0 / 3.0`
+/// is exactly 2/3, and decimals with up to seven places are exact as written
+/// (`0.9` is 9/10, `0.6667` is 6667/10000, `0.6666666` is 3333333/5000000),
+/// so there is no float rounding at any `n`. The result never decreases as
+/// the threshold grows.
 fn calculate_threshold_based_value(expected_voters: u32, consensus_threshold: f64) -> u32 {
-    if (consensus_threshold - (2.0 / 3.0)).abs() < f64::EPSILON {
-        (2 * expected_voters).div_ceil(3)
+    threshold_based_weight(u64::from(expected_voters), consensus_threshold) as u32
+}
+
+/// [`calculate_threshold_based_value`] for a total weight rather than a voter count.
+fn threshold_based_weight(total_weight: u64, consensus_threshold: f64) -> u64 {
+    let (numerator, denominator) = threshold_as_fraction(consensus_threshold);
+    (u128::from(total_weight) * numerator).div_ceil(denominator) as u64
+}
+
+/// Bits of fixed-point precision used to read a threshold exactly.
+const THRESHOLD_FRACTION_BITS: u32 = 103;
+/// Tolerance (as `2^-THRESHOLD_TOLERANCE_BITS`) when looking for a simple fraction.
+const THRESHOLD_TOLERANCE_BITS: u32 = 50;
+
+/// Simplest fraction within `2^-50` of `threshold` (clamped to `[0.0, 1.0]`).
+fn threshold_as_fraction(threshold: f64) -> (u128, u128) {
+    let threshold = if threshold > 0.0 {
+        threshold.min(1.0)
     } else {
-        ((expected_voters as f64) * consensus_threshold).ceil() as u32
+        0.0
+    };
+    // Exact: scaling by a power of two only changes the exponent, and every
+    // threshold at or above the tolerance becomes an integer at this precision.
+    let scaled = threshold * 2f64.powi(THRESHOLD_FRACTION_BITS as i32);
+    let tolerance = 1u128 << (THRESHOLD_FRACTION_BITS - THRESHOLD_TOLERANCE_BITS);
+    if scaled <= tolerance as f64 {
+        return (0, 1);
     }
+    let scaled = scaled as u128;
+    let one = 1u128 << THRESHOLD_FRACTION_BITS;
+    simplest_fraction_between(scaled - tolerance, one, scaled + tolerance, one)
+}
+
+/// Simplest fraction (smallest denominator) in `[lo_n/lo_d, hi_n/hi_d]`, for
+/// `0 < lo < hi`, via continued fractions.
+fn simplest_fraction_between(lo_n: u128, lo_d: u128, hi_n: u128, hi_d: u128) -> (u128, u128) {
+    let whole = lo_n / lo_d;
+    if lo_n.is_multiple_of(lo_d) {
+        return (whole, 1);
+    }
+    if (whole + 1) * hi_d <= hi_n {
+        return (whole + 1, 1);
+    }
+    // Both bounds lie in (whole, whole + 1): recurse on the reciprocals of
+    // their fractional parts, which swaps their order.
+    let (p, q) = simplest_fraction_between(hi_d, hi_n - whole * hi_d, lo_d, lo_n - whole * lo_d);
+    (whole * p + q, p)
 }
 
 /// Check if a proposal has expired.
@@ -343,6 +1184,15 @@ pub(crate) fn validate_timeout(timeout: Duration) -> Result<(), ConsensusError>
     Ok(())
 }
 
+/// Reject a proposal payload of `size` bytes larger than `max` (`None` means
+/// no limit).
+pub(crate) fn validate_payload_size(size: usize, max: Option<usize>) -> Result<(), ConsensusError> {
+    match max {
+        Some(max) if size > max => Err(ConsensusError::PayloadTooLarge { size, max }),
+        _ => Ok(()),
+    }
+}
+
 /// Validate that `expected_voters_count` is at least 1.
 pub(crate) fn validate_expected_voters_count(
     expected_voters_count: u32,
@@ -360,9 +1210,9 @@ pub(crate) fn validate_expected_voters_count(
 pub fn has_sufficient_votes(
     total_votes: u32,
     expected_voters: u32,
-    consensus_threshold: f64,
+    consensus_threshold: impl Into<ThresholdMode>,
 ) -> bool {
-    let required_votes = calculate_required_votes(expected_voters, consensus_threshold);
+    let required_votes = calculate_required_votes(expected_voters, consensus_threshold.into());
     total_votes >= required_votes
 }
 
@@ -370,7 +1220,7 @@ pub fn has_sufficient_votes(
 mod tests {
     use uuid::Uuid;
 
-    use super::fold_u128_to_u32;
+    use super::mix_u128_to_u64;
 
     #[test]
     fn id_generation_should_not_collapse_distinct_128bit_values() {
@@ -384,13 +1234,13 @@ mod tests {
         let uuid_a = Uuid::from_u128(value_a);
         let uuid_b = Uuid::from_u128(value_b);
 
-        let id_a = fold_u128_to_u32(uuid_a.as_u128());
-        let id_b = fold_u128_to_u32(uuid_b.as_u128());
+        let id_a = mix_u128_to_u64(uuid_a.as_u128());
+        let id_b = mix_u128_to_u64(uuid_b.as_u128());
 
         // Desired behavior: distinct 128-bit values should remain distinct after conversion.
         assert_ne!(
             id_a, id_b,
-            "distinct 128-bit values should not collapse to the same 32-bit id"
+            "distinct 128-bit values should not collapse to the same 64-bit id"
         );
     }
 }
@@ -0,0 +1,109 @@
+//! Coalescing buffer for per-vote events.
+//!
+//! When vote batching is enabled, the service collects the voters that enter
+//! a proposal's tally in [`VoteBatches`] instead of publishing one
+//! [`VoteAdded`](crate::types::ConsensusEvent::VoteAdded) each, and publishes
+//! a single [`VotesBatch`](crate::types::ConsensusEvent::VotesBatch) per
+//! proposal once its window has elapsed.
+
+use std::collections::HashMap;
+
+use parking_lot::Mutex;
+
+use crate::scope::ConsensusScope;
+
+/// Voters added to one proposal's tally since its batch opened.
+struct PendingBatch {
+    /// When the first voter of the batch was added (seconds since Unix epoch).
+    opened_at: u64,
+    /// Voter identities in the order they were added, each listed once.
+    new_voters: Vec<Vec<u8>>,
+}
+
+/// Pending vote batches, keyed by scope and proposal.
+pub(crate) struct VoteBatches<Scope> {
+    pending: Mutex<HashMap<(Scope, u64), PendingBatch>>,
+}
+
+impl<Scope: ConsensusScope> VoteBatches<Scope> {
+    pub(crate) fn new() -> Self {
+        Self {
+            pending: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Add `voters` to the batch for `proposal_id`, opening one at `now` if
+    /// none is pending. Returns the batch's voters, closing it, once
+    /// `window_secs` have passed since it opened.
+    pub(crate) fn add(
+        &self,
+        scope: &Scope,
+        proposal_id: u64,
+        voters: impl IntoIterator<Item = Vec<u8>>,
+        now: u64,
+        window_secs: u64,
+    ) -> Option<Vec<Vec<u8>>> {
+        let mut pending = self.pending.lock();
+        let key = (scope.clone(), proposal_id);
+        let batch = pending.entry(key.clone()).or_insert_with(|| PendingBatch {
+            opened_at: now,
+            new_voters: Vec::new(),
+        });
+        for voter in voters {
+            if !batch.new_voters.contains(&voter) {
+                batch.new_voters.push(voter);
+            }
+        }
+        if now < batch.opened_at.saturating_add(window_secs) {
+            return None;
+        }
+        pending.remove(&key).map(|batch| batch.new_voters)
+    }
+
+    /// Close and return the batch for `proposal_id`, whatever its age.
+    pub(crate) fn take(&self, scope: &Scope, proposal_id: u64) -> Option<Vec<Vec<u8>>> {
+        self.pending
+            .lock()
+            .remove(&(scope.clone(), proposal_id))
+            .map(|batch| batch.new_voters)
+    }
+
+    /// Close and return every batch opened `window_secs` or more before `now`.
+    pub(crate) fn take_due(&self, now: u64, window_secs: u64) -> Vec<(Scope, u64, Vec<Vec<u8>>)> {
+        let mut due = Vec::new();
+        self.pending.lock().retain(|(scope, proposal_id), batch| {
+            if now < batch.opened_at.saturating_add(window_secs) {
+                return true;
+            }
+            due.push((
+                scope.clone(),
+                *proposal_id,
+                std::mem::take(&mut batch.new_voters),
+            ));
+            false
+        });
+        due
+    }
+
+    /// Move the batches pending in `old` under `new`.
+    pub(crate) fn rename_scope(&self, old: &Scope, new: &Scope) {
+        let mut pending = self.pending.lock();
+        let keys: Vec<_> = pending
+            .keys()
+            .filter(|(scope, _)| scope == old)
+            .cloned()
+            .collect();
+        for key in keys {
+            if let Some(batch) = pending.remove(&key) {
+                pending.insert((new.clone(), key.1), batch);
+            }
+        }
+    }
+
+    /// Drop every batch pending in `scope` without publishing it.
+    pub(crate) fn clear_scope(&self, scope: &Scope) {
+        self.pending
+            .lock()
+            .retain(|(pending_scope, _), _| pending_scope != scope);
+    }
+}
@@ -0,0 +1,104 @@
+//! Stake weights for token-governed voting.
+//!
+//! By default every voter counts once. A [`VoterWeights`] map, supplied in a
+//! proposal's [`ConsensusConfig`](crate::session::ConsensusConfig) or a scope's
+//! [`ScopeConfig`](crate::scope_config::ScopeConfig), gives listed voters a
+//! different weight; consensus then compares summed weights against the total
+//! possible weight instead of counting votes.
+//!
+//! Weights are local configuration, never read from the vote itself: a voter
+//! cannot claim more stake than the application grants them.
+
+use std::collections::HashMap;
+
+/// Weight every voter not listed in a [`VoterWeights`] map carries.
+pub const DEFAULT_VOTER_WEIGHT: u64 = 1;
+
+/// Per-voter weights keyed by voter identity (the vote owner bytes).
+///
+/// Voters without an entry weigh [`DEFAULT_VOTER_WEIGHT`], so an empty map is
+/// plain one-voter-one-vote consensus.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VoterWeights {
+    #[cfg_attr(feature = "serde", serde(with = "weights_by_owner"))]
+    weights: HashMap<Vec<u8>, u64>,
+}
+
+impl VoterWeights {
+    /// An empty map: every voter weighs [`DEFAULT_VOTER_WEIGHT`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set `voter`'s weight and return the updated map.
+    pub fn with_weight(mut self, voter: impl Into<Vec<u8>>, weight: u64) -> Self {
+        self.insert(voter, weight);
+        self
+    }
+
+    /// Set `voter`'s weight, returning the previous one if it was listed.
+    pub fn insert(&mut self, voter: impl Into<Vec<u8>>, weight: u64) -> Option<u64> {
+        self.weights.insert(voter.into(), weight)
+    }
+
+    /// The weight `voter`'s vote carries.
+    pub fn weight_of(&self, voter: &[u8]) -> u64 {
+        self.weights
+            .get(voter)
+            .copied()
+            .unwrap_or(DEFAULT_VOTER_WEIGHT)
+    }
+
+    /// Total possible weight for a proposal with `expected_voters` voters:
+    /// every listed weight, plus the default weight for each expected voter
+    /// beyond the listed ones.
+    pub fn total_weight(&self, expected_voters: u32) -> u64 {
+        let listed: u64 = self.weights.values().sum();
+        let unlisted = (expected_voters as usize).saturating_sub(self.weights.len()) as u64;
+        listed.saturating_add(unlisted * DEFAULT_VOTER_WEIGHT)
+    }
+
+    /// Whether no voter is listed (every vote counts once).
+    pub fn is_empty(&self) -> bool {
+        self.weights.is_empty()
+    }
+
+    /// Number of listed voters.
+    pub fn len(&self) -> usize {
+        self.weights.len()
+    }
+}
+
+impl<Voter: Into<Vec<u8>>> FromIterator<(Voter, u64)> for VoterWeights {
+    fn from_iter<I: IntoIterator<Item = (Voter, u64)>>(iter: I) -> Self {
+        Self {
+            weights: iter
+                .into_iter()
+                .map(|(voter, weight)| (voter.into(), weight))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+mod weights_by_owner {
+    use std::collections::HashMap;
+
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        weights: &HashMap<Vec<u8>, u64>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(weights.iter())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<HashMap<Vec<u8>, u64>, D::Error> {
+        Ok(Vec::<(Vec<u8>, u64)>::deserialize(deserializer)?
+            .into_iter()
+            .collect())
+    }
+}
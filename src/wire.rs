@@ -0,0 +1,148 @@
+//! Wire envelope for gossiping proposals and votes between peers.
+//!
+//! Every message on the wire is a [`ConsensusMessage`] carrying the protocol
+//! version, the encoded scope, and either a [`Proposal`] or a [`Vote`]. Peers
+//! hand received bytes to
+//! [`ConsensusService::process_incoming_message`](crate::service::ConsensusService::process_incoming_message),
+//! which decodes the envelope and dispatches to the right handler.
+//!
+//! [`encode_snapshot`] and [`decode_snapshot`] move a scope's archived
+//! proposals between processes, guarded by a checksum.
+
+use prost::Message;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    error::ConsensusError,
+    protos::consensus::v1::{
+        ConsensusMessage, Proposal, ScopeSnapshotExport, Vote, consensus_message::Payload,
+    },
+    scope::ConsensusScope,
+    storage::ScopeSnapshot,
+};
+
+/// Length of the SHA-256 checksum that prefixes an exported snapshot.
+const SNAPSHOT_CHECKSUM_LEN: usize = 32;
+
+/// Wire protocol version written into every [`ConsensusMessage`].
+///
+/// Version 2 requires proposals to carry their owner's `proposal_signature`.
+/// Version 3 widens `proposal_id` and `vote_id` to 64 bits; vote hashes and
+/// proposal signatures cover the wider IDs, so version 2 peers cannot verify them.
+pub const PROTOCOL_VERSION: u32 = 3;
+
+/// A scope key that can be written into and read back from the wire envelope.
+///
+/// Implemented for [`String`] (UTF-8, so also [`ScopeID`](crate::scope::ScopeID))
+/// and `Vec<u8>`. Implement it for custom scope types to use
+/// [`process_incoming_message`](crate::service::ConsensusService::process_incoming_message).
+pub trait WireScope: ConsensusScope {
+    /// Encode the scope as bytes for the envelope.
+    fn to_wire(&self) -> Vec<u8>;
+
+    /// Decode a scope from envelope bytes.
+    fn from_wire(bytes: &[u8]) -> Result<Self, ConsensusError>;
+}
+
+impl WireScope for String {
+    fn to_wire(&self) -> Vec<u8> {
+        self.as_bytes().to_vec()
+    }
+
+    fn from_wire(bytes: &[u8]) -> Result<Self, ConsensusError> {
+        String::from_utf8(bytes.to_vec()).map_err(|_| ConsensusError::MalformedMessage)
+    }
+}
+
+impl WireScope for Vec<u8> {
+    fn to_wire(&self) -> Vec<u8> {
+        self.clone()
+    }
+
+    fn from_wire(bytes: &[u8]) -> Result<Self, ConsensusError> {
+        Ok(bytes.to_vec())
+    }
+}
+
+/// Encode a proposal for `scope` into envelope bytes.
+pub fn encode_proposal<Scope: WireScope>(scope: &Scope, proposal: Proposal) -> Vec<u8> {
+    encode(scope, Payload::Proposal(proposal))
+}
+
+/// Encode a vote for `scope` into envelope bytes.
+pub fn encode_vote<Scope: WireScope>(scope: &Scope, vote: Vote) -> Vec<u8> {
+    encode(scope, Payload::Vote(vote))
+}
+
+/// Decode envelope bytes into the scope and the carried payload.
+///
+/// Returns [`MalformedMessage`](ConsensusError::MalformedMessage) if the bytes
+/// are not a valid envelope or carry no payload, and
+/// [`UnsupportedProtocolVersion`](ConsensusError::UnsupportedProtocolVersion)
+/// if the sender uses a different protocol version.
+pub fn decode<Scope: WireScope>(bytes: &[u8]) -> Result<(Scope, Payload), ConsensusError> {
+    let message = ConsensusMessage::decode(bytes).map_err(|_| ConsensusError::MalformedMessage)?;
+    if message.version != PROTOCOL_VERSION {
+        return Err(ConsensusError::UnsupportedProtocolVersion(message.version));
+    }
+    let payload = message.payload.ok_or(ConsensusError::MalformedMessage)?;
+    Ok((Scope::from_wire(&message.scope)?, payload))
+}
+
+/// Export the proposals of a [`ScopeSnapshot`] taken from `scope`.
+///
+/// The bytes are the SHA-256 checksum of a [`ScopeSnapshotExport`] followed
+/// by the encoded export itself. Session state, late votes, and the scope
+/// config are not exported: the importer rebuilds them by feeding each
+/// proposal to
+/// [`process_incoming_proposal`](crate::service::ConsensusService::process_incoming_proposal),
+/// which re-validates its signatures and votes.
+pub fn encode_snapshot<Scope: WireScope>(scope: &Scope, snapshot: &ScopeSnapshot) -> Vec<u8> {
+    let body = ScopeSnapshotExport {
+        version: PROTOCOL_VERSION,
+        scope: scope.to_wire(),
+        proposals: snapshot
+            .sessions
+            .iter()
+            .map(|session| session.proposal.clone())
+            .collect(),
+    }
+    .encode_to_vec();
+    let mut bytes = Sha256::digest(&body).to_vec();
+    bytes.extend_from_slice(&body);
+    bytes
+}
+
+/// Decode bytes from [`encode_snapshot`] into the scope and its proposals.
+///
+/// The checksum is verified before the body is decoded: truncated or altered
+/// bytes return [`CorruptSnapshot`](ConsensusError::CorruptSnapshot). A body
+/// that passes the checksum but does not decode returns
+/// [`MalformedMessage`](ConsensusError::MalformedMessage), and one from a
+/// different protocol version returns
+/// [`UnsupportedProtocolVersion`](ConsensusError::UnsupportedProtocolVersion).
+pub fn decode_snapshot<Scope: WireScope>(
+    bytes: &[u8],
+) -> Result<(Scope, Vec<Proposal>), ConsensusError> {
+    if bytes.len() < SNAPSHOT_CHECKSUM_LEN {
+        return Err(ConsensusError::CorruptSnapshot);
+    }
+    let (checksum, body) = bytes.split_at(SNAPSHOT_CHECKSUM_LEN);
+    if Sha256::digest(body)[..] != *checksum {
+        return Err(ConsensusError::CorruptSnapshot);
+    }
+    let export = ScopeSnapshotExport::decode(body).map_err(|_| ConsensusError::MalformedMessage)?;
+    if export.version != PROTOCOL_VERSION {
+        return Err(ConsensusError::UnsupportedProtocolVersion(export.version));
+    }
+    Ok((Scope::from_wire(&export.scope)?, export.proposals))
+}
+
+fn encode<Scope: WireScope>(scope: &Scope, payload: Payload) -> Vec<u8> {
+    ConsensusMessage {
+        version: PROTOCOL_VERSION,
+        scope: scope.to_wire(),
+        payload: Some(payload),
+    }
+    .encode_to_vec()
+}
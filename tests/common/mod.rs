@@ -29,6 +29,12 @@ pub fn make_service() -> DefaultConsensusService {
     DefaultConsensusService::new(EthereumConsensusSigner::new(PrivateKeySigner::random()))
 }
 
+/// A service with in-memory storage holding `signer`, so the proposals it
+/// creates for `signer` as owner carry a valid proposal signature.
+pub fn make_service_for(signer: &PrivateKeySigner) -> DefaultConsensusService {
+    DefaultConsensusService::new(wrap(signer.clone()))
+}
+
 /// Wrap a raw key into the Ethereum signature scheme.
 pub fn wrap(signer: PrivateKeySigner) -> EthereumConsensusSigner {
     EthereumConsensusSigner::new(signer)
@@ -44,7 +50,7 @@ pub fn owner_bytes(signer: &PrivateKeySigner) -> Vec<u8> {
 pub fn cast_remote_vote(
     service: &DefaultConsensusService,
     scope: &ScopeID,
-    proposal_id: u32,
+    proposal_id: u64,
     choice: bool,
     signer: &EthereumConsensusSigner,
 ) -> Result<Vote, ConsensusError> {
@@ -58,7 +64,7 @@ pub fn cast_remote_vote(
 pub fn cast_remote_vote_and_get_proposal(
     service: &DefaultConsensusService,
     scope: &ScopeID,
-    proposal_id: u32,
+    proposal_id: u64,
     choice: bool,
     signer: &EthereumConsensusSigner,
 ) -> Result<Proposal, ConsensusError> {
@@ -0,0 +1,121 @@
+#![cfg(feature = "compression")]
+
+mod common;
+use common::{cast_remote_vote, make_service, now_ts, owner_bytes, wrap};
+
+use alloy::signers::local::PrivateKeySigner;
+use hashgraph_like_consensus::{
+    scope::ScopeID,
+    session::{ConsensusConfig, ConsensusSession},
+    signing::EthereumConsensusSigner,
+    storage::{ConsensusStorage, DEFAULT_COMPRESSION_THRESHOLD, InMemoryConsensusStorage},
+    types::CreateProposalRequest,
+};
+
+const SCOPE_NAME: &str = "compression_scope";
+const LARGE_PAYLOAD_LEN: usize = 64 * 1024;
+
+fn large_payload() -> Vec<u8> {
+    b"switch the contract to v2; "
+        .iter()
+        .copied()
+        .cycle()
+        .take(LARGE_PAYLOAD_LEN)
+        .collect()
+}
+
+fn make_session(payload: Vec<u8>) -> ConsensusSession {
+    let proposal =
+        CreateProposalRequest::new("Compressed".into(), payload, vec![1, 2, 3], 3, 60, true)
+            .expect("valid proposal request")
+            .into_proposal(now_ts())
+            .expect("proposal");
+    let (session, _) = ConsensusSession::from_proposal::<EthereumConsensusSigner>(
+        proposal,
+        ConsensusConfig::gossipsub(),
+        now_ts(),
+    )
+    .expect("session");
+    session
+}
+
+#[test]
+fn test_large_payload_round_trips_and_shrinks_footprint() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE_NAME);
+    let owner = PrivateKeySigner::random();
+
+    let proposal = service
+        .create_proposal(
+            &scope,
+            CreateProposalRequest::new(
+                "Large".into(),
+                large_payload(),
+                owner_bytes(&owner),
+                2,
+                60,
+                true,
+            )
+            .unwrap(),
+            now_ts(),
+        )
+        .unwrap();
+
+    assert!(service.storage().payload_footprint() < LARGE_PAYLOAD_LEN);
+    let stored = service
+        .storage()
+        .get_proposal(&scope, proposal.proposal_id)
+        .unwrap();
+    assert_eq!(stored.payload, large_payload());
+    assert_eq!(stored, proposal);
+
+    // Votes still verify and mutate the stored (compressed) session.
+    cast_remote_vote(&service, &scope, proposal.proposal_id, true, &wrap(owner)).unwrap();
+    cast_remote_vote(
+        &service,
+        &scope,
+        proposal.proposal_id,
+        true,
+        &wrap(PrivateKeySigner::random()),
+    )
+    .unwrap();
+    assert!(
+        service
+            .storage()
+            .get_consensus_result(&scope, proposal.proposal_id)
+            .unwrap()
+    );
+    assert_eq!(
+        service
+            .storage()
+            .get_proposal(&scope, proposal.proposal_id)
+            .unwrap()
+            .payload,
+        large_payload()
+    );
+    assert!(service.storage().payload_footprint() < LARGE_PAYLOAD_LEN);
+}
+
+#[test]
+fn test_payloads_below_threshold_are_stored_verbatim() {
+    let storage: InMemoryConsensusStorage<ScopeID> = InMemoryConsensusStorage::new();
+    let scope = ScopeID::from(SCOPE_NAME);
+
+    let small = vec![7u8; DEFAULT_COMPRESSION_THRESHOLD - 1];
+    let session = make_session(small.clone());
+    let proposal_id = session.proposal.proposal_id;
+    storage.save_session(&scope, session).unwrap();
+    assert_eq!(storage.payload_footprint(), small.len());
+    assert_eq!(
+        storage.get_proposal(&scope, proposal_id).unwrap().payload,
+        small
+    );
+
+    // Raising the threshold keeps even large payloads verbatim.
+    let storage: InMemoryConsensusStorage<ScopeID> =
+        InMemoryConsensusStorage::new().with_compression_threshold(usize::MAX);
+    storage
+        .save_session(&scope, make_session(large_payload()))
+        .unwrap();
+    assert_eq!(storage.payload_footprint(), LARGE_PAYLOAD_LEN);
+}
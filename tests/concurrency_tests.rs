@@ -17,6 +17,7 @@ use hashgraph_like_consensus::{
     signing::{ConsensusSignatureScheme, EthereumConsensusSigner},
     storage::{ConsensusStorage, InMemoryConsensusStorage},
     types::CreateProposalRequest,
+    utils::build_vote,
 };
 
 fn peer_service(
@@ -226,3 +227,69 @@ fn test_concurrent_duplicate_vote_rejection() {
         "Four votes should be rejected as duplicates"
     );
 }
+
+// Two copies of the same proposal, each carrying a different embedded vote,
+// race into one peer: the loser merges its vote instead of erroring.
+#[test]
+fn test_concurrent_proposal_copies_merge_embedded_votes() {
+    const ROUNDS: usize = 10;
+    let origin = peer_service(
+        &InMemoryConsensusStorage::<ScopeID>::new(),
+        &BroadcastEventBus::<ScopeID>::default(),
+        wrap(PrivateKeySigner::random()),
+    );
+    let storage = InMemoryConsensusStorage::<ScopeID>::new();
+    let bus = BroadcastEventBus::<ScopeID>::default();
+    let scope = ScopeID::from(SCOPE);
+
+    for _ in 0..ROUNDS {
+        let proposal = origin
+            .create_proposal_with_config(
+                &scope,
+                CreateProposalRequest::new(
+                    PROPOSAL_NAME.to_string(),
+                    PROPOSAL_PAYLOAD,
+                    origin.signer().identity().to_vec(),
+                    EXPECTED_VOTERS_COUNT_5,
+                    EXPIRATION,
+                    true,
+                )
+                .expect("valid proposal request"),
+                Some(ConsensusConfig::gossipsub()),
+                now_ts(),
+            )
+            .expect("proposal should be created");
+
+        let barrier = Arc::new(Barrier::new(2));
+        let handles: Vec<_> = [true, false]
+            .into_iter()
+            .map(|choice| {
+                let mut copy = proposal.clone();
+                let vote = build_vote(&copy, choice, &wrap(PrivateKeySigner::random()), now_ts())
+                    .expect("vote");
+                copy.votes.push(vote);
+                let storage = storage.clone();
+                let bus = bus.clone();
+                let scope = scope.clone();
+                let barrier = Arc::clone(&barrier);
+                thread::spawn(move || {
+                    let peer = peer_service(&storage, &bus, wrap(PrivateKeySigner::random()));
+                    barrier.wait();
+                    peer.process_incoming_proposal(&scope, copy, now_ts())
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle
+                .join()
+                .expect("thread should complete")
+                .expect("both copies should be accepted");
+        }
+
+        let stored = storage
+            .get_proposal(&scope, proposal.proposal_id)
+            .expect("proposal stored");
+        assert_eq!(stored.votes.len(), 2, "both embedded votes recorded");
+    }
+}
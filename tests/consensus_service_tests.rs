@@ -1,5 +1,8 @@
 mod common;
-use common::{cast_remote_vote, cast_remote_vote_and_get_proposal, make_service, now_ts, wrap};
+use common::{
+    cast_remote_vote, cast_remote_vote_and_get_proposal, make_service, make_service_for, now_ts,
+    wrap,
+};
 
 use alloy::signers::SignerSync;
 use alloy::signers::local::PrivateKeySigner;
@@ -9,14 +12,24 @@ use std::time::Duration;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use hashgraph_like_consensus::{
+    clock::MockClock,
     error::ConsensusError,
-    events::ConsensusEventBus,
+    events::{BroadcastEventBus, ConsensusEventBus, ReplayEventBus},
     scope::ScopeID,
-    service::DefaultConsensusService,
-    session::ConsensusConfig,
-    storage::ConsensusStorage,
-    types::{ConsensusEvent, CreateProposalRequest},
-    utils::{build_vote, compute_vote_hash},
+    scope_config::{DuplicateVotePolicy, NetworkType, ThresholdMode, TieBreak},
+    service::{ConsensusService, DefaultConsensusService},
+    session::{ConsensusConfig, ConsensusState, MAX_ANNOTATION_LEN},
+    storage::{ConsensusStorage, InMemoryConsensusStorage, diff_snapshots},
+    types::{
+        ConsensusEvent, ConsensusProgress, ConsensusStatus, CreateProposalRequest, EvictionReason,
+        FailReason, HashAlgo, IntegrityIssue, ProposalId, QuorumRule, QuorumVerdict,
+        SessionTransition, TimeoutOutcome, ValidationCost, VoteApplyResult, VoteChoice, VoteTally,
+    },
+    utils::{
+        build_vote, compute_vote_hash, compute_vote_hash_with, estimate_validation_cost,
+        sign_proposal,
+    },
+    weights::VoterWeights,
 };
 
 const SCOPE1_NAME: &str = "scope1";
@@ -25,6 +38,7 @@ const PROPOSAL_NAME: &str = "Test Proposal";
 const PROPOSAL_PAYLOAD: Vec<u8> = vec![];
 const PROPOSAL_EXPIRATION_TIME: u64 = 60;
 
+const EXPECTED_VOTERS_COUNT_5: u32 = 5;
 const EXPECTED_VOTERS_COUNT_4: u32 = 4;
 const EXPECTED_VOTERS_COUNT_3: u32 = 3;
 const EXPECTED_VOTERS_COUNT_2: u32 = 2;
@@ -65,7 +79,7 @@ fn setup_proposal(
 fn cast_vote_or_panic(
     service: &DefaultConsensusService,
     scope: &ScopeID,
-    proposal_id: u32,
+    proposal_id: u64,
     choice: bool,
     signer: PrivateKeySigner,
     msg: &str,
@@ -120,6 +134,14 @@ fn test_basic_consensus_flow() {
         matches!(result, Err(ConsensusError::ConsensusNotReached)),
         "should not have reached consensus with only 1 vote"
     );
+    assert_eq!(
+        service
+            .storage()
+            .consensus_outcome(&scope, proposal.proposal_id)
+            .unwrap(),
+        None,
+        "an active proposal has no outcome yet rather than an error"
+    );
 
     let voter_two = PrivateKeySigner::random();
     cast_remote_vote(
@@ -131,24 +153,39 @@ fn test_basic_consensus_flow() {
     )
     .expect("second vote should succeed");
 
+    // Two YES votes plus the silent peer (liveness YES) reach consensus
+    let result = service
+        .storage()
+        .get_consensus_result(&scope, proposal.proposal_id);
+    assert!(
+        result.is_ok(),
+        "consensus should be reached with 2 YES votes"
+    );
+    assert_eq!(
+        service
+            .storage()
+            .consensus_outcome(&scope, proposal.proposal_id)
+            .unwrap(),
+        Some(true)
+    );
+    assert!(matches!(
+        service
+            .storage()
+            .consensus_outcome(&scope, proposal.proposal_id.wrapping_add(1)),
+        Err(ConsensusError::SessionNotFound)
+    ));
+
+    // Late votes on the finalized session are rejected
     let voter_three = PrivateKeySigner::random();
-    cast_remote_vote(
+    let err = cast_remote_vote(
         &service,
         &scope,
         proposal.proposal_id,
         VOTE_YES,
         &wrap(voter_three),
     )
-    .expect("third vote should succeed");
-
-    // Now consensus should be reached
-    let result = service
-        .storage()
-        .get_consensus_result(&scope, proposal.proposal_id);
-    assert!(
-        result.is_ok(),
-        "consensus should be reached with 3 YES votes"
-    );
+    .unwrap_err();
+    assert!(matches!(err, ConsensusError::SessionTerminal { .. }));
 }
 
 #[test]
@@ -266,7 +303,7 @@ fn test_consensus_threshold_emits_event() {
     )
     .expect("proposal_owner vote");
 
-    for _ in 0..3 {
+    for _ in 0..2 {
         let signer = PrivateKeySigner::random();
         cast_remote_vote(
             &service,
@@ -299,6 +336,220 @@ fn test_consensus_threshold_emits_event() {
     assert!(result);
 }
 
+#[test]
+fn test_scoped_subscriber_only_receives_its_scope() {
+    let bus = BroadcastEventBus::<ScopeID>::new(1);
+    let scope1 = ScopeID::from(SCOPE1_NAME);
+    let scope2 = ScopeID::from(SCOPE2_NAME);
+    let scoped = bus.subscribe_scoped(&scope1);
+    let everything = bus.subscribe();
+    let reached = |proposal_id| ConsensusEvent::ConsensusReached {
+        proposal_id,
+        result: true,
+        timestamp: now_ts(),
+    };
+
+    // Other scopes' events never queue, so a one-slot buffer still has room.
+    for proposal_id in 0..5 {
+        bus.publish(scope2.clone(), reached(proposal_id));
+    }
+    bus.publish(scope1.clone(), reached(7));
+
+    assert_eq!(scoped.try_recv().unwrap(), (scope1, reached(7)));
+    assert!(scoped.try_recv().is_err());
+    assert_eq!(everything.try_recv().unwrap(), (scope2, reached(0)));
+    assert!(everything.try_recv().is_err());
+}
+
+#[test]
+fn test_terminal_subscriber_only_receives_outcomes() {
+    let service = make_service();
+    let terminal_events = service.event_bus().subscribe_terminal_events();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let proposal_owner = PrivateKeySigner::random();
+
+    let proposal = setup_proposal(
+        &service,
+        &scope,
+        &proposal_owner,
+        EXPECTED_VOTERS_COUNT_4,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+
+    cast_remote_vote(
+        &service,
+        &scope,
+        proposal.proposal_id,
+        VOTE_YES,
+        &wrap(proposal_owner),
+    )
+    .expect("proposal_owner vote");
+    cast_remote_vote(
+        &service,
+        &scope,
+        proposal.proposal_id,
+        VOTE_YES,
+        &wrap(PrivateKeySigner::random()),
+    )
+    .expect("second vote");
+    assert!(
+        terminal_events.try_recv().is_err(),
+        "non-deciding votes must not produce terminal events"
+    );
+
+    cast_remote_vote(
+        &service,
+        &scope,
+        proposal.proposal_id,
+        VOTE_YES,
+        &wrap(PrivateKeySigner::random()),
+    )
+    .expect("deciding vote");
+
+    let received: Vec<_> = terminal_events.try_iter().collect();
+    assert_eq!(received.len(), 1);
+    let (event_scope, event) = &received[0];
+    assert_eq!(event_scope, &scope);
+    assert!(event.is_terminal());
+    assert!(matches!(
+        event,
+        ConsensusEvent::ConsensusReached { proposal_id, result: true, .. }
+            if *proposal_id == proposal.proposal_id
+    ));
+}
+
+#[test]
+fn test_reached_only_subscriber_yields_results() {
+    let bus = BroadcastEventBus::<ScopeID>::default();
+    let results = bus.subscribe_reached_only();
+    let scope1 = ScopeID::from(SCOPE1_NAME);
+    let scope2 = ScopeID::from(SCOPE2_NAME);
+
+    bus.publish(
+        scope1.clone(),
+        ConsensusEvent::ConsensusFailed {
+            proposal_id: 1,
+            reason: "tie".to_string(),
+            timestamp: now_ts(),
+        },
+    );
+    bus.publish(
+        scope1.clone(),
+        ConsensusEvent::SessionEvicted {
+            proposal_id: 1,
+            reason: EvictionReason::TtlExpired,
+            timestamp: now_ts(),
+        },
+    );
+    bus.publish(
+        scope2.clone(),
+        ConsensusEvent::ConsensusReached {
+            proposal_id: 2,
+            result: false,
+            timestamp: now_ts(),
+        },
+    );
+    bus.publish(
+        scope1.clone(),
+        ConsensusEvent::ConsensusReached {
+            proposal_id: 3,
+            result: true,
+            timestamp: now_ts(),
+        },
+    );
+
+    assert_eq!(results.try_recv(), Some((scope2, false)));
+    assert_eq!(
+        results.recv_timeout(Duration::from_millis(10)),
+        Some((scope1, true))
+    );
+    assert_eq!(results.try_recv(), None);
+    assert_eq!(results.recv_timeout(Duration::from_millis(10)), None);
+    drop(bus);
+    assert_eq!(results.count(), 0);
+}
+
+#[test]
+fn test_replay_bus_delivers_result_to_late_subscriber() {
+    let service = ConsensusService::new_with_components(
+        InMemoryConsensusStorage::<ScopeID>::default(),
+        ReplayEventBus::new(8),
+        wrap(PrivateKeySigner::random()),
+        10,
+    );
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let early = service.event_bus().subscribe();
+
+    let proposal = service
+        .create_proposal(
+            &scope,
+            CreateProposalRequest::new(
+                PROPOSAL_NAME.to_string(),
+                PROPOSAL_PAYLOAD,
+                vec![0u8; 20],
+                EXPECTED_VOTERS_COUNT_2,
+                PROPOSAL_EXPIRATION_TIME,
+                true,
+            )
+            .expect("valid proposal request"),
+            now_ts(),
+        )
+        .expect("proposal should be created");
+    service
+        .cast_vote(&scope, proposal.proposal_id, VOTE_YES, now_ts())
+        .expect("own vote");
+    let proposal = service
+        .storage()
+        .get_proposal(&scope, proposal.proposal_id)
+        .unwrap();
+    let vote = build_vote(
+        &proposal,
+        VOTE_YES,
+        &wrap(PrivateKeySigner::random()),
+        now_ts(),
+    )
+    .unwrap();
+    service
+        .process_incoming_vote(&scope, vote, now_ts())
+        .expect("remote vote");
+
+    let reached = |(_, event): &(ScopeID, ConsensusEvent)| {
+        matches!(event, ConsensusEvent::ConsensusReached { result: true, .. })
+    };
+    let live: Vec<_> = early.try_iter().collect();
+    assert!(live.iter().any(reached));
+
+    // Subscribed after finalization: the buffered events arrive in order.
+    let late = service.event_bus().subscribe();
+    let replayed: Vec<_> = late.try_iter().collect();
+    assert_eq!(replayed.len(), live.len());
+    assert!(replayed.iter().any(reached));
+
+    let other_scope = service
+        .event_bus()
+        .subscribe_scoped(&ScopeID::from(SCOPE2_NAME));
+    assert!(other_scope.try_recv().is_err());
+
+    // The ring buffer keeps only the most recent events.
+    let bounded = ReplayEventBus::<ScopeID>::new(1);
+    for proposal_id in 1..=3 {
+        bounded.publish(
+            scope.clone(),
+            ConsensusEvent::ConsensusFailed {
+                proposal_id,
+                reason: "tie".to_string(),
+                timestamp: now_ts(),
+            },
+        );
+    }
+    let replayed: Vec<_> = bounded.subscribe().try_iter().collect();
+    assert!(matches!(
+        replayed.as_slice(),
+        [(_, ConsensusEvent::ConsensusFailed { proposal_id: 3, .. })]
+    ));
+}
+
 #[test]
 fn test_handle_consensus_timeout_already_reached() {
     let service = make_service();
@@ -343,7 +594,11 @@ fn test_handle_consensus_timeout_already_reached() {
         .handle_consensus_timeout(&scope, proposal.proposal_id, now_ts())
         .expect("should return consensus result");
 
-    assert!(result, "should return true (YES consensus)");
+    assert_eq!(
+        result,
+        TimeoutOutcome::AlreadyReached(true),
+        "should report the pre-existing YES consensus"
+    );
 }
 
 #[test]
@@ -383,12 +638,16 @@ fn test_handle_consensus_timeout_reaches_consensus() {
         "second vote",
     );
 
-    // Call handle_consensus_timeout - should calculate consensus and reach it
+    // Votes already decided the proposal, so the timeout reports the existing result
     let result = service
         .handle_consensus_timeout(&scope, proposal.proposal_id, now_ts())
         .expect("should reach consensus");
 
-    assert!(result, "should return true (YES consensus)");
+    assert_eq!(
+        result,
+        TimeoutOutcome::AlreadyReached(true),
+        "should return true (YES consensus)"
+    );
 
     // Verify event was emitted
     let event_received = (|| {
@@ -461,7 +720,11 @@ fn test_handle_consensus_timeout_reaches_no_consensus_with_multiple_votes() {
         .handle_consensus_timeout(&scope, proposal.proposal_id, now_ts())
         .expect("should reach consensus at timeout");
 
-    assert!(!result, "should return false (NO consensus)");
+    assert_eq!(
+        result,
+        TimeoutOutcome::AlreadyReached(false),
+        "should return false (NO consensus)"
+    );
 
     let event_result = (|| {
         while let Ok((event_scope, event)) = events.recv_timeout(Duration::from_secs(1)) {
@@ -515,7 +778,11 @@ fn test_handle_consensus_timeout_resolves_with_liveness_yes() {
         .handle_consensus_timeout(&scope, proposal.proposal_id, now_ts())
         .expect("should reach consensus with silent peers as YES");
 
-    assert!(result, "should return true (YES consensus)");
+    assert_eq!(
+        result,
+        TimeoutOutcome::Reached(true),
+        "should return true (YES consensus)"
+    );
 
     // Verify ConsensusReached event was emitted
     let event_received = (|| {
@@ -583,13 +850,14 @@ fn test_handle_consensus_timeout_insufficient_votes() {
     );
 
     // Call handle_consensus_timeout - should fail (tied votes, no majority)
-    let err = service
+    let outcome = service
         .handle_consensus_timeout(&scope, proposal.proposal_id, now_ts())
-        .expect_err("should fail with insufficient votes");
+        .expect("timeout handling should succeed");
 
-    assert!(
-        matches!(err, ConsensusError::InsufficientVotesAtTimeout),
-        "should return InsufficientVotesAtTimeout error"
+    assert_eq!(
+        outcome,
+        TimeoutOutcome::Failed,
+        "tied votes should fail at timeout"
     );
 
     // Verify ConsensusFailed event was emitted
@@ -598,7 +866,7 @@ fn test_handle_consensus_timeout_insufficient_votes() {
             if event_scope == scope
                 && let ConsensusEvent::ConsensusFailed {
                     proposal_id: event_proposal_id,
-                    timestamp: _event_timestamp,
+                    ..
                 } = event
                 && event_proposal_id == proposal.proposal_id
             {
@@ -650,7 +918,11 @@ fn test_handle_consensus_timeout_no_votes_liveness_true() {
         .handle_consensus_timeout(&scope, proposal.proposal_id, now_ts())
         .expect("should reach consensus with silent peers as YES");
 
-    assert!(result, "should return true (all silent peers as YES)");
+    assert_eq!(
+        result,
+        TimeoutOutcome::Reached(true),
+        "should return true (all silent peers as YES)"
+    );
 
     // Verify ConsensusReached event was emitted
     let event_received = (|| {
@@ -695,7 +967,11 @@ fn test_handle_consensus_timeout_no_votes_liveness_false() {
         .handle_consensus_timeout(&scope, proposal.proposal_id, now_ts())
         .expect("should reach NO consensus with silent peers as NO");
 
-    assert!(!result, "should return false (all silent peers as NO)");
+    assert_eq!(
+        result,
+        TimeoutOutcome::Reached(false),
+        "should return false (all silent peers as NO)"
+    );
 
     // Verify ConsensusReached event was emitted
     let event_received = (|| {
@@ -757,7 +1033,11 @@ fn test_handle_consensus_timeout_reaches_consensus_p2p() {
         .handle_consensus_timeout(&scope, proposal.proposal_id, now_ts())
         .expect("should reach consensus");
 
-    assert!(result, "should return true (YES consensus)");
+    assert_eq!(
+        result,
+        TimeoutOutcome::AlreadyReached(true),
+        "should return true (YES consensus)"
+    );
 
     let event_received = (|| {
         while let Ok((event_scope, event)) = events.recv_timeout(Duration::from_secs(1)) {
@@ -815,13 +1095,14 @@ fn test_handle_consensus_timeout_insufficient_votes_p2p() {
         "second vote",
     );
 
-    let err = service
+    let outcome = service
         .handle_consensus_timeout(&scope, proposal.proposal_id, now_ts())
-        .expect_err("should fail with insufficient votes");
+        .expect("timeout handling should succeed");
 
-    assert!(
-        matches!(err, ConsensusError::InsufficientVotesAtTimeout),
-        "should return InsufficientVotesAtTimeout error"
+    assert_eq!(
+        outcome,
+        TimeoutOutcome::Failed,
+        "tied votes should fail at timeout"
     );
 
     let event_received = (|| {
@@ -829,7 +1110,7 @@ fn test_handle_consensus_timeout_insufficient_votes_p2p() {
             if event_scope == scope
                 && let ConsensusEvent::ConsensusFailed {
                     proposal_id: event_proposal_id,
-                    timestamp: _event_timestamp,
+                    ..
                 } = event
                 && event_proposal_id == proposal.proposal_id
             {
@@ -884,9 +1165,9 @@ fn test_cast_vote_rejects_same_voter_twice() {
 
 #[test]
 fn test_process_incoming_proposal_rejects_duplicate_proposal() {
-    let service = make_service();
-    let scope = ScopeID::from(SCOPE1_NAME);
     let proposal_owner = PrivateKeySigner::random();
+    let service = make_service_for(&proposal_owner);
+    let scope = ScopeID::from(SCOPE1_NAME);
 
     let proposal = service
         .create_proposal_with_config(
@@ -915,6 +1196,72 @@ fn test_process_incoming_proposal_rejects_duplicate_proposal() {
     );
 }
 
+#[test]
+fn test_known_proposal_copy_validates_embedded_votes() {
+    let owner = PrivateKeySigner::random();
+    let service = make_service_for(&owner);
+    let events = service.event_bus().subscribe();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let proposal = setup_proposal(
+        &service,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_5,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+    let proposal_id = proposal.proposal_id;
+    let voter = wrap(PrivateKeySigner::random());
+    let held_votes = || {
+        service
+            .storage()
+            .get_session(&scope, proposal_id)
+            .unwrap()
+            .unwrap()
+            .votes()
+            .len()
+    };
+
+    // An embedded vote for another proposal is refused like a direct one.
+    let mut foreign = proposal.clone();
+    let mut vote = build_vote(&proposal, VOTE_YES, &voter, now_ts()).unwrap();
+    vote.proposal_id = proposal_id.wrapping_add(1);
+    foreign.votes.push(vote);
+    assert!(matches!(
+        service.process_incoming_proposal(&scope, foreign, now_ts()),
+        Err(ConsensusError::VoteProposalIdMismatch)
+    ));
+    assert_eq!(held_votes(), 0);
+
+    let held = cast_remote_vote(&service, &scope, proposal_id, VOTE_YES, &voter).unwrap();
+
+    // A copy contradicting the held vote is equivocation, and is announced.
+    let mut conflicting = proposal.clone();
+    let contradiction = build_vote(&proposal, !VOTE_YES, &voter, now_ts()).unwrap();
+    conflicting.votes.push(contradiction.clone());
+    assert!(matches!(
+        service.process_incoming_proposal(&scope, conflicting, now_ts()),
+        Err(ConsensusError::Equivocation { .. })
+    ));
+    assert!(matches!(
+        events.try_recv(),
+        Ok((_, ConsensusEvent::EquivocationDetected { first, second, .. }))
+            if *first == held && *second == contradiction
+    ));
+    assert_eq!(held_votes(), 1);
+
+    // Repeating a held voter's choice brings nothing new.
+    let mut repeated = proposal.clone();
+    repeated
+        .votes
+        .push(build_vote(&proposal, VOTE_YES, &voter, now_ts() + 1).unwrap());
+    assert!(matches!(
+        service.process_incoming_proposal(&scope, repeated, now_ts() + 1),
+        Err(ConsensusError::ProposalAlreadyExist)
+    ));
+    assert_eq!(held_votes(), 1);
+}
+
 #[test]
 fn test_process_incoming_vote_rejects_unknown_session() {
     let service = make_service();
@@ -976,7 +1323,7 @@ fn test_handle_consensus_timeout_rejects_unknown_session() {
     let scope = ScopeID::from(SCOPE1_NAME);
 
     let err = service
-        .handle_consensus_timeout(&scope, u32::MAX, now_ts())
+        .handle_consensus_timeout(&scope, u64::MAX, now_ts())
         .expect_err("timeout handling for unknown proposal should fail");
 
     assert!(
@@ -987,7 +1334,8 @@ fn test_handle_consensus_timeout_rejects_unknown_session() {
 
 #[test]
 fn test_process_incoming_proposal_rejects_expired_proposal() {
-    let service = make_service();
+    let clock = MockClock::new(now_ts());
+    let service = make_service().with_clock(clock.clone());
     let scope = ScopeID::from(SCOPE1_NAME);
     let proposal_owner = PrivateKeySigner::random();
 
@@ -1001,13 +1349,13 @@ fn test_process_incoming_proposal_rejects_expired_proposal() {
     )
     .expect("valid proposal request");
     let proposal = request
-        .into_proposal(now_ts())
+        .into_signed_proposal(&wrap(proposal_owner), service.now())
         .expect("proposal should be created");
 
-    std::thread::sleep(Duration::from_secs(2));
+    clock.advance(Duration::from_secs(2));
 
     let err = service
-        .process_incoming_proposal(&scope, proposal, now_ts())
+        .process_incoming_proposal(&scope, proposal, service.now())
         .expect_err("expired incoming proposal should fail");
 
     assert!(
@@ -1258,21 +1606,15 @@ fn test_handle_consensus_timeout_is_idempotent_for_failed_session() {
     )
     .expect("second vote should succeed");
 
-    let err_first = service
+    let first = service
         .handle_consensus_timeout(&scope, proposal.proposal_id, now_ts())
-        .expect_err("first timeout should fail consensus");
-    assert!(matches!(
-        err_first,
-        ConsensusError::InsufficientVotesAtTimeout
-    ));
+        .expect("first timeout should be handled");
+    assert_eq!(first, TimeoutOutcome::Failed);
 
-    let err_second = service
+    let second = service
         .handle_consensus_timeout(&scope, proposal.proposal_id, now_ts())
-        .expect_err("second timeout should keep failed consensus");
-    assert!(matches!(
-        err_second,
-        ConsensusError::InsufficientVotesAtTimeout
-    ));
+        .expect("second timeout should be handled");
+    assert_eq!(second, TimeoutOutcome::Failed);
 
     let result = service
         .storage()
@@ -1333,11 +1675,12 @@ fn test_process_incoming_proposal_resolve_config_uses_base_timeout_when_expirati
  {
     let service = make_service();
     let scope = ScopeID::from("resolve_config_base_timeout_scope");
+    let proposal_owner = PrivateKeySigner::random();
 
     let request = CreateProposalRequest::new(
         PROPOSAL_NAME.to_string(),
         PROPOSAL_PAYLOAD,
-        vec![1u8; 20],
+        proposal_owner_from_signer(&proposal_owner),
         EXPECTED_VOTERS_COUNT_3,
         PROPOSAL_EXPIRATION_TIME,
         false, // ensure liveness comes from proposal fields
@@ -1355,6 +1698,7 @@ fn test_process_incoming_proposal_resolve_config_uses_base_timeout_when_expirati
         + 120;
     incoming.timestamp = future;
     incoming.expiration_timestamp = future;
+    sign_proposal(&mut incoming, &wrap(proposal_owner)).expect("re-sign proposal");
 
     service
         .process_incoming_proposal(&scope, incoming.clone(), now_ts())
@@ -1733,3 +2077,4448 @@ fn test_delete_scope_on_unknown_scope_is_ok() {
         "delete_scope on unknown scope should succeed"
     );
 }
+
+#[test]
+fn test_rename_scope_moves_sessions_and_config() {
+    let service = make_service();
+    let old_scope = ScopeID::from("rename_old");
+    let new_scope = ScopeID::from("rename_new");
+
+    service
+        .scope(&old_scope)
+        .unwrap()
+        .with_threshold(0.75)
+        .initialize()
+        .unwrap();
+
+    // One finalized session (n = 1) and one still-active session.
+    let finalized_owner = PrivateKeySigner::random();
+    let finalized = setup_proposal(
+        &service,
+        &old_scope,
+        &finalized_owner,
+        EXPECTED_VOTERS_COUNT_1,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+    cast_remote_vote(
+        &service,
+        &old_scope,
+        finalized.proposal_id,
+        VOTE_YES,
+        &wrap(finalized_owner),
+    )
+    .expect("vote should succeed");
+
+    let active = setup_proposal(
+        &service,
+        &old_scope,
+        &PrivateKeySigner::random(),
+        EXPECTED_VOTERS_COUNT_3,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+
+    service
+        .storage()
+        .rename_scope(&old_scope, &new_scope)
+        .expect("rename should succeed");
+
+    // Everything is reachable under the new name.
+    assert!(
+        service
+            .storage()
+            .get_consensus_result(&new_scope, finalized.proposal_id)
+            .unwrap()
+    );
+    let active_proposals = service.storage().get_active_proposals(&new_scope).unwrap();
+    assert_eq!(active_proposals.len(), 1);
+    assert_eq!(active_proposals[0].proposal_id, active.proposal_id);
+    let config = service
+        .storage()
+        .get_scope_config(&new_scope)
+        .unwrap()
+        .expect("config should move with the scope");
+    assert_eq!(config.default_consensus_threshold, 0.75);
+
+    // Votes keep working on the moved active session.
+    service
+        .cast_vote(&new_scope, active.proposal_id, VOTE_YES, now_ts())
+        .expect("vote under new scope should succeed");
+
+    // Nothing remains under the old name.
+    assert!(matches!(
+        service
+            .storage()
+            .get_consensus_result(&old_scope, finalized.proposal_id),
+        Err(ConsensusError::SessionNotFound)
+    ));
+    assert!(
+        service
+            .storage()
+            .get_scope_config(&old_scope)
+            .unwrap()
+            .is_none()
+    );
+    assert!(
+        service
+            .storage()
+            .list_scope_sessions(&old_scope)
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[test]
+fn test_rename_scope_rejects_existing_target_and_unknown_source() {
+    let service = make_service();
+    let scope_a = ScopeID::from("rename_a");
+    let scope_b = ScopeID::from("rename_b");
+
+    setup_proposal(
+        &service,
+        &scope_a,
+        &PrivateKeySigner::random(),
+        EXPECTED_VOTERS_COUNT_3,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+    service.scope(&scope_b).unwrap().initialize().unwrap();
+
+    let err = service
+        .storage()
+        .rename_scope(&scope_a, &scope_b)
+        .unwrap_err();
+    assert!(matches!(err, ConsensusError::ScopeAlreadyExists));
+    assert_eq!(
+        service
+            .storage()
+            .get_active_proposals(&scope_a)
+            .unwrap()
+            .len(),
+        1
+    );
+
+    let err = service
+        .storage()
+        .rename_scope(&ScopeID::from("rename_missing"), &ScopeID::from("rename_c"))
+        .unwrap_err();
+    assert!(matches!(err, ConsensusError::ScopeNotFound));
+}
+
+#[test]
+fn test_service_rename_and_rollover_carry_in_memory_scope_state() {
+    let owner = PrivateKeySigner::random();
+    let remote = make_service_for(&owner);
+    let service = make_service()
+        .with_orphan_vote_buffer(16, Duration::from_secs(PROPOSAL_EXPIRATION_TIME))
+        .with_vote_batching(Duration::from_secs(10))
+        .with_proposal_id_reuse_window(Duration::from_secs(5));
+    let old_scope = ScopeID::from("carry_old");
+    let new_scope = ScopeID::from("carry_new");
+    let start = now_ts();
+    let propose = || {
+        setup_proposal(
+            &remote,
+            &old_scope,
+            &owner,
+            EXPECTED_VOTERS_COUNT_5,
+            true,
+            ConsensusConfig::gossipsub(),
+        )
+    };
+    let remote_vote = |proposal_id| {
+        cast_remote_vote(
+            &remote,
+            &old_scope,
+            proposal_id,
+            VOTE_YES,
+            &wrap(PrivateKeySigner::random()),
+        )
+        .expect("remote vote")
+    };
+
+    // A reserved ID, a pending vote batch, and a held orphan vote in the old scope.
+    let removed = propose();
+    service
+        .process_incoming_proposal(&old_scope, removed.clone(), start)
+        .unwrap();
+    service
+        .remove_proposal(&old_scope, removed.proposal_id, start)
+        .unwrap();
+    let batched = propose();
+    service
+        .process_incoming_proposal(&old_scope, batched.clone(), start)
+        .unwrap();
+    service
+        .process_incoming_vote(&old_scope, remote_vote(batched.proposal_id), start)
+        .unwrap();
+    let orphaned = propose();
+    service
+        .process_incoming_vote(&old_scope, remote_vote(orphaned.proposal_id), start)
+        .unwrap();
+
+    let events = service.event_bus().subscribe();
+    service.rename_scope(&old_scope, &new_scope).unwrap();
+
+    assert!(matches!(
+        service.process_incoming_proposal(&new_scope, removed, start),
+        Err(ConsensusError::ProposalIdRecentlyUsed)
+    ));
+    service
+        .process_incoming_proposal(&new_scope, orphaned.clone(), start)
+        .unwrap();
+    assert_eq!(
+        service
+            .storage()
+            .get_proposal(&new_scope, orphaned.proposal_id)
+            .unwrap()
+            .votes
+            .len(),
+        1
+    );
+    service.flush_vote_batches(start + 10);
+    let flushed: Vec<(ScopeID, ConsensusEvent)> = events.try_iter().collect();
+    assert!(flushed.iter().all(|(scope, _)| *scope == new_scope));
+    assert!(flushed.iter().any(|(_, event)| matches!(
+        event,
+        ConsensusEvent::VotesBatch { proposal_id, .. } if *proposal_id == batched.proposal_id
+    )));
+
+    // Rollover drops the pending batch and the held orphan vote, and reserves
+    // the archived IDs.
+    service
+        .process_incoming_vote(&new_scope, remote_vote(batched.proposal_id), start + 20)
+        .unwrap();
+    let late = propose();
+    service
+        .process_incoming_vote(&new_scope, remote_vote(late.proposal_id), start + 20)
+        .unwrap();
+    let snapshot = service.rollover_scope(&new_scope, start + 20).unwrap();
+    assert_eq!(snapshot.sessions.len(), 2);
+    assert!(matches!(
+        service.process_incoming_proposal(&new_scope, orphaned, start + 20),
+        Err(ConsensusError::ProposalIdRecentlyUsed)
+    ));
+
+    // Once the reuse window passes, the next epoch reuses an archived ID.
+    service
+        .process_incoming_proposal(&new_scope, batched, start + 25)
+        .unwrap();
+    service
+        .process_incoming_proposal(&new_scope, late.clone(), start + 25)
+        .unwrap();
+    service.flush_vote_batches(start + 40);
+    assert!(events.try_recv().is_err());
+    assert!(
+        service
+            .storage()
+            .get_proposal(&new_scope, late.proposal_id)
+            .unwrap()
+            .votes
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_handle_consensus_timeout_already_reached_does_not_reemit_event() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let proposal_owner = PrivateKeySigner::random();
+
+    let proposal = setup_proposal(
+        &service,
+        &scope,
+        &proposal_owner,
+        EXPECTED_VOTERS_COUNT_1,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+    cast_vote_or_panic(
+        &service,
+        &scope,
+        proposal.proposal_id,
+        false,
+        proposal_owner,
+        "single vote",
+    );
+
+    let events = service.event_bus().subscribe();
+    let outcome = service
+        .handle_consensus_timeout(&scope, proposal.proposal_id, now_ts())
+        .expect("timeout handling should succeed");
+
+    assert_eq!(outcome, TimeoutOutcome::AlreadyReached(false));
+    assert!(
+        events.try_recv().is_err(),
+        "no event should be emitted for an already-decided proposal"
+    );
+}
+
+#[test]
+fn test_absolute_yes_threshold_decides_on_fixed_count() {
+    let service = make_service();
+    let scope = ScopeID::from("absolute_threshold_scope");
+    service
+        .scope(&scope)
+        .unwrap()
+        .with_network_type(NetworkType::P2P)
+        .with_threshold_mode(ThresholdMode::AbsoluteYes(3))
+        .initialize()
+        .unwrap();
+
+    let request = |expected_voters_count| {
+        CreateProposalRequest::new(
+            PROPOSAL_NAME.to_string(),
+            PROPOSAL_PAYLOAD,
+            vec![0u8; 20],
+            expected_voters_count,
+            PROPOSAL_EXPIRATION_TIME,
+            true,
+        )
+        .expect("valid proposal request")
+    };
+    let proposal = service
+        .create_proposal(&scope, request(10), now_ts())
+        .expect("proposal should be created");
+    for _ in 0..2 {
+        cast_vote_or_panic(
+            &service,
+            &scope,
+            proposal.proposal_id,
+            VOTE_YES,
+            PrivateKeySigner::random(),
+            "yes vote",
+        );
+    }
+    assert!(matches!(
+        service.get_consensus_result(&scope, proposal.proposal_id, now_ts()),
+        Err(ConsensusError::ConsensusNotReached)
+    ));
+    cast_vote_or_panic(
+        &service,
+        &scope,
+        proposal.proposal_id,
+        VOTE_YES,
+        PrivateKeySigner::random(),
+        "third yes vote",
+    );
+    assert!(
+        service
+            .get_consensus_result(&scope, proposal.proposal_id, now_ts())
+            .unwrap()
+    );
+
+    assert!(matches!(
+        service.create_proposal(&scope, request(EXPECTED_VOTERS_COUNT_2), now_ts()),
+        Err(ConsensusError::AbsoluteThresholdExceedsVoters {
+            required: 3,
+            expected_voters_count: 2,
+        })
+    ));
+    assert!(matches!(
+        service
+            .scope(&scope)
+            .unwrap()
+            .with_threshold_mode(ThresholdMode::AbsoluteYes(0))
+            .update(),
+        Err(ConsensusError::InvalidConsensusThreshold)
+    ));
+}
+
+#[test]
+fn test_high_weight_yes_outvotes_low_weight_nos() {
+    let service = make_service();
+    let scope = ScopeID::from("weighted_scope");
+    let whale = PrivateKeySigner::random();
+    service
+        .scope(&scope)
+        .unwrap()
+        .with_voter_weights(VoterWeights::new().with_weight(proposal_owner_from_signer(&whale), 10))
+        .initialize()
+        .unwrap();
+
+    let proposal = service
+        .create_proposal(
+            &scope,
+            CreateProposalRequest::new(
+                PROPOSAL_NAME.to_string(),
+                PROPOSAL_PAYLOAD,
+                vec![0u8; 20],
+                EXPECTED_VOTERS_COUNT_4,
+                PROPOSAL_EXPIRATION_TIME,
+                true,
+            )
+            .expect("valid proposal request"),
+            now_ts(),
+        )
+        .expect("proposal should be created");
+
+    // Three NOs weigh 3 of a possible 13: not enough to decide.
+    for _ in 0..3 {
+        cast_vote_or_panic(
+            &service,
+            &scope,
+            proposal.proposal_id,
+            !VOTE_YES,
+            PrivateKeySigner::random(),
+            "no vote",
+        );
+    }
+    assert!(matches!(
+        service.get_consensus_result(&scope, proposal.proposal_id, now_ts()),
+        Err(ConsensusError::ConsensusNotReached)
+    ));
+
+    cast_vote_or_panic(
+        &service,
+        &scope,
+        proposal.proposal_id,
+        VOTE_YES,
+        whale,
+        "weighted yes vote",
+    );
+    assert!(
+        service
+            .get_consensus_result(&scope, proposal.proposal_id, now_ts())
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_allowed_voters_reject_ineligible_votes() {
+    let owner = PrivateKeySigner::random();
+    let member = PrivateKeySigner::random();
+    let outsider = PrivateKeySigner::random();
+    let service = make_service_for(&owner);
+    let outsider_service = make_service_for(&outsider);
+    let scope = ScopeID::from("allowlist_scope");
+    for service in [&service, &outsider_service] {
+        service
+            .scope(&scope)
+            .unwrap()
+            .with_allowed_voters(Some(
+                [&owner, &member].map(proposal_owner_from_signer).into(),
+            ))
+            .initialize()
+            .unwrap();
+    }
+
+    assert!(matches!(
+        setup_proposal_result(&service, &scope, &owner, EXPECTED_VOTERS_COUNT_3),
+        Err(ConsensusError::ExpectedVotersExceedAllowlist {
+            expected_voters_count: 3,
+            allowed_voters: 2,
+        })
+    ));
+    let proposal = setup_proposal_result(&service, &scope, &owner, EXPECTED_VOTERS_COUNT_2)
+        .expect("proposal should be created");
+    let sibling = setup_proposal_result(&service, &scope, &owner, EXPECTED_VOTERS_COUNT_2)
+        .expect("proposal should be created");
+    let configs = [proposal.proposal_id, sibling.proposal_id]
+        .map(|id| service.storage().get_proposal_config(&scope, id).unwrap());
+    assert!(
+        std::ptr::eq(
+            configs[0].allowed_voters().unwrap(),
+            configs[1].allowed_voters().unwrap()
+        ),
+        "sessions share the scope's allowlist instead of copying it"
+    );
+    outsider_service
+        .process_incoming_proposal(&scope, proposal.clone(), now_ts())
+        .expect("outsider peer stores the proposal");
+
+    // A local cast and a gossiped vote from outside the set are both refused.
+    assert!(matches!(
+        outsider_service.cast_vote(&scope, proposal.proposal_id, VOTE_YES, now_ts()),
+        Err(ConsensusError::VoterNotEligible)
+    ));
+    assert!(matches!(
+        cast_remote_vote(
+            &service,
+            &scope,
+            proposal.proposal_id,
+            VOTE_YES,
+            &wrap(outsider)
+        ),
+        Err(ConsensusError::VoterNotEligible)
+    ));
+
+    service
+        .cast_vote(&scope, proposal.proposal_id, VOTE_YES, now_ts())
+        .expect("owner is eligible");
+    cast_vote_or_panic(
+        &service,
+        &scope,
+        proposal.proposal_id,
+        VOTE_YES,
+        member,
+        "member is eligible",
+    );
+    assert!(
+        service
+            .get_consensus_result(&scope, proposal.proposal_id, now_ts())
+            .unwrap()
+    );
+}
+
+fn setup_proposal_result(
+    service: &DefaultConsensusService,
+    scope: &ScopeID,
+    proposal_owner: &PrivateKeySigner,
+    expected_voters_count: u32,
+) -> Result<hashgraph_like_consensus::protos::consensus::v1::Proposal, ConsensusError> {
+    service.create_proposal(
+        scope,
+        CreateProposalRequest::new(
+            PROPOSAL_NAME.to_string(),
+            PROPOSAL_PAYLOAD,
+            proposal_owner_from_signer(proposal_owner),
+            expected_voters_count,
+            PROPOSAL_EXPIRATION_TIME,
+            true,
+        )
+        .expect("valid proposal request"),
+        now_ts(),
+    )
+}
+
+#[test]
+fn test_non_voters_returns_known_voters_without_votes() {
+    let service = make_service();
+    let scope = ScopeID::from("non_voters_scope");
+    let members: Vec<PrivateKeySigner> = (0..4).map(|_| PrivateKeySigner::random()).collect();
+
+    service
+        .scope(&scope)
+        .unwrap()
+        .with_allowed_voters(Some(
+            members.iter().map(proposal_owner_from_signer).collect(),
+        ))
+        .initialize()
+        .unwrap();
+
+    let proposal = setup_proposal(
+        &service,
+        &scope,
+        &members[0],
+        EXPECTED_VOTERS_COUNT_4,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+    for member in &members[..2] {
+        cast_vote_or_panic(
+            &service,
+            &scope,
+            proposal.proposal_id,
+            VOTE_YES,
+            member.clone(),
+            "member vote",
+        );
+    }
+
+    let mut expected: Vec<Vec<u8>> = members[2..]
+        .iter()
+        .map(proposal_owner_from_signer)
+        .collect();
+    expected.sort();
+    let pending = service
+        .storage()
+        .non_voters(&scope, proposal.proposal_id)
+        .expect("non_voters should succeed");
+    assert_eq!(pending, expected);
+}
+
+#[test]
+fn test_non_voters_without_known_voter_set_errors() {
+    let service = make_service();
+    let scope = ScopeID::from("non_voters_open_scope");
+    let proposal = setup_proposal(
+        &service,
+        &scope,
+        &PrivateKeySigner::random(),
+        EXPECTED_VOTERS_COUNT_3,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+
+    let err = service
+        .storage()
+        .non_voters(&scope, proposal.proposal_id)
+        .unwrap_err();
+    assert!(matches!(err, ConsensusError::VoterSetUnknown));
+}
+
+#[test]
+fn test_known_voters_is_deduplicated_union_across_proposals() {
+    let service = make_service();
+    let scope = ScopeID::from("known_voters_scope");
+    let voters: Vec<PrivateKeySigner> = (0..4).map(|_| PrivateKeySigner::random()).collect();
+
+    // Overlapping voter sets: {0, 1}, {1, 2}, {2, 3} across three proposals.
+    for pair in voters.windows(2) {
+        let proposal = setup_proposal(
+            &service,
+            &scope,
+            &PrivateKeySigner::random(),
+            EXPECTED_VOTERS_COUNT_4,
+            true,
+            ConsensusConfig::gossipsub(),
+        );
+        for voter in pair {
+            cast_vote_or_panic(
+                &service,
+                &scope,
+                proposal.proposal_id,
+                VOTE_YES,
+                voter.clone(),
+                "voter vote",
+            );
+        }
+    }
+
+    let mut expected: Vec<Vec<u8>> = voters.iter().map(proposal_owner_from_signer).collect();
+    expected.sort();
+    assert_eq!(service.storage().known_voters(&scope).unwrap(), expected);
+    assert!(
+        service
+            .storage()
+            .known_voters(&ScopeID::from("known_voters_unknown"))
+            .unwrap()
+            .is_empty()
+    );
+}
+
+fn setup_partial_tie(service: &DefaultConsensusService, scope: &ScopeID) -> u64 {
+    // n = 4, liveness YES: 1 YES + 1 silent (counted YES) vs 2 NO → tie at timeout.
+    let proposal_owner = PrivateKeySigner::random();
+    let proposal = service
+        .create_proposal(
+            scope,
+            CreateProposalRequest::new(
+                PROPOSAL_NAME.to_string(),
+                PROPOSAL_PAYLOAD,
+                proposal_owner_from_signer(&proposal_owner),
+                EXPECTED_VOTERS_COUNT_4,
+                PROPOSAL_EXPIRATION_TIME,
+                true,
+            )
+            .expect("valid proposal request"),
+            now_ts(),
+        )
+        .expect("proposal should be created");
+
+    cast_vote_or_panic(
+        service,
+        scope,
+        proposal.proposal_id,
+        VOTE_YES,
+        proposal_owner,
+        "YES vote",
+    );
+    for _ in 0..2 {
+        cast_vote_or_panic(
+            service,
+            scope,
+            proposal.proposal_id,
+            false,
+            PrivateKeySigner::random(),
+            "NO vote",
+        );
+    }
+    proposal.proposal_id
+}
+
+#[test]
+fn test_partial_tie_at_timeout_fails_by_default() {
+    let service = make_service();
+    let scope = ScopeID::from("partial_tie_default");
+    let proposal_id = setup_partial_tie(&service, &scope);
+
+    let outcome = service
+        .handle_consensus_timeout(&scope, proposal_id, now_ts())
+        .unwrap();
+    assert_eq!(outcome, TimeoutOutcome::Failed);
+}
+
+#[test]
+fn test_partial_tie_at_timeout_resolves_to_liveness_when_enabled() {
+    let service = make_service();
+    let scope = ScopeID::from("partial_tie_resolve");
+    service
+        .scope(&scope)
+        .unwrap()
+        .with_resolve_partial_tie_on_timeout(true)
+        .initialize()
+        .unwrap();
+    let proposal_id = setup_partial_tie(&service, &scope);
+
+    let outcome = service
+        .handle_consensus_timeout(&scope, proposal_id, now_ts())
+        .unwrap();
+    assert_eq!(outcome, TimeoutOutcome::Reached(true));
+    assert!(
+        service
+            .storage()
+            .get_consensus_result(&scope, proposal_id)
+            .unwrap()
+    );
+}
+
+/// Apply `votes` one by one to a fresh peer that only knows the bare proposal.
+fn finalize_on_fresh_peer(
+    proposal: &hashgraph_like_consensus::protos::consensus::v1::Proposal,
+    votes: &[hashgraph_like_consensus::protos::consensus::v1::Vote],
+    threshold: f64,
+) -> Result<bool, ConsensusError> {
+    let peer = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    peer.scope(&scope)
+        .unwrap()
+        .with_threshold(threshold)
+        .initialize()
+        .unwrap();
+    let mut bare = proposal.clone();
+    bare.votes.clear();
+    bare.round = 1;
+    peer.process_incoming_proposal(&scope, bare, now_ts())
+        .unwrap();
+    for vote in votes {
+        peer.process_incoming_vote(&scope, vote.clone(), now_ts())
+            .unwrap();
+    }
+    peer.storage()
+        .get_consensus_result(&scope, proposal.proposal_id)
+}
+
+#[test]
+fn test_minimal_finalizing_set_finalizes_fresh_session_and_is_minimal() {
+    const THRESHOLD: f64 = 0.5;
+    let owner = PrivateKeySigner::random();
+    let service = make_service_for(&owner);
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let proposal = setup_proposal(
+        &service,
+        &scope,
+        &owner,
+        6,
+        true,
+        ConsensusConfig::gossipsub()
+            .with_threshold(THRESHOLD)
+            .unwrap(),
+    );
+    let proposal_id = proposal.proposal_id;
+
+    // NO, NO, NO, YES, YES, YES: weights stay tied until the last vote, where
+    // the full-turnout tie resolves to YES via liveness.
+    cast_vote_or_panic(&service, &scope, proposal_id, false, owner, "vote 1");
+    for (i, choice) in [false, false, true, true, true].into_iter().enumerate() {
+        cast_vote_or_panic(
+            &service,
+            &scope,
+            proposal_id,
+            choice,
+            PrivateKeySigner::random(),
+            &format!("vote {}", i + 2),
+        );
+    }
+    assert!(
+        service
+            .storage()
+            .get_consensus_result(&scope, proposal_id)
+            .unwrap()
+    );
+
+    // Three YES votes alone meet quorum, with silent peers weighted toward YES.
+    let proposal = service.storage().get_proposal(&scope, proposal_id).unwrap();
+    assert_eq!(proposal.votes.len(), 6);
+    let minimal = service
+        .storage()
+        .minimal_finalizing_set(&scope, proposal_id)
+        .unwrap();
+    assert_eq!(minimal.len(), 3);
+    assert!(minimal.iter().all(|vote| vote.vote));
+
+    assert!(finalize_on_fresh_peer(&proposal, &minimal, THRESHOLD).unwrap());
+    for skip in 0..minimal.len() {
+        let mut reduced = minimal.clone();
+        reduced.remove(skip);
+        assert!(matches!(
+            finalize_on_fresh_peer(&proposal, &reduced, THRESHOLD),
+            Err(ConsensusError::ConsensusNotReached)
+        ));
+    }
+}
+
+#[test]
+fn test_minimal_finalizing_set_requires_reached_consensus() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let owner = PrivateKeySigner::random();
+    let proposal = setup_proposal(
+        &service,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_3,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+
+    let err = service
+        .storage()
+        .minimal_finalizing_set(&scope, proposal.proposal_id)
+        .unwrap_err();
+    assert!(matches!(err, ConsensusError::ConsensusNotReached));
+
+    let err = service
+        .storage()
+        .minimal_finalizing_set(&scope, u64::MAX)
+        .unwrap_err();
+    assert!(matches!(err, ConsensusError::SessionNotFound));
+}
+
+#[test]
+fn test_mutating_ops_on_terminal_sessions_return_session_terminal() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let owner = PrivateKeySigner::random();
+
+    // Finalized: two YES votes plus the silent peer decide n=3.
+    let reached = setup_proposal(
+        &service,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_3,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+    cast_vote_or_panic(
+        &service,
+        &scope,
+        reached.proposal_id,
+        VOTE_YES,
+        owner,
+        "vote 1",
+    );
+    cast_vote_or_panic(
+        &service,
+        &scope,
+        reached.proposal_id,
+        VOTE_YES,
+        PrivateKeySigner::random(),
+        "vote 2",
+    );
+
+    // Failed: weighted tie at timeout.
+    let failed_id = setup_partial_tie(&service, &scope);
+    let late_voter = wrap(PrivateKeySigner::random());
+    let failed = service.storage().get_proposal(&scope, failed_id).unwrap();
+    let late_vote = build_vote(&failed, VOTE_YES, &late_voter, now_ts()).unwrap();
+    assert_eq!(
+        service
+            .handle_consensus_timeout(&scope, failed_id, now_ts())
+            .unwrap(),
+        TimeoutOutcome::Failed
+    );
+
+    let expected = [
+        (reached.proposal_id, ConsensusState::ConsensusReached(true)),
+        (failed_id, ConsensusState::Failed),
+    ];
+    for (proposal_id, state) in expected {
+        let err = service
+            .cast_vote(&scope, proposal_id, VOTE_YES, now_ts())
+            .unwrap_err();
+        assert!(matches!(err, ConsensusError::SessionTerminal { state: s } if s == state));
+
+        let proposal = service.storage().get_proposal(&scope, proposal_id).unwrap();
+        let vote = build_vote(&proposal, VOTE_YES, &late_voter, now_ts()).unwrap();
+        let err = service
+            .process_incoming_vote(&scope, vote, now_ts())
+            .unwrap_err();
+        assert!(matches!(err, ConsensusError::SessionTerminal { state: s } if s == state));
+    }
+
+    // A vote built while the session was active is rejected the same way.
+    let err = service
+        .process_incoming_vote(&scope, late_vote, now_ts())
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        ConsensusError::SessionTerminal {
+            state: ConsensusState::Failed
+        }
+    ));
+}
+
+#[test]
+fn test_rollover_scope_archives_sessions_and_keeps_config() {
+    let service = make_service();
+    let scope = ScopeID::from("rollover_scope");
+
+    service
+        .scope(&scope)
+        .unwrap()
+        .with_threshold(0.75)
+        .initialize()
+        .unwrap();
+
+    let first = setup_proposal(
+        &service,
+        &scope,
+        &PrivateKeySigner::random(),
+        EXPECTED_VOTERS_COUNT_3,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+    let second = setup_proposal(
+        &service,
+        &scope,
+        &PrivateKeySigner::random(),
+        EXPECTED_VOTERS_COUNT_3,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+
+    let snapshot = service
+        .storage()
+        .rollover_scope(&scope)
+        .expect("rollover should succeed");
+
+    let archived: Vec<u64> = snapshot
+        .sessions
+        .iter()
+        .map(|session| session.proposal.proposal_id)
+        .collect();
+    let mut expected = vec![first.proposal_id, second.proposal_id];
+    expected.sort_unstable();
+    assert_eq!(archived, expected);
+    assert_eq!(
+        snapshot
+            .config
+            .expect("snapshot should carry the config")
+            .default_consensus_threshold,
+        0.75
+    );
+
+    // The scope is empty but keeps its config for the next epoch.
+    assert!(
+        service
+            .storage()
+            .list_scope_sessions(&scope)
+            .unwrap()
+            .is_none()
+    );
+    assert_eq!(
+        service
+            .scope(&scope)
+            .unwrap()
+            .get_config()
+            .default_consensus_threshold,
+        0.75
+    );
+
+    // Rolling over an unknown scope yields an empty snapshot.
+    let empty = service
+        .storage()
+        .rollover_scope(&ScopeID::from("rollover_unknown"))
+        .unwrap();
+    assert!(empty.sessions.is_empty());
+    assert!(empty.config.is_none());
+}
+
+#[test]
+fn test_exceeding_session_cap_emits_eviction_events() {
+    const MAX_SESSIONS: usize = 2;
+    let service = DefaultConsensusService::new_with_max_sessions(
+        EthereumConsensusSigner::new(PrivateKeySigner::random()),
+        MAX_SESSIONS,
+    );
+    let scope = ScopeID::from("eviction_scope");
+    let events = service.event_bus().subscribe();
+
+    // Distinct creation times so the trim order is deterministic.
+    let start = now_ts();
+    let ids: Vec<u64> = (0..4)
+        .map(|i| {
+            service
+                .create_proposal(
+                    &scope,
+                    CreateProposalRequest::new(
+                        format!("{PROPOSAL_NAME} {i}"),
+                        PROPOSAL_PAYLOAD,
+                        proposal_owner_from_signer(&PrivateKeySigner::random()),
+                        EXPECTED_VOTERS_COUNT_3,
+                        PROPOSAL_EXPIRATION_TIME,
+                        true,
+                    )
+                    .expect("valid proposal request"),
+                    start + i,
+                )
+                .expect("proposal should be created")
+                .proposal_id
+        })
+        .collect();
+
+    let mut evicted = Vec::new();
+    while let Ok((event_scope, event)) = events.recv_timeout(Duration::from_millis(100)) {
+        assert_eq!(event_scope, scope);
+        if let ConsensusEvent::SessionEvicted {
+            proposal_id,
+            reason,
+            ..
+        } = event
+        {
+            assert_eq!(reason, EvictionReason::CapacityTrim);
+            evicted.push(proposal_id);
+        }
+    }
+    assert_eq!(evicted, ids[..2].to_vec());
+
+    for proposal_id in &ids[..2] {
+        assert!(matches!(
+            service.storage().get_proposal(&scope, *proposal_id),
+            Err(ConsensusError::SessionNotFound)
+        ));
+    }
+    for proposal_id in &ids[2..] {
+        assert!(service.storage().get_proposal(&scope, *proposal_id).is_ok());
+    }
+}
+
+#[test]
+fn test_participation_stats_tracks_rates_across_finalized_proposals() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let steady = PrivateKeySigner::random();
+    let sporadic = PrivateKeySigner::random();
+
+    // Both vote on the first two proposals; only `steady` votes on the third,
+    // a single-voter proposal. A fourth proposal stays active and is ignored.
+    for (expected_voters, steady_choice, sporadic_choice) in [
+        (EXPECTED_VOTERS_COUNT_2, true, Some(true)),
+        (EXPECTED_VOTERS_COUNT_2, false, Some(false)),
+        (EXPECTED_VOTERS_COUNT_1, true, None),
+    ] {
+        let proposal = setup_proposal(
+            &service,
+            &scope,
+            &steady,
+            expected_voters,
+            true,
+            ConsensusConfig::gossipsub(),
+        );
+        let id = proposal.proposal_id;
+        cast_vote_or_panic(
+            &service,
+            &scope,
+            id,
+            steady_choice,
+            steady.clone(),
+            "steady",
+        );
+        if let Some(choice) = sporadic_choice {
+            cast_vote_or_panic(&service, &scope, id, choice, sporadic.clone(), "sporadic");
+        }
+    }
+    let active = setup_proposal(
+        &service,
+        &scope,
+        &steady,
+        EXPECTED_VOTERS_COUNT_2,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+    cast_vote_or_panic(
+        &service,
+        &scope,
+        active.proposal_id,
+        true,
+        sporadic.clone(),
+        "active vote",
+    );
+
+    let stats = service.participation_stats(&scope);
+    assert_eq!(stats.len(), 2);
+
+    let steady_stat = stats[&proposal_owner_from_signer(&steady)];
+    assert_eq!(steady_stat.votes_cast, 3);
+    assert_eq!(steady_stat.finalized_total, 3);
+    assert_eq!(steady_stat.participation_rate(), 1.0);
+    assert_eq!((steady_stat.yes_votes, steady_stat.no_votes), (2, 1));
+
+    let sporadic_stat = stats[&proposal_owner_from_signer(&sporadic)];
+    assert_eq!(sporadic_stat.votes_cast, 2);
+    assert!((sporadic_stat.participation_rate() - 2.0 / 3.0).abs() < f64::EPSILON);
+    assert_eq!(sporadic_stat.yes_ratio(), 0.5);
+
+    assert!(
+        service
+            .participation_stats(&ScopeID::from(SCOPE2_NAME))
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_explain_consensus_pinpoints_missing_votes_on_stuck_proposal() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let owner = PrivateKeySigner::random();
+    let proposal = setup_proposal(
+        &service,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_4,
+        false,
+        ConsensusConfig::gossipsub(),
+    );
+    let proposal_id = proposal.proposal_id;
+    cast_vote_or_panic(&service, &scope, proposal_id, VOTE_YES, owner, "owner vote");
+
+    // One of four voters in: 2n/3 turnout needs three votes.
+    let explanation = service
+        .storage()
+        .explain_consensus(&scope, proposal_id)
+        .unwrap();
+    assert_eq!(explanation.state, ConsensusState::Active);
+    assert_eq!(explanation.required_votes, 3);
+    assert_eq!(explanation.required_choice_votes, 3);
+    assert_eq!(
+        (
+            explanation.yes_votes,
+            explanation.no_votes,
+            explanation.silent_votes
+        ),
+        (1, 0, 3)
+    );
+    assert_eq!((explanation.yes_weight, explanation.no_weight), (1, 3));
+    assert_eq!(explanation.rule, QuorumRule::WeightedMajority);
+    assert_eq!(
+        explanation.verdict,
+        QuorumVerdict::NeedsMoreVotes { missing: 2 }
+    );
+
+    // Turnout met, but silent NO weight cancels the YES lead.
+    cast_vote_or_panic(
+        &service,
+        &scope,
+        proposal_id,
+        VOTE_YES,
+        PrivateKeySigner::random(),
+        "second vote",
+    );
+    cast_vote_or_panic(
+        &service,
+        &scope,
+        proposal_id,
+        false,
+        PrivateKeySigner::random(),
+        "third vote",
+    );
+    let explanation = service
+        .storage()
+        .explain_consensus(&scope, proposal_id)
+        .unwrap();
+    assert_eq!((explanation.yes_weight, explanation.no_weight), (2, 2));
+    assert_eq!(explanation.verdict, QuorumVerdict::NoQualifiedMajority);
+
+    assert!(matches!(
+        service
+            .storage()
+            .explain_consensus(&scope, proposal_id.wrapping_add(1)),
+        Err(ConsensusError::SessionNotFound)
+    ));
+}
+
+#[test]
+fn test_recast_vote_does_not_inflate_distinct_voter_quorum() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let owner = PrivateKeySigner::random();
+    let proposal = setup_proposal(
+        &service,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_4,
+        false,
+        ConsensusConfig::gossipsub(),
+    );
+    let proposal_id = proposal.proposal_id;
+    cast_vote_or_panic(&service, &scope, proposal_id, VOTE_YES, owner, "owner vote");
+    let voter = PrivateKeySigner::random();
+    cast_vote_or_panic(
+        &service,
+        &scope,
+        proposal_id,
+        VOTE_YES,
+        voter.clone(),
+        "first vote",
+    );
+
+    // The recast chains to the voter's earlier vote via parent_hash; a second
+    // event from the same peer must not count toward the 2n/3 turnout.
+    let snapshot = service.storage().get_proposal(&scope, proposal_id).unwrap();
+    let recast = build_vote(&snapshot, VOTE_YES, &wrap(voter), now_ts()).unwrap();
+    assert!(!recast.parent_hash.is_empty());
+    assert!(matches!(
+        service.process_incoming_vote(&scope, recast, now_ts()),
+        Err(ConsensusError::DuplicateVote)
+    ));
+
+    let session = service
+        .storage()
+        .get_session(&scope, proposal_id)
+        .unwrap()
+        .expect("session exists");
+    assert_eq!(session.distinct_voters(), 2);
+    assert_eq!(session.vote_events(), 2);
+    assert_eq!(
+        service
+            .storage()
+            .explain_consensus(&scope, proposal_id)
+            .unwrap()
+            .verdict,
+        QuorumVerdict::NeedsMoreVotes { missing: 1 }
+    );
+}
+
+#[test]
+fn test_consensus_failed_event_carries_reason() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let events = service.event_bus().subscribe();
+    let proposal_id = setup_partial_tie(&service, &scope);
+
+    assert_eq!(
+        service
+            .handle_consensus_timeout(&scope, proposal_id, now_ts())
+            .unwrap(),
+        TimeoutOutcome::Failed
+    );
+
+    let reason = std::iter::from_fn(|| events.recv_timeout(Duration::from_secs(1)).ok())
+        .find_map(|(_, event)| match event {
+            ConsensusEvent::ConsensusFailed {
+                proposal_id: id,
+                reason,
+                ..
+            } if id == proposal_id => Some(reason),
+            _ => None,
+        })
+        .expect("ConsensusFailed event should be emitted");
+    assert!(!reason.is_empty());
+    assert!(reason.contains("tied"), "reason: {reason}");
+}
+
+#[test]
+fn test_finalize_expired_reconciles_stale_sessions_after_restart() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+
+    // n = 3, liveness YES, one YES vote: silent peers carry it at timeout.
+    let owner = PrivateKeySigner::random();
+    let decided = setup_proposal(
+        &service,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_3,
+        true,
+        ConsensusConfig::gossipsub(),
+    )
+    .proposal_id;
+    cast_vote_or_panic(&service, &scope, decided, VOTE_YES, owner, "owner vote");
+    let tied = setup_partial_tie(&service, &scope);
+    let long_lived = service
+        .create_proposal(
+            &scope,
+            CreateProposalRequest::new(
+                PROPOSAL_NAME.to_string(),
+                PROPOSAL_PAYLOAD,
+                proposal_owner_from_signer(&PrivateKeySigner::random()),
+                EXPECTED_VOTERS_COUNT_3,
+                PROPOSAL_EXPIRATION_TIME * 10,
+                true,
+            )
+            .expect("valid proposal request"),
+            now_ts(),
+        )
+        .expect("proposal should be created")
+        .proposal_id;
+
+    // Restart: a fresh service over the same storage, no timers running.
+    let restarted = DefaultConsensusService::new_with_components(
+        service.storage().clone(),
+        BroadcastEventBus::default(),
+        wrap(PrivateKeySigner::random()),
+        10,
+    );
+    drop(service);
+
+    let later = now_ts() + PROPOSAL_EXPIRATION_TIME + 1;
+    let mut expected = vec![(decided, Some(true)), (tied, None)];
+    expected.sort_unstable_by_key(|(id, _)| *id);
+    let sweep = restarted.finalize_expired(&scope, later).unwrap();
+    assert!(sweep.errors.is_empty());
+    assert_eq!(sweep.finalized, expected);
+
+    let storage = restarted.storage();
+    assert!(storage.get_consensus_result(&scope, decided).unwrap());
+    assert!(matches!(
+        storage.get_consensus_result(&scope, tied),
+        Err(ConsensusError::ConsensusFailed)
+    ));
+    assert!(matches!(
+        storage.get_consensus_result(&scope, long_lived),
+        Err(ConsensusError::ConsensusNotReached)
+    ));
+
+    // Already-terminal sessions are not revisited.
+    assert!(
+        restarted
+            .finalize_expired(&scope, later)
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_allow_owner_vote_setting_controls_self_voting() {
+    let owner = PrivateKeySigner::random();
+    let service = DefaultConsensusService::new(wrap(owner.clone()));
+    let open_scope = ScopeID::from(SCOPE1_NAME);
+    let closed_scope = ScopeID::from(SCOPE2_NAME);
+    service
+        .scope(&closed_scope)
+        .unwrap()
+        .with_allow_owner_vote(false)
+        .initialize()
+        .unwrap();
+
+    let create = |scope: &ScopeID| {
+        service
+            .create_proposal(
+                scope,
+                CreateProposalRequest::new(
+                    PROPOSAL_NAME.to_string(),
+                    PROPOSAL_PAYLOAD,
+                    proposal_owner_from_signer(&owner),
+                    EXPECTED_VOTERS_COUNT_4,
+                    PROPOSAL_EXPIRATION_TIME,
+                    true,
+                )
+                .expect("valid proposal request"),
+                now_ts(),
+            )
+            .expect("proposal should be created")
+    };
+
+    // Default: the owner votes like anyone else.
+    let open = create(&open_scope);
+    service
+        .cast_vote(&open_scope, open.proposal_id, VOTE_YES, now_ts())
+        .expect("owner vote allowed by default");
+
+    // Forbidden: both the local and the remote path reject the owner.
+    let closed = create(&closed_scope);
+    assert!(matches!(
+        service.cast_vote(&closed_scope, closed.proposal_id, VOTE_YES, now_ts()),
+        Err(ConsensusError::OwnerCannotVote)
+    ));
+    let owner_vote = build_vote(&closed, VOTE_YES, &wrap(owner), now_ts()).unwrap();
+    assert!(matches!(
+        service.process_incoming_vote(&closed_scope, owner_vote, now_ts()),
+        Err(ConsensusError::OwnerCannotVote)
+    ));
+    cast_vote_or_panic(
+        &service,
+        &closed_scope,
+        closed.proposal_id,
+        VOTE_YES,
+        PrivateKeySigner::random(),
+        "non-owner vote allowed",
+    );
+}
+
+#[test]
+fn test_service_accepts_typed_and_raw_proposal_ids() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let owner = PrivateKeySigner::random();
+    let proposal = setup_proposal(
+        &service,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_3,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+
+    let typed: ProposalId = proposal.id();
+    assert_eq!(typed, proposal.proposal_id.into());
+    let vote = service
+        .cast_vote(&scope, typed, VOTE_YES, now_ts())
+        .unwrap();
+    assert_eq!(vote.for_proposal(), typed);
+    assert_eq!(u64::from(vote.id()), vote.vote_id);
+
+    let raw: u64 = proposal.proposal_id;
+    assert_eq!(
+        service
+            .handle_consensus_timeout(&scope, raw, now_ts())
+            .unwrap(),
+        TimeoutOutcome::Reached(true)
+    );
+}
+
+#[test]
+fn test_vote_flood_beyond_capacity_is_rejected() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let owner = PrivateKeySigner::random();
+    // Unanimity keeps the session undecided while votes keep arriving.
+    let config = ConsensusConfig::gossipsub()
+        .with_threshold(1.0)
+        .unwrap()
+        .with_max_votes_multiplier(1);
+    let proposal = setup_proposal(
+        &service,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_4,
+        true,
+        config,
+    );
+
+    for choice in [VOTE_YES, VOTE_YES, !VOTE_YES, VOTE_YES] {
+        cast_vote_or_panic(
+            &service,
+            &scope,
+            proposal.proposal_id,
+            choice,
+            PrivateKeySigner::random(),
+            "vote within capacity",
+        );
+    }
+
+    for _ in 0..10 {
+        let err = cast_remote_vote(
+            &service,
+            &scope,
+            proposal.proposal_id,
+            !VOTE_YES,
+            &wrap(PrivateKeySigner::random()),
+        )
+        .unwrap_err();
+        assert!(matches!(err, ConsensusError::TooManyVotes { max_votes: 4 }));
+    }
+
+    let explanation = service
+        .storage()
+        .explain_consensus(&scope, proposal.proposal_id)
+        .unwrap();
+    assert_eq!(explanation.state, ConsensusState::Active);
+    assert_eq!(explanation.yes_votes + explanation.no_votes, 4);
+}
+
+#[test]
+fn test_vote_cap_bounds_incoming_proposals() {
+    let owner = PrivateKeySigner::random();
+    let service = make_service_for(&owner);
+    let peer = make_service();
+    let scope = ScopeID::from("vote_cap_scope");
+    peer.scope(&scope)
+        .unwrap()
+        .with_max_votes_per_proposal(2)
+        .initialize()
+        .unwrap();
+
+    // The multiplier alone would allow 10 votes; the absolute cap wins.
+    let proposal = setup_proposal_result(&service, &scope, &owner, EXPECTED_VOTERS_COUNT_5)
+        .expect("proposal should be created");
+    let mut with_two = None;
+    let mut with_three = None;
+    for choice in [VOTE_YES, !VOTE_YES, VOTE_YES] {
+        let updated = cast_remote_vote_and_get_proposal(
+            &service,
+            &scope,
+            proposal.proposal_id,
+            choice,
+            &wrap(PrivateKeySigner::random()),
+        )
+        .expect("vote should be cast");
+        with_two = with_three.replace(updated);
+    }
+    let (with_two, with_three) = (with_two.unwrap(), with_three.unwrap());
+
+    assert!(matches!(
+        peer.process_incoming_proposal(&scope, with_three, now_ts()),
+        Err(ConsensusError::TooManyVotes { max_votes: 2 })
+    ));
+    peer.process_incoming_proposal(&scope, with_two, now_ts())
+        .expect("proposal within the cap is accepted");
+    assert!(matches!(
+        cast_remote_vote(
+            &peer,
+            &scope,
+            proposal.proposal_id,
+            VOTE_YES,
+            &wrap(PrivateKeySigner::random()),
+        ),
+        Err(ConsensusError::TooManyVotes { max_votes: 2 })
+    ));
+}
+
+#[test]
+fn test_timeout_path_runs_on_virtual_time() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let owner = PrivateKeySigner::random();
+
+    // A fixed epoch unrelated to the wall clock: nothing below sleeps.
+    let start = 1_000_000;
+    let proposal_id = service
+        .create_proposal(
+            &scope,
+            CreateProposalRequest::new(
+                PROPOSAL_NAME.to_string(),
+                PROPOSAL_PAYLOAD,
+                proposal_owner_from_signer(&owner),
+                EXPECTED_VOTERS_COUNT_3,
+                PROPOSAL_EXPIRATION_TIME,
+                true,
+            )
+            .expect("valid proposal request"),
+            start,
+        )
+        .expect("proposal should be created")
+        .proposal_id;
+    let proposal = service.storage().get_proposal(&scope, proposal_id).unwrap();
+    let vote = build_vote(&proposal, VOTE_YES, &wrap(owner), start + 1).unwrap();
+    service
+        .process_incoming_vote(&scope, vote, start + 1)
+        .unwrap();
+
+    let expiry = start + PROPOSAL_EXPIRATION_TIME;
+    assert!(
+        service
+            .finalize_expired(&scope, expiry - 1)
+            .unwrap()
+            .is_empty()
+    );
+    assert_eq!(
+        service.finalize_expired(&scope, expiry).unwrap().finalized,
+        vec![(proposal_id, Some(true))]
+    );
+    assert_eq!(
+        service
+            .storage()
+            .consensus_outcome(&scope, proposal_id)
+            .unwrap(),
+        Some(true)
+    );
+}
+
+#[test]
+fn test_reaper_runs_timeouts_on_manually_advanced_clock() {
+    // A fixed epoch unrelated to the wall clock, moved only by the test.
+    let start = 1_000_000;
+    let clock = MockClock::new(start);
+    let service = make_service().with_clock(clock.clone());
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let owner = PrivateKeySigner::random();
+    let proposal_id = service
+        .create_proposal(
+            &scope,
+            CreateProposalRequest::new(
+                PROPOSAL_NAME.to_string(),
+                PROPOSAL_PAYLOAD,
+                proposal_owner_from_signer(&owner),
+                EXPECTED_VOTERS_COUNT_3,
+                PROPOSAL_EXPIRATION_TIME,
+                true,
+            )
+            .expect("valid proposal request"),
+            service.now(),
+        )
+        .expect("proposal should be created")
+        .proposal_id;
+    let proposal = service.storage().get_proposal(&scope, proposal_id).unwrap();
+    let vote = build_vote(&proposal, VOTE_YES, &wrap(owner), start + 1).unwrap();
+    service
+        .process_incoming_vote(&scope, vote, start + 1)
+        .unwrap();
+    let events = service.event_bus().subscribe();
+    let reaper = service.spawn_reaper(Duration::from_millis(10));
+
+    // One second short of expiry, neither a sweep nor the reaper finalizes.
+    clock.set(start + PROPOSAL_EXPIRATION_TIME - 1);
+    assert!(
+        service
+            .finalize_all_expired(service.now())
+            .unwrap()
+            .is_empty()
+    );
+    assert!(events.recv_timeout(Duration::from_millis(100)).is_err());
+
+    // The tick after the clock reaches expiry finalizes it.
+    clock.advance(Duration::from_secs(1));
+    let (_, event) = events
+        .recv_timeout(Duration::from_secs(5))
+        .expect("reaper finalizes once the clock reaches expiry");
+    assert!(matches!(
+        event,
+        ConsensusEvent::ConsensusReached { proposal_id: id, result: true, timestamp }
+            if id == proposal_id && timestamp == start + PROPOSAL_EXPIRATION_TIME
+    ));
+    reaper.abort();
+    assert!(
+        service
+            .finalize_all_expired(service.now())
+            .unwrap()
+            .is_empty()
+    );
+}
+
+#[test]
+fn test_accept_late_votes_scope_policy() {
+    let service = make_service();
+    let recording_scope = ScopeID::from(SCOPE1_NAME);
+    let rejecting_scope = ScopeID::from(SCOPE2_NAME);
+    service
+        .scope(&recording_scope)
+        .unwrap()
+        .with_accept_late_votes(true)
+        .initialize()
+        .unwrap();
+
+    for scope in [&recording_scope, &rejecting_scope] {
+        let owner = PrivateKeySigner::random();
+        // No explicit config, so the scope's late-vote policy applies.
+        let proposal_id = service
+            .create_proposal(
+                scope,
+                CreateProposalRequest::new(
+                    PROPOSAL_NAME.to_string(),
+                    PROPOSAL_PAYLOAD,
+                    proposal_owner_from_signer(&owner),
+                    EXPECTED_VOTERS_COUNT_3,
+                    PROPOSAL_EXPIRATION_TIME,
+                    true,
+                )
+                .expect("valid proposal request"),
+                now_ts(),
+            )
+            .expect("proposal should be created")
+            .proposal_id;
+        cast_vote_or_panic(&service, scope, proposal_id, VOTE_YES, owner, "owner vote");
+        cast_vote_or_panic(
+            &service,
+            scope,
+            proposal_id,
+            VOTE_YES,
+            PrivateKeySigner::random(),
+            "deciding vote",
+        );
+        assert!(
+            service
+                .storage()
+                .get_consensus_result(scope, proposal_id)
+                .unwrap()
+        );
+
+        let late_voter = PrivateKeySigner::random();
+        let late = cast_remote_vote(
+            &service,
+            scope,
+            proposal_id,
+            !VOTE_YES,
+            &wrap(late_voter.clone()),
+        );
+        let session = service
+            .storage()
+            .get_session(scope, proposal_id)
+            .unwrap()
+            .unwrap();
+        if scope == &recording_scope {
+            late.expect("late vote should be recorded");
+            assert_eq!(session.late_votes.len(), 1);
+            assert_eq!(
+                service
+                    .participation_stats(scope)
+                    .get(late_voter.address().as_slice())
+                    .map(|stat| stat.no_votes),
+                Some(1)
+            );
+        } else {
+            assert!(matches!(
+                late.unwrap_err(),
+                ConsensusError::SessionTerminal { .. }
+            ));
+            assert!(session.late_votes.is_empty());
+        }
+
+        // Either way the decision and the counted votes are untouched.
+        assert_eq!(session.votes().len(), 2);
+        assert_eq!(session.proposal.votes.len(), 2);
+        assert!(
+            service
+                .storage()
+                .get_consensus_result(scope, proposal_id)
+                .unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_session_annotations_survive_vote_processing() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let owner = PrivateKeySigner::random();
+    let proposal = setup_proposal(
+        &service,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_3,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+    let proposal_id = proposal.proposal_id;
+
+    service
+        .annotate(&scope, proposal_id, "correlation_id", "req-42")
+        .unwrap();
+    service
+        .annotate(&scope, proposal_id, "topic", "/governance/1")
+        .unwrap();
+    assert!(matches!(
+        service.annotate(
+            &scope,
+            proposal_id,
+            "oversized",
+            "x".repeat(MAX_ANNOTATION_LEN + 1)
+        ),
+        Err(ConsensusError::AnnotationLimitExceeded)
+    ));
+
+    cast_vote_or_panic(&service, &scope, proposal_id, VOTE_YES, owner, "owner vote");
+    cast_vote_or_panic(
+        &service,
+        &scope,
+        proposal_id,
+        VOTE_YES,
+        PrivateKeySigner::random(),
+        "second vote",
+    );
+    assert!(
+        service
+            .storage()
+            .get_consensus_result(&scope, proposal_id)
+            .unwrap()
+    );
+
+    let annotations = service.get_annotations(&scope, proposal_id).unwrap();
+    assert_eq!(annotations.len(), 2);
+    assert_eq!(annotations["correlation_id"], "req-42");
+    assert_eq!(annotations["topic"], "/governance/1");
+}
+
+#[test]
+fn test_can_vote_runs_cast_vote_prechecks_without_voting() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let owner = PrivateKeySigner::random();
+    let proposal_id = setup_proposal(
+        &service,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_4,
+        true,
+        ConsensusConfig::gossipsub(),
+    )
+    .proposal_id;
+    let owner_identity = proposal_owner_from_signer(&owner);
+
+    service
+        .can_vote(&scope, proposal_id, &owner_identity, now_ts())
+        .expect("fresh voter may vote");
+    assert!(
+        service
+            .storage()
+            .get_proposal(&scope, proposal_id)
+            .unwrap()
+            .votes
+            .is_empty(),
+        "can_vote must not cast anything"
+    );
+
+    cast_vote_or_panic(&service, &scope, proposal_id, VOTE_YES, owner, "owner vote");
+    assert!(matches!(
+        service.can_vote(&scope, proposal_id, &owner_identity, now_ts()),
+        Err(ConsensusError::UserAlreadyVoted)
+    ));
+    assert!(matches!(
+        service.can_vote(
+            &scope,
+            proposal_id,
+            &owner_identity[1..],
+            now_ts() + PROPOSAL_EXPIRATION_TIME + 1
+        ),
+        Err(ConsensusError::ProposalExpired)
+    ));
+}
+
+#[test]
+fn test_evicted_proposal_id_cannot_be_reused_within_window() {
+    let reuse = |window: Duration| {
+        let owner = PrivateKeySigner::random();
+        let service = DefaultConsensusService::new_with_max_sessions(wrap(owner.clone()), 1)
+            .with_proposal_id_reuse_window(window);
+        let scope = ScopeID::from(SCOPE1_NAME);
+        let finalized = setup_proposal(
+            &service,
+            &scope,
+            &owner,
+            EXPECTED_VOTERS_COUNT_1,
+            true,
+            ConsensusConfig::gossipsub(),
+        );
+        let finalized = service
+            .cast_vote_and_get_proposal(&scope, finalized.proposal_id, VOTE_YES, now_ts())
+            .unwrap();
+        assert!(
+            service
+                .storage()
+                .get_consensus_result(&scope, finalized.proposal_id)
+                .unwrap()
+        );
+
+        // Capacity 1: a newer proposal evicts the finalized one.
+        let request = CreateProposalRequest::new(
+            PROPOSAL_NAME.to_string(),
+            PROPOSAL_PAYLOAD,
+            proposal_owner_from_signer(&PrivateKeySigner::random()),
+            EXPECTED_VOTERS_COUNT_1,
+            PROPOSAL_EXPIRATION_TIME,
+            true,
+        )
+        .expect("valid proposal request");
+        service
+            .create_proposal(&scope, request, now_ts() + 1)
+            .expect("proposal should be created");
+        assert!(matches!(
+            service
+                .storage()
+                .get_proposal(&scope, finalized.proposal_id),
+            Err(ConsensusError::SessionNotFound)
+        ));
+        service.process_incoming_proposal(&scope, finalized, now_ts())
+    };
+
+    assert!(matches!(
+        reuse(Duration::from_secs(PROPOSAL_EXPIRATION_TIME)),
+        Err(ConsensusError::ProposalIdRecentlyUsed)
+    ));
+    reuse(Duration::ZERO).expect("reuse allowed when the window is disabled");
+}
+
+#[test]
+fn test_removed_proposal_id_cannot_be_reused_within_window() {
+    let owner = PrivateKeySigner::random();
+    let service = make_service_for(&owner)
+        .with_proposal_id_reuse_window(Duration::from_secs(PROPOSAL_EXPIRATION_TIME));
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let finalize = || {
+        let proposal = setup_proposal(
+            &service,
+            &scope,
+            &owner,
+            EXPECTED_VOTERS_COUNT_1,
+            true,
+            ConsensusConfig::gossipsub(),
+        );
+        service
+            .cast_vote_and_get_proposal(&scope, proposal.proposal_id, VOTE_YES, now_ts())
+            .unwrap()
+    };
+
+    let removed = finalize();
+    service
+        .remove_proposal(&scope, removed.proposal_id, now_ts())
+        .unwrap()
+        .expect("proposal removed");
+    let cleared = finalize();
+    service.clear_scope(&scope, now_ts()).unwrap();
+
+    // A stale copy of either purged proposal must not come back under its ID.
+    for stale in [removed, cleared] {
+        assert!(matches!(
+            service.process_incoming_proposal(&scope, stale, now_ts()),
+            Err(ConsensusError::ProposalIdRecentlyUsed)
+        ));
+    }
+}
+
+#[test]
+fn test_diff_snapshots_reports_missing_vote_and_proposal() {
+    let owner = PrivateKeySigner::random();
+    let node_a = make_service_for(&owner);
+    let node_b = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+
+    let shared = setup_proposal(
+        &node_a,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_3,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+    cast_vote_or_panic(
+        &node_a,
+        &scope,
+        shared.proposal_id,
+        VOTE_YES,
+        owner,
+        "owner vote",
+    );
+    let gossiped = node_a
+        .storage()
+        .get_proposal(&scope, shared.proposal_id)
+        .unwrap();
+    node_b
+        .process_incoming_proposal(&scope, gossiped, now_ts())
+        .unwrap();
+
+    // node_b never hears the second vote, nor the extra proposal.
+    let late_voter = PrivateKeySigner::random();
+    cast_vote_or_panic(
+        &node_a,
+        &scope,
+        shared.proposal_id,
+        VOTE_YES,
+        late_voter.clone(),
+        "second vote",
+    );
+    let extra = setup_proposal(
+        &node_a,
+        &scope,
+        &PrivateKeySigner::random(),
+        EXPECTED_VOTERS_COUNT_3,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+
+    let snapshot_a = node_a.storage().rollover_scope(&scope).unwrap();
+    let snapshot_b = node_b.storage().rollover_scope(&scope).unwrap();
+    let diff = diff_snapshots(&snapshot_a, &snapshot_b);
+
+    assert_eq!(diff.only_in_a, vec![extra.proposal_id]);
+    assert!(diff.only_in_b.is_empty());
+    assert_eq!(diff.changed.len(), 1);
+    let changed = &diff.changed[0];
+    assert_eq!(changed.proposal_id, shared.proposal_id);
+    assert_eq!(changed.state_a, ConsensusState::ConsensusReached(true));
+    assert_eq!(changed.state_b, ConsensusState::Active);
+    assert_eq!(
+        changed.votes_only_in_a,
+        vec![proposal_owner_from_signer(&late_voter)]
+    );
+    assert!(changed.votes_only_in_b.is_empty());
+    assert!(changed.votes_differ.is_empty());
+
+    assert!(diff_snapshots(&snapshot_b, &snapshot_b).is_empty());
+}
+
+#[test]
+fn test_validation_cost_scales_with_votes_and_enforces_budget() {
+    let owner = PrivateKeySigner::random();
+    let node = make_service_for(&owner);
+    let peer = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    peer.scope(&scope)
+        .unwrap()
+        .with_max_validation_cost(3)
+        .initialize()
+        .unwrap();
+
+    let mut proposal = setup_proposal(
+        &node,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_5,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+    assert_eq!(
+        estimate_validation_cost(&proposal),
+        ValidationCost {
+            signature_verifications: 1,
+            chain_checks: 0,
+        }
+    );
+    for votes in 1..=3 {
+        proposal = cast_remote_vote_and_get_proposal(
+            &node,
+            &scope,
+            proposal.proposal_id,
+            VOTE_YES,
+            &wrap(PrivateKeySigner::random()),
+        )
+        .unwrap();
+        assert_eq!(
+            estimate_validation_cost(&proposal),
+            ValidationCost {
+                signature_verifications: votes + 1,
+                chain_checks: votes - 1,
+            }
+        );
+        if votes == 2 {
+            // Proposal signature plus two votes: exactly the budget.
+            let fits = make_service();
+            fits.scope(&scope)
+                .unwrap()
+                .with_max_validation_cost(3)
+                .initialize()
+                .unwrap();
+            fits.process_incoming_proposal(&scope, proposal.clone(), now_ts())
+                .expect("proposal within the budget is accepted");
+        }
+    }
+
+    assert!(matches!(
+        peer.process_incoming_proposal(&scope, proposal, now_ts()),
+        Err(ConsensusError::TooManyVotes { max_votes: 3 })
+    ));
+}
+
+#[test]
+fn test_paused_scope_accumulates_votes_and_finalizes_on_resume() {
+    let service = make_service();
+    let events = service.event_bus().subscribe();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let owner = PrivateKeySigner::random();
+    let proposal = setup_proposal(
+        &service,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_3,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+
+    service.pause_scope(&scope).unwrap();
+    // The pause is not part of the scope config, so rewriting it keeps the scope paused.
+    service
+        .scope(&scope)
+        .unwrap()
+        .with_threshold(0.75)
+        .update()
+        .unwrap();
+    assert!(service.storage().is_scope_paused(&scope).unwrap());
+    cast_vote_or_panic(
+        &service,
+        &scope,
+        proposal.proposal_id,
+        VOTE_YES,
+        owner,
+        "owner vote",
+    );
+    for _ in 0..2 {
+        cast_remote_vote(
+            &service,
+            &scope,
+            proposal.proposal_id,
+            VOTE_YES,
+            &wrap(PrivateKeySigner::random()),
+        )
+        .expect("vote while paused");
+    }
+    let session = service
+        .storage()
+        .get_session(&scope, proposal.proposal_id)
+        .unwrap()
+        .unwrap();
+    assert_eq!(session.votes().len(), 3);
+    assert_eq!(session.state, ConsensusState::Active);
+    assert!(matches!(
+        service.handle_consensus_timeout(&scope, proposal.proposal_id, now_ts()),
+        Err(ConsensusError::ScopePaused)
+    ));
+    assert!(events.try_recv().is_err(), "paused scope must not finalize");
+
+    service.resume_scope(&scope, now_ts()).unwrap();
+    assert!(
+        service
+            .storage()
+            .get_consensus_result(&scope, proposal.proposal_id)
+            .unwrap()
+    );
+    let received: Vec<_> = events.try_iter().collect();
+    assert_eq!(received.len(), 1);
+    assert!(matches!(
+        &received[0].1,
+        ConsensusEvent::ConsensusReached { proposal_id, result: true, .. }
+            if *proposal_id == proposal.proposal_id
+    ));
+}
+
+#[test]
+fn test_list_reached_proposals_is_ordered_by_finalization() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let start = now_ts();
+
+    let mut proposals = Vec::new();
+    for _ in 0..4 {
+        let owner = PrivateKeySigner::random();
+        let proposal = service
+            .create_proposal(
+                &scope,
+                CreateProposalRequest::new(
+                    PROPOSAL_NAME.to_string(),
+                    PROPOSAL_PAYLOAD,
+                    proposal_owner_from_signer(&owner),
+                    EXPECTED_VOTERS_COUNT_1,
+                    PROPOSAL_EXPIRATION_TIME,
+                    true,
+                )
+                .expect("valid proposal request"),
+                start,
+            )
+            .expect("proposal should be created");
+        proposals.push((proposal, owner));
+    }
+
+    // Finalize in reverse creation order; the first two share a second.
+    let finalize_times = [start + 3, start + 2, start + 1, start + 1];
+    for ((proposal, owner), at) in proposals.iter().zip(finalize_times) {
+        let vote = build_vote(proposal, VOTE_YES, &wrap(owner.clone()), at).unwrap();
+        service.process_incoming_vote(&scope, vote, at).unwrap();
+    }
+
+    let ids: Vec<u64> = proposals.iter().map(|(p, _)| p.proposal_id).collect();
+    let (tied_first, tied_second) = if ids[2] < ids[3] {
+        (ids[2], ids[3])
+    } else {
+        (ids[3], ids[2])
+    };
+    let expected = vec![
+        (tied_first, true),
+        (tied_second, true),
+        (ids[1], true),
+        (ids[0], true),
+    ];
+    for _ in 0..3 {
+        assert_eq!(
+            service.storage().list_reached_proposals(&scope).unwrap(),
+            expected
+        );
+    }
+    assert_eq!(
+        service
+            .storage()
+            .get_reached_proposals(&scope)
+            .unwrap()
+            .len(),
+        4
+    );
+}
+
+#[test]
+fn test_verify_scope_integrity_flags_corrupted_session() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let mut proposal_ids = Vec::new();
+    for _ in 0..2 {
+        let owner = PrivateKeySigner::random();
+        let proposal = setup_proposal(
+            &service,
+            &scope,
+            &owner,
+            EXPECTED_VOTERS_COUNT_2,
+            true,
+            ConsensusConfig::gossipsub(),
+        );
+        cast_vote_or_panic(
+            &service,
+            &scope,
+            proposal.proposal_id,
+            VOTE_YES,
+            owner,
+            "owner",
+        );
+        cast_vote_or_panic(
+            &service,
+            &scope,
+            proposal.proposal_id,
+            VOTE_YES,
+            PrivateKeySigner::random(),
+            "second",
+        );
+        proposal_ids.push(proposal.proposal_id);
+    }
+
+    let clean = service.verify_scope_integrity(&scope).unwrap();
+    assert_eq!(clean.sessions_checked, 2);
+    assert!(clean.is_clean(), "unexpected issues: {:?}", clean.issues);
+
+    // Flip the stored result of the first session and break a vote signature
+    // in the second.
+    service
+        .storage()
+        .update_session(&scope, proposal_ids[0], |session| {
+            session.state = ConsensusState::ConsensusReached(false);
+            Ok(())
+        })
+        .unwrap();
+    service
+        .storage()
+        .update_session(&scope, proposal_ids[1], |session| {
+            session.proposal.votes[0].signature[0] ^= 0xff;
+            Ok(())
+        })
+        .unwrap();
+
+    let report = service.verify_scope_integrity(&scope).unwrap();
+    assert_eq!(report.issues.len(), 2);
+    assert!(report.issues.iter().any(|issue| matches!(
+        issue,
+        IntegrityIssue::ResultMismatch {
+            proposal_id,
+            stored: ConsensusState::ConsensusReached(false),
+            recomputed: Some(true),
+        } if *proposal_id == proposal_ids[0]
+    )));
+    assert!(report.issues.iter().any(|issue| matches!(
+        issue,
+        IntegrityIssue::InvalidVotes { proposal_id, .. } if *proposal_id == proposal_ids[1]
+    )));
+}
+
+#[test]
+fn test_apply_votes_and_status_reports_each_vote_and_final_state() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let owner = PrivateKeySigner::random();
+    let proposal = setup_proposal(
+        &service,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_3,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+    let proposal_id = proposal.proposal_id;
+    let owner_vote = build_vote(&proposal, VOTE_YES, &wrap(owner), now_ts()).unwrap();
+
+    let (results, status) = service
+        .apply_votes_and_status(&scope, proposal_id, vec![owner_vote.clone()], now_ts())
+        .unwrap();
+    assert!(matches!(results[..], [VoteApplyResult::Accepted]));
+    assert_eq!(status.state, ConsensusState::Active);
+    assert_eq!(status.distinct_voters, 1);
+
+    let proposal = service.storage().get_proposal(&scope, proposal_id).unwrap();
+    let new_vote = build_vote(
+        &proposal,
+        VOTE_YES,
+        &wrap(PrivateKeySigner::random()),
+        now_ts(),
+    )
+    .unwrap();
+    let mut invalid = build_vote(
+        &proposal,
+        VOTE_YES,
+        &wrap(PrivateKeySigner::random()),
+        now_ts(),
+    )
+    .unwrap();
+    invalid.vote = !invalid.vote;
+    let mut foreign = new_vote.clone();
+    foreign.proposal_id = proposal_id.wrapping_add(1);
+
+    let (results, status) = service
+        .apply_votes_and_status(
+            &scope,
+            proposal_id,
+            vec![owner_vote, invalid, foreign, new_vote],
+            now_ts(),
+        )
+        .unwrap();
+    assert!(matches!(
+        results[..],
+        [
+            VoteApplyResult::Duplicate,
+            VoteApplyResult::Rejected(ConsensusError::InvalidVoteHash),
+            VoteApplyResult::Rejected(ConsensusError::VoteProposalIdMismatch),
+            VoteApplyResult::Accepted,
+        ]
+    ));
+    assert_eq!(
+        status,
+        ConsensusStatus {
+            state: ConsensusState::ConsensusReached(true),
+            distinct_voters: 2,
+            expected_voters: EXPECTED_VOTERS_COUNT_3,
+        }
+    );
+}
+
+#[test]
+fn test_finalized_session_outlives_expiry_until_ttl() {
+    let service = make_service();
+    let events = service.event_bus().subscribe();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let ttl = Duration::from_secs(3600);
+    service
+        .scope(&scope)
+        .unwrap()
+        .with_session_ttl(ttl)
+        .initialize()
+        .unwrap();
+
+    let start = now_ts();
+    let owner = PrivateKeySigner::random();
+    let proposal = service
+        .create_proposal(
+            &scope,
+            CreateProposalRequest::new(
+                PROPOSAL_NAME.to_string(),
+                PROPOSAL_PAYLOAD,
+                proposal_owner_from_signer(&owner),
+                EXPECTED_VOTERS_COUNT_1,
+                PROPOSAL_EXPIRATION_TIME,
+                true,
+            )
+            .expect("valid proposal request"),
+            start,
+        )
+        .expect("proposal should be created");
+    let proposal_id = proposal.proposal_id;
+    let vote = build_vote(&proposal, VOTE_YES, &wrap(owner), start + 1).unwrap();
+    service
+        .process_incoming_vote(&scope, vote, start + 1)
+        .unwrap();
+    let _ = events.try_iter().count();
+
+    // Well past the proposal's expiry, the finalized session is still there.
+    let after_expiry = start + PROPOSAL_EXPIRATION_TIME * 10;
+    assert!(
+        service
+            .gc_finalized_sessions(&scope, after_expiry)
+            .unwrap()
+            .is_empty()
+    );
+    assert_eq!(
+        service
+            .storage()
+            .consensus_outcome(&scope, proposal_id)
+            .unwrap(),
+        Some(true)
+    );
+
+    let ttl_end = start + 1 + ttl.as_secs();
+    assert!(
+        service
+            .gc_finalized_sessions(&scope, ttl_end - 1)
+            .unwrap()
+            .is_empty()
+    );
+    assert_eq!(
+        service.gc_finalized_sessions(&scope, ttl_end).unwrap(),
+        vec![proposal_id]
+    );
+    assert!(
+        service
+            .storage()
+            .get_session(&scope, proposal_id)
+            .unwrap()
+            .is_none()
+    );
+    assert!(matches!(
+        events.try_recv().unwrap().1,
+        ConsensusEvent::SessionEvicted {
+            proposal_id: evicted,
+            reason: EvictionReason::TtlExpired,
+            ..
+        } if evicted == proposal_id
+    ));
+}
+
+#[test]
+fn test_many_votes_publish_a_single_outcome_event() {
+    let service = make_service();
+    let events = service.event_bus().subscribe();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let owner = PrivateKeySigner::random();
+    let proposal = setup_proposal(
+        &service,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_5,
+        true,
+        ConsensusConfig::p2p(),
+    );
+
+    cast_vote_or_panic(
+        &service,
+        &scope,
+        proposal.proposal_id,
+        VOTE_YES,
+        owner,
+        "owner",
+    );
+    for _ in 0..3 {
+        cast_remote_vote(
+            &service,
+            &scope,
+            proposal.proposal_id,
+            VOTE_YES,
+            &wrap(PrivateKeySigner::random()),
+        )
+        .expect("vote");
+    }
+
+    let session = service
+        .storage()
+        .get_session(&scope, proposal.proposal_id)
+        .unwrap()
+        .unwrap();
+    assert_eq!(session.distinct_voters(), 4);
+    let received: Vec<_> = events.try_iter().collect();
+    assert_eq!(received.len(), 1, "votes themselves are not published");
+    assert!(matches!(
+        received[0].1,
+        ConsensusEvent::ConsensusReached { result: true, .. }
+    ));
+}
+
+#[test]
+fn test_simulate_finalize_with_does_not_mutate_session() {
+    let service = make_service();
+    let events = service.event_bus().subscribe();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let owner = PrivateKeySigner::random();
+    let proposal = setup_proposal(
+        &service,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_5,
+        true,
+        ConsensusConfig::p2p(),
+    );
+    cast_vote_or_panic(
+        &service,
+        &scope,
+        proposal.proposal_id,
+        VOTE_YES,
+        owner,
+        "owner",
+    );
+    cast_remote_vote(
+        &service,
+        &scope,
+        proposal.proposal_id,
+        !VOTE_YES,
+        &wrap(PrivateKeySigner::random()),
+    )
+    .expect("dissenting vote");
+
+    let signers: Vec<_> = (0..3).map(|_| wrap(PrivateKeySigner::random())).collect();
+    assert_eq!(
+        service
+            .simulate_finalize_with(
+                &scope,
+                proposal.proposal_id,
+                &signers[..1],
+                VOTE_YES,
+                now_ts()
+            )
+            .unwrap(),
+        SessionTransition::StillActive
+    );
+    assert_eq!(
+        service
+            .simulate_finalize_with(&scope, proposal.proposal_id, &signers, VOTE_YES, now_ts())
+            .unwrap(),
+        SessionTransition::ConsensusReached(true)
+    );
+
+    let session = service
+        .storage()
+        .get_session(&scope, proposal.proposal_id)
+        .unwrap()
+        .unwrap();
+    assert_eq!(session.state, ConsensusState::Active);
+    assert_eq!(session.distinct_voters(), 2);
+    assert!(events.try_recv().is_err());
+}
+
+#[test]
+fn test_certified_event_carries_verifiable_deciding_votes() {
+    let service = make_service().with_certified_events(true);
+    let events = service.event_bus().subscribe();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let owner = PrivateKeySigner::random();
+    let proposal = setup_proposal(
+        &service,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_3,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+    cast_vote_or_panic(
+        &service,
+        &scope,
+        proposal.proposal_id,
+        VOTE_YES,
+        owner,
+        "owner",
+    );
+    cast_vote_or_panic(
+        &service,
+        &scope,
+        proposal.proposal_id,
+        VOTE_YES,
+        PrivateKeySigner::random(),
+        "deciding vote",
+    );
+
+    // Evicting the session afterwards must not matter: the event is self-contained.
+    service
+        .storage()
+        .remove_session(&scope, proposal.proposal_id)
+        .unwrap();
+
+    let (_, event) = events.try_recv().expect("consensus event");
+    assert!(event.is_terminal());
+    let ConsensusEvent::ConsensusCertified {
+        proposal_id,
+        result: true,
+        certificate,
+        ..
+    } = event
+    else {
+        panic!("expected a certified event, got {event:?}");
+    };
+    assert_eq!(proposal_id, proposal.proposal_id);
+    assert_eq!(certificate.deciding_votes().len(), 2);
+    assert!(!certificate.decided_at_timeout);
+    certificate
+        .verify::<EthereumConsensusSigner>()
+        .expect("certificate verifies offline");
+
+    let mut forged = (*certificate).clone();
+    forged.result = false;
+    assert!(matches!(
+        forged.verify::<EthereumConsensusSigner>(),
+        Err(ConsensusError::CertificateMismatch)
+    ));
+    let mut tampered = *certificate;
+    tampered.proposal.votes[1].vote = false;
+    assert!(tampered.verify::<EthereumConsensusSigner>().is_err());
+}
+
+#[test]
+fn test_certified_event_verifies_after_vote_change() {
+    let service = make_service().with_certified_events(true);
+    let events = service.event_bus().subscribe();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let proposal = setup_proposal(
+        &service,
+        &scope,
+        &PrivateKeySigner::random(),
+        EXPECTED_VOTERS_COUNT_3,
+        true,
+        ConsensusConfig::gossipsub().with_allow_vote_changes(true),
+    );
+    let id = proposal.proposal_id;
+    let voter = wrap(PrivateKeySigner::random());
+    cast_remote_vote(&service, &scope, id, !VOTE_YES, &voter).expect("vote");
+    service
+        .recast_vote(&scope, id, VOTE_YES, &voter, now_ts())
+        .expect("vote change");
+    cast_remote_vote(
+        &service,
+        &scope,
+        id,
+        VOTE_YES,
+        &wrap(PrivateKeySigner::random()),
+    )
+    .expect("deciding vote");
+
+    let (_, event) = events.try_recv().expect("consensus event");
+    let ConsensusEvent::ConsensusCertified {
+        result: true,
+        certificate,
+        ..
+    } = event
+    else {
+        panic!("expected a certified YES event, got {event:?}");
+    };
+    assert_eq!(certificate.deciding_votes().len(), 3);
+    certificate
+        .verify::<EthereumConsensusSigner>()
+        .expect("certificate with a vote change verifies offline");
+
+    // Without vote changes in the config, the owner's second vote is a duplicate.
+    let mut fixed = *certificate;
+    fixed.config = fixed.config.with_allow_vote_changes(false);
+    assert!(matches!(
+        fixed.verify::<EthereumConsensusSigner>(),
+        Err(ConsensusError::DuplicateVote)
+    ));
+}
+
+#[test]
+fn test_certified_timeout_event_matches_stored_session() {
+    let service = make_service().with_certified_events(true);
+    let events = service.event_bus().subscribe();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let owner = PrivateKeySigner::random();
+    let proposal = setup_proposal(
+        &service,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_3,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+    cast_vote_or_panic(
+        &service,
+        &scope,
+        proposal.proposal_id,
+        VOTE_YES,
+        owner,
+        "owner",
+    );
+
+    let outcome = service
+        .handle_consensus_timeout(&scope, proposal.proposal_id, proposal.expiration_timestamp)
+        .unwrap();
+    assert_eq!(outcome, TimeoutOutcome::Reached(true));
+
+    let (_, event) = events.try_recv().expect("consensus event");
+    let ConsensusEvent::ConsensusCertified { certificate, .. } = event else {
+        panic!("expected a certified event, got {event:?}");
+    };
+    assert!(certificate.decided_at_timeout);
+    certificate
+        .verify::<EthereumConsensusSigner>()
+        .expect("timeout certificate verifies offline");
+    let session = service
+        .storage()
+        .get_session(&scope, proposal.proposal_id)
+        .unwrap()
+        .unwrap();
+    assert_eq!(certificate.proposal, session.proposal);
+    assert_eq!(certificate.config, session.config);
+}
+
+#[test]
+fn test_retract_vote_only_removes_unreferenced_latest_vote() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let owner = PrivateKeySigner::random();
+    let proposal = setup_proposal(
+        &service,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_4,
+        true,
+        ConsensusConfig::p2p(),
+    );
+    let first = wrap(PrivateKeySigner::random());
+    let second = wrap(PrivateKeySigner::random());
+    let id = proposal.proposal_id;
+    cast_remote_vote(&service, &scope, id, VOTE_YES, &first).expect("first vote");
+    cast_remote_vote(&service, &scope, id, !VOTE_YES, &second).expect("second vote");
+
+    let err = service
+        .retract_vote(&scope, id, &first, now_ts())
+        .unwrap_err();
+    assert!(matches!(err, ConsensusError::VoteReferenced));
+    let err = service
+        .retract_vote(&scope, id, &wrap(PrivateKeySigner::random()), now_ts())
+        .unwrap_err();
+    assert!(matches!(err, ConsensusError::VoteNotFound));
+
+    service
+        .retract_vote(&scope, id, &second, now_ts())
+        .expect("latest vote is retractable");
+    let session = service.storage().get_session(&scope, id).unwrap().unwrap();
+    assert_eq!(session.distinct_voters(), 1);
+    assert_eq!(session.vote_events(), 1);
+    assert_eq!(session.proposal.round, 2);
+    assert!(service.verify_scope_integrity(&scope).unwrap().is_clean());
+
+    // The retracted voter may vote again, chaining onto the remaining vote.
+    cast_remote_vote(&service, &scope, id, VOTE_YES, &second).expect("re-vote");
+    let third = wrap(PrivateKeySigner::random());
+    cast_remote_vote(&service, &scope, id, VOTE_YES, &third).expect("third vote");
+    assert_eq!(
+        service.storage().get_consensus_result(&scope, id).unwrap(),
+        VOTE_YES
+    );
+    let err = service
+        .retract_vote(&scope, id, &third, now_ts())
+        .unwrap_err();
+    assert!(matches!(err, ConsensusError::SessionTerminal { .. }));
+}
+
+#[test]
+fn test_recast_vote_replaces_choice_when_scope_allows_changes() {
+    let service = make_service();
+    let changing_scope = ScopeID::from(SCOPE1_NAME);
+    let fixed_scope = ScopeID::from(SCOPE2_NAME);
+    service
+        .scope(&changing_scope)
+        .unwrap()
+        .with_allow_vote_changes(true)
+        .initialize()
+        .unwrap();
+    let create = |scope: &ScopeID| {
+        service
+            .create_proposal(
+                scope,
+                CreateProposalRequest::new(
+                    PROPOSAL_NAME.to_string(),
+                    PROPOSAL_PAYLOAD,
+                    proposal_owner_from_signer(&PrivateKeySigner::random()),
+                    EXPECTED_VOTERS_COUNT_5,
+                    PROPOSAL_EXPIRATION_TIME,
+                    true,
+                )
+                .expect("valid proposal request"),
+                now_ts(),
+            )
+            .expect("proposal should be created")
+            .proposal_id
+    };
+    let voter = wrap(PrivateKeySigner::random());
+
+    let fixed_id = create(&fixed_scope);
+    cast_remote_vote(&service, &fixed_scope, fixed_id, VOTE_YES, &voter).expect("vote");
+    let err = service
+        .recast_vote(&fixed_scope, fixed_id, !VOTE_YES, &voter, now_ts())
+        .unwrap_err();
+    assert!(matches!(err, ConsensusError::UserAlreadyVoted));
+
+    let id = create(&changing_scope);
+    let err = service
+        .recast_vote(&changing_scope, id, !VOTE_YES, &voter, now_ts())
+        .unwrap_err();
+    assert!(matches!(err, ConsensusError::VoteNotFound));
+    cast_remote_vote(&service, &changing_scope, id, VOTE_YES, &voter).expect("vote");
+    let round = service
+        .storage()
+        .get_proposal(&changing_scope, id)
+        .unwrap()
+        .round;
+    let vote = service
+        .recast_vote(&changing_scope, id, !VOTE_YES, &voter, now_ts())
+        .expect("vote change");
+
+    // A peer's change arrives over the network like any other vote.
+    let peer = wrap(PrivateKeySigner::random());
+    cast_remote_vote(&service, &changing_scope, id, VOTE_YES, &peer).expect("peer vote");
+    cast_remote_vote(&service, &changing_scope, id, !VOTE_YES, &peer).expect("peer change");
+
+    let session = service
+        .storage()
+        .get_session(&changing_scope, id)
+        .unwrap()
+        .unwrap();
+    assert_eq!(session.proposal.round, round);
+    assert_eq!(session.distinct_voters(), 2);
+    assert_eq!(session.vote_events(), 4);
+    assert_eq!(session.votes()[&vote.vote_owner].vote, !VOTE_YES);
+    assert!(
+        service
+            .verify_scope_integrity(&changing_scope)
+            .unwrap()
+            .is_clean()
+    );
+
+    for _ in 0..2 {
+        cast_remote_vote(
+            &service,
+            &changing_scope,
+            id,
+            !VOTE_YES,
+            &wrap(PrivateKeySigner::random()),
+        )
+        .expect("deciding vote");
+    }
+    assert!(
+        !service
+            .storage()
+            .get_consensus_result(&changing_scope, id)
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_fresh_peer_ingests_proposal_with_recast_vote() {
+    for network_type in [NetworkType::Gossipsub, NetworkType::P2P] {
+        let owner = PrivateKeySigner::random();
+        let scope = ScopeID::from(SCOPE1_NAME);
+        let with_changes = |service: DefaultConsensusService| {
+            service
+                .scope(&scope)
+                .unwrap()
+                .with_network_type(network_type)
+                .with_allow_vote_changes(true)
+                .initialize()
+                .unwrap();
+            service
+        };
+        let origin = with_changes(make_service_for(&owner));
+        let proposal = origin
+            .create_proposal(
+                &scope,
+                CreateProposalRequest::new(
+                    PROPOSAL_NAME.to_string(),
+                    PROPOSAL_PAYLOAD,
+                    proposal_owner_from_signer(&owner),
+                    EXPECTED_VOTERS_COUNT_5,
+                    PROPOSAL_EXPIRATION_TIME,
+                    true,
+                )
+                .expect("valid proposal request"),
+                now_ts(),
+            )
+            .expect("proposal should be created");
+        let id = proposal.proposal_id;
+        let voter = wrap(PrivateKeySigner::random());
+        cast_remote_vote(&origin, &scope, id, VOTE_YES, &voter).expect("vote");
+        let recast = origin
+            .recast_vote(&scope, id, !VOTE_YES, &voter, now_ts())
+            .expect("vote change");
+        cast_remote_vote(
+            &origin,
+            &scope,
+            id,
+            VOTE_YES,
+            &wrap(PrivateKeySigner::random()),
+        )
+        .expect("peer vote");
+        let gossiped = origin.storage().get_proposal(&scope, id).unwrap();
+        assert_eq!(gossiped.votes.len(), 3);
+
+        // A fresh peer that allows vote changes takes the recast as a replacement.
+        let fresh = with_changes(make_service());
+        fresh
+            .process_incoming_proposal(&scope, gossiped.clone(), now_ts())
+            .expect("fresh peer ingests the recast");
+        let session = fresh.storage().get_session(&scope, id).unwrap().unwrap();
+        assert_eq!(session.distinct_voters(), 2);
+        assert_eq!(session.votes()[&recast.vote_owner], recast);
+        assert_eq!(session.proposal.round, gossiped.round);
+        assert!(fresh.verify_scope_integrity(&scope).unwrap().is_clean());
+
+        // A peer that does not allow vote changes still refuses the second vote.
+        let strict = make_service();
+        strict
+            .scope(&scope)
+            .unwrap()
+            .with_network_type(network_type)
+            .initialize()
+            .unwrap();
+        let err = strict
+            .process_incoming_proposal(&scope, gossiped, now_ts())
+            .unwrap_err();
+        assert!(matches!(err, ConsensusError::DuplicateVote));
+    }
+}
+
+#[test]
+fn test_vote_tally_reports_live_counts_in_every_state() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let owner = PrivateKeySigner::random();
+    let proposal_id = setup_proposal(
+        &service,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_5,
+        true,
+        ConsensusConfig::p2p(),
+    )
+    .proposal_id;
+    cast_vote_or_panic(&service, &scope, proposal_id, VOTE_YES, owner, "owner vote");
+    cast_remote_vote(
+        &service,
+        &scope,
+        proposal_id,
+        !VOTE_YES,
+        &wrap(PrivateKeySigner::random()),
+    )
+    .expect("dissenting vote");
+
+    let live = VoteTally {
+        yes: 1,
+        no: 1,
+        abstain: 0,
+        silent: 3,
+        required_votes: 4,
+        required_choice_votes: 4,
+    };
+    assert_eq!(
+        service
+            .storage()
+            .get_vote_tally(&scope, proposal_id)
+            .unwrap(),
+        live
+    );
+    // Timeout weighting decides the session, but the tally stays the raw counts.
+    assert_eq!(
+        service
+            .handle_consensus_timeout(&scope, proposal_id, now_ts())
+            .unwrap(),
+        TimeoutOutcome::Reached(true)
+    );
+    assert_eq!(
+        service
+            .storage()
+            .get_vote_tally(&scope, proposal_id)
+            .unwrap(),
+        live
+    );
+
+    let solo = PrivateKeySigner::random();
+    let solo_id = setup_proposal(
+        &service,
+        &scope,
+        &solo,
+        EXPECTED_VOTERS_COUNT_1,
+        true,
+        ConsensusConfig::p2p(),
+    )
+    .proposal_id;
+    cast_vote_or_panic(&service, &scope, solo_id, VOTE_YES, solo, "solo vote");
+    let tally = service.storage().get_vote_tally(&scope, solo_id).unwrap();
+    assert_eq!((tally.yes, tally.no, tally.silent), (1, 0, 0));
+    assert_eq!(tally.required_votes, 1);
+
+    assert!(matches!(
+        service
+            .storage()
+            .get_vote_tally(&scope, solo_id.wrapping_add(1)),
+        Err(ConsensusError::SessionNotFound)
+    ));
+}
+
+#[test]
+fn test_votes_needed_counts_remaining_yes_votes() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let owner = PrivateKeySigner::random();
+    let proposal_id = setup_proposal(
+        &service,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_5,
+        true,
+        ConsensusConfig::p2p(),
+    )
+    .proposal_id;
+    cast_vote_or_panic(&service, &scope, proposal_id, VOTE_YES, owner, "owner vote");
+    cast_remote_vote(
+        &service,
+        &scope,
+        proposal_id,
+        !VOTE_YES,
+        &wrap(PrivateKeySigner::random()),
+    )
+    .expect("dissenting vote");
+
+    // n = 5: the 2n/3 threshold (4) outweighs a bare majority (3).
+    assert_eq!(
+        service.storage().votes_needed(&scope, proposal_id).unwrap(),
+        ConsensusProgress {
+            current_votes: 2,
+            required_votes: 4,
+            current_yes: 1,
+            yes_needed_for_consensus: 3,
+        }
+    );
+
+    // n <= 2 is unanimous: every voter must say YES.
+    for (expected_voters, yes_votes, yes_needed) in [
+        (EXPECTED_VOTERS_COUNT_1, 0, 1),
+        (EXPECTED_VOTERS_COUNT_2, 1, 1),
+    ] {
+        let owner = PrivateKeySigner::random();
+        let proposal_id = setup_proposal(
+            &service,
+            &scope,
+            &owner,
+            expected_voters,
+            true,
+            ConsensusConfig::p2p(),
+        )
+        .proposal_id;
+        if yes_votes > 0 {
+            cast_vote_or_panic(&service, &scope, proposal_id, VOTE_YES, owner, "owner vote");
+        }
+        let progress = service.storage().votes_needed(&scope, proposal_id).unwrap();
+        assert_eq!(progress.required_votes, expected_voters);
+        assert_eq!(progress.current_yes, yes_votes);
+        assert_eq!(progress.yes_needed_for_consensus, yes_needed);
+    }
+
+    assert!(matches!(
+        service
+            .storage()
+            .votes_needed(&scope, proposal_id.wrapping_add(1)),
+        Err(ConsensusError::SessionNotFound)
+    ));
+}
+
+#[test]
+fn test_with_session_reads_stored_session_in_place() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let owner = PrivateKeySigner::random();
+    let proposal_id = setup_proposal(
+        &service,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_3,
+        true,
+        ConsensusConfig::gossipsub(),
+    )
+    .proposal_id;
+    cast_vote_or_panic(&service, &scope, proposal_id, VOTE_YES, owner, "owner vote");
+
+    let (votes, state) = service
+        .storage()
+        .with_session(&scope, proposal_id, |session| {
+            Ok((session.votes().len(), session.state))
+        })
+        .unwrap();
+    assert_eq!((votes, state), (1, ConsensusState::Active));
+    assert!(matches!(
+        service
+            .storage()
+            .with_session(&scope, proposal_id.wrapping_add(1), |_| Ok(())),
+        Err(ConsensusError::SessionNotFound)
+    ));
+}
+
+#[test]
+fn test_consensus_outcome_flags_ties_broken_by_liveness() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    for (choices, decided_by_liveness) in [
+        (vec![!VOTE_YES, !VOTE_YES, VOTE_YES, VOTE_YES], true),
+        (vec![VOTE_YES, VOTE_YES, VOTE_YES], false),
+    ] {
+        let owner = PrivateKeySigner::random();
+        let proposal_id = setup_proposal(
+            &service,
+            &scope,
+            &owner,
+            EXPECTED_VOTERS_COUNT_4,
+            true,
+            ConsensusConfig::gossipsub(),
+        )
+        .proposal_id;
+        assert!(matches!(
+            service.storage().get_consensus_outcome(&scope, proposal_id),
+            Err(ConsensusError::ConsensusNotReached)
+        ));
+        for &choice in &choices {
+            cast_remote_vote(
+                &service,
+                &scope,
+                proposal_id,
+                choice,
+                &wrap(PrivateKeySigner::random()),
+            )
+            .expect("vote");
+        }
+
+        let outcome = service
+            .storage()
+            .get_consensus_outcome(&scope, proposal_id)
+            .unwrap();
+        assert!(outcome.result);
+        assert_eq!(outcome.decided_by_liveness, decided_by_liveness);
+        assert_eq!(outcome.yes + outcome.no, choices.len() as u32);
+        assert_eq!(outcome.silent, 4 - choices.len() as u32);
+        assert_eq!(outcome.round, 2);
+        assert!(
+            service
+                .storage()
+                .get_consensus_result(&scope, proposal_id)
+                .unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_finalize_now_is_idempotent() {
+    let service = make_service();
+    let events = service.event_bus().subscribe();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let finalize = |choices: [bool; 2]| {
+        let owner = PrivateKeySigner::random();
+        let proposal_id = setup_proposal(
+            &service,
+            &scope,
+            &owner,
+            EXPECTED_VOTERS_COUNT_4,
+            true,
+            ConsensusConfig::gossipsub(),
+        )
+        .proposal_id;
+        for choice in choices {
+            cast_remote_vote(
+                &service,
+                &scope,
+                proposal_id,
+                choice,
+                &wrap(PrivateKeySigner::random()),
+            )
+            .expect("vote");
+        }
+        let first = service.finalize_now(&scope, proposal_id, now_ts());
+        let (_, event) = events.try_recv().expect("one outcome event");
+        let second = service.finalize_now(&scope, proposal_id, now_ts());
+        assert!(events.try_recv().is_err());
+        (first, second, event)
+    };
+
+    // Silent peers count as YES, so two YES votes decide the proposal.
+    let (first, second, event) = finalize([VOTE_YES, VOTE_YES]);
+    assert!(first.unwrap());
+    assert!(second.unwrap());
+    assert!(matches!(
+        event,
+        ConsensusEvent::ConsensusReached { result: true, .. }
+    ));
+
+    // Two NO votes against two silent YES peers is an unresolved tie.
+    let (first, second, event) = finalize([!VOTE_YES, !VOTE_YES]);
+    assert!(matches!(first, Err(ConsensusError::ConsensusFailed)));
+    assert!(matches!(second, Err(ConsensusError::ConsensusFailed)));
+    assert!(matches!(event, ConsensusEvent::ConsensusFailed { .. }));
+}
+
+#[test]
+fn test_process_incoming_votes_applies_batch_atomically() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let owner = PrivateKeySigner::random();
+    let proposal = setup_proposal(
+        &service,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_4,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+    let proposal_id = proposal.proposal_id;
+
+    // Build a chained batch as a gossip message would carry it.
+    let mut chained = proposal.clone();
+    let mut batch = Vec::new();
+    for signer in [
+        owner,
+        PrivateKeySigner::random(),
+        PrivateKeySigner::random(),
+    ] {
+        let vote = build_vote(&chained, VOTE_YES, &wrap(signer), now_ts()).unwrap();
+        chained.votes.push(vote.clone());
+        batch.push(vote);
+    }
+
+    // One bad vote rejects the whole batch and leaves the session untouched.
+    let mut tampered = batch.clone();
+    tampered[2].vote = !tampered[2].vote;
+    assert!(matches!(
+        service.process_incoming_votes(&scope, proposal_id, tampered, now_ts()),
+        Err(ConsensusError::InvalidVoteHash)
+    ));
+    let mut reordered = batch.clone();
+    reordered.swap(0, 1);
+    assert!(
+        service
+            .process_incoming_votes(&scope, proposal_id, reordered, now_ts())
+            .is_err()
+    );
+    let mut foreign = batch.clone();
+    foreign[1].proposal_id = proposal_id.wrapping_add(1);
+    assert!(matches!(
+        service.process_incoming_votes(&scope, proposal_id, foreign, now_ts()),
+        Err(ConsensusError::VoteProposalIdMismatch)
+    ));
+    let session = service
+        .storage()
+        .get_session(&scope, proposal_id)
+        .unwrap()
+        .unwrap();
+    assert!(session.votes().is_empty());
+    assert!(session.proposal.votes.is_empty());
+
+    let events = service.event_bus().subscribe();
+    let transition = service
+        .process_incoming_votes(&scope, proposal_id, batch, now_ts())
+        .unwrap();
+    assert_eq!(transition, SessionTransition::ConsensusReached(true));
+    assert_eq!(
+        service
+            .storage()
+            .get_proposal(&scope, proposal_id)
+            .unwrap()
+            .votes,
+        chained.votes
+    );
+    assert!(matches!(
+        events.try_recv(),
+        Ok((_, ConsensusEvent::ConsensusReached { .. }))
+    ));
+}
+
+#[test]
+fn test_duplicate_vote_policy_ignores_identical_regossip() {
+    let service = make_service();
+    let ignoring_scope = ScopeID::from(SCOPE1_NAME);
+    let rejecting_scope = ScopeID::from(SCOPE2_NAME);
+    service
+        .scope(&ignoring_scope)
+        .unwrap()
+        .with_duplicate_vote_policy(DuplicateVotePolicy::Ignore)
+        .initialize()
+        .unwrap();
+
+    for scope in [&ignoring_scope, &rejecting_scope] {
+        let proposal_id = service
+            .create_proposal(
+                scope,
+                CreateProposalRequest::new(
+                    PROPOSAL_NAME.to_string(),
+                    PROPOSAL_PAYLOAD,
+                    proposal_owner_from_signer(&PrivateKeySigner::random()),
+                    EXPECTED_VOTERS_COUNT_3,
+                    PROPOSAL_EXPIRATION_TIME,
+                    true,
+                )
+                .expect("valid proposal request"),
+                now_ts(),
+            )
+            .expect("proposal should be created")
+            .proposal_id;
+        let voter = wrap(PrivateKeySigner::random());
+        let proposal = service.storage().get_proposal(scope, proposal_id).unwrap();
+        let vote = build_vote(&proposal, VOTE_YES, &voter, now_ts()).unwrap();
+        service
+            .process_incoming_vote(scope, vote.clone(), now_ts())
+            .expect("first delivery");
+
+        let redelivered = service.process_incoming_vote(scope, vote.clone(), now_ts());
+        if scope == &ignoring_scope {
+            redelivered.expect("identical vote is a no-op");
+        } else {
+            assert!(matches!(redelivered, Err(ConsensusError::DuplicateVote)));
+        }
+
+        // A different vote from the same owner is rejected either way.
+        let changed = build_vote(&proposal, VOTE_YES, &voter, now_ts()).unwrap();
+        assert!(matches!(
+            service.process_incoming_vote(scope, changed, now_ts()),
+            Err(ConsensusError::DuplicateVote)
+        ));
+        let session = service
+            .storage()
+            .get_session(scope, proposal_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(session.proposal.votes, vec![vote]);
+        assert_eq!(session.proposal.round, 2);
+    }
+}
+
+#[test]
+fn test_equivocation_is_reported_with_both_signed_votes() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let owner = PrivateKeySigner::random();
+    let proposal = setup_proposal(
+        &service,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_4,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+    let proposal_id = proposal.proposal_id;
+    let byzantine = wrap(PrivateKeySigner::random());
+    let yes = build_vote(&proposal, VOTE_YES, &byzantine, now_ts()).unwrap();
+    let no = build_vote(&proposal, !VOTE_YES, &byzantine, now_ts()).unwrap();
+
+    let events = service.event_bus().subscribe();
+    service
+        .process_incoming_vote(&scope, yes.clone(), now_ts())
+        .expect("first vote");
+    let err = service
+        .process_incoming_vote(&scope, no.clone(), now_ts())
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        ConsensusError::Equivocation { owner, first_hash, second_hash }
+            if owner == yes.vote_owner
+                && first_hash == yes.vote_hash
+                && second_hash == no.vote_hash
+    ));
+    match events.try_recv() {
+        Ok((
+            event_scope,
+            ConsensusEvent::EquivocationDetected {
+                proposal_id: event_proposal_id,
+                first,
+                second,
+                ..
+            },
+        )) => {
+            assert_eq!(event_scope, scope);
+            assert_eq!(event_proposal_id, proposal_id);
+            assert_eq!((*first, *second), (yes.clone(), no.clone()));
+        }
+        other => panic!("expected EquivocationDetected, got {other:?}"),
+    }
+
+    // Within one batch, the conflicting pair rejects the whole batch.
+    let other = setup_proposal(
+        &service,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_4,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+    let mut chained = other.clone();
+    let first = build_vote(&chained, VOTE_YES, &byzantine, now_ts()).unwrap();
+    chained.votes.push(first.clone());
+    let second = build_vote(&chained, !VOTE_YES, &byzantine, now_ts()).unwrap();
+    assert!(matches!(
+        service.process_incoming_votes(
+            &scope,
+            other.proposal_id,
+            vec![first.clone(), second.clone()],
+            now_ts()
+        ),
+        Err(ConsensusError::Equivocation { .. })
+    ));
+    assert!(matches!(
+        events.try_recv(),
+        Ok((_, ConsensusEvent::EquivocationDetected { first: f, second: s, .. }))
+            if *f == first && *s == second
+    ));
+}
+
+#[test]
+fn test_finalize_all_expired_sweeps_every_scope_once() {
+    let service = make_service();
+    let scope1 = ScopeID::from(SCOPE1_NAME);
+    let scope2 = ScopeID::from(SCOPE2_NAME);
+
+    let owner = PrivateKeySigner::random();
+    let decided = setup_proposal(
+        &service,
+        &scope1,
+        &owner,
+        EXPECTED_VOTERS_COUNT_3,
+        true,
+        ConsensusConfig::gossipsub(),
+    )
+    .proposal_id;
+    cast_vote_or_panic(&service, &scope1, decided, VOTE_YES, owner, "owner vote");
+    let tied = setup_partial_tie(&service, &scope2);
+    let already_failed = setup_partial_tie(&service, &scope2);
+
+    let later = now_ts() + PROPOSAL_EXPIRATION_TIME + 1;
+    assert_eq!(
+        service
+            .handle_consensus_timeout(&scope2, already_failed, later)
+            .unwrap(),
+        TimeoutOutcome::Failed
+    );
+
+    let events = service.event_bus().subscribe();
+    let mut swept: Vec<(ScopeID, u64, Option<bool>)> = service
+        .finalize_all_expired(later)
+        .unwrap()
+        .into_iter()
+        .flat_map(|(scope, sweep)| {
+            let sweep = sweep.expect("scope should be listed");
+            assert!(sweep.errors.is_empty());
+            sweep
+                .finalized
+                .into_iter()
+                .map(move |(id, outcome)| (scope.clone(), id, outcome))
+        })
+        .collect();
+    swept.sort_unstable_by_key(|(_, id, _)| *id);
+    let mut expected = vec![(scope1, decided, Some(true)), (scope2.clone(), tied, None)];
+    expected.sort_unstable_by_key(|(_, id, _)| *id);
+    assert_eq!(swept, expected);
+
+    let mut finalized: Vec<u64> = std::iter::from_fn(|| events.try_recv().ok())
+        .filter_map(|(_, event)| match event {
+            ConsensusEvent::ConsensusReached { proposal_id, .. }
+            | ConsensusEvent::ConsensusFailed { proposal_id, .. } => Some(proposal_id),
+            _ => None,
+        })
+        .collect();
+    finalized.sort_unstable();
+    let mut expected_ids = vec![decided, tied];
+    expected_ids.sort_unstable();
+    assert_eq!(finalized, expected_ids, "no session is finalized twice");
+
+    // A second sweep, or a late timer firing, finds nothing left to do.
+    assert!(service.finalize_all_expired(later).unwrap().is_empty());
+    assert_eq!(
+        service
+            .handle_consensus_timeout(&scope2, tied, later)
+            .unwrap(),
+        TimeoutOutcome::Failed
+    );
+    assert!(events.try_recv().is_err());
+}
+
+#[test]
+fn test_spawn_reaper_finalizes_expired_sessions_until_aborted() {
+    let clock = MockClock::new(now_ts());
+    let service = make_service().with_clock(clock.clone());
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let owner = PrivateKeySigner::random();
+    let events = service.event_bus().subscribe();
+    let proposal = service
+        .create_proposal(
+            &scope,
+            CreateProposalRequest::new(
+                PROPOSAL_NAME.to_string(),
+                PROPOSAL_PAYLOAD,
+                proposal_owner_from_signer(&owner),
+                EXPECTED_VOTERS_COUNT_3,
+                PROPOSAL_EXPIRATION_TIME,
+                true,
+            )
+            .expect("valid proposal request"),
+            service.now(),
+        )
+        .expect("proposal should be created");
+    clock.advance(Duration::from_secs(PROPOSAL_EXPIRATION_TIME + 1));
+
+    let reaper = service.spawn_reaper(Duration::from_millis(10));
+    let (_, event) = events
+        .recv_timeout(Duration::from_secs(5))
+        .expect("reaper finalizes the expired proposal");
+    assert!(matches!(
+        event,
+        ConsensusEvent::ConsensusReached { proposal_id, result: true, .. }
+            if proposal_id == proposal.proposal_id
+    ));
+    assert!(!reaper.is_finished());
+    reaper.abort();
+}
+
+#[test]
+fn test_get_consensus_result_finalizes_expired_session_on_read() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let owner = PrivateKeySigner::random();
+    let proposal_id = setup_proposal(
+        &service,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_3,
+        true,
+        ConsensusConfig::gossipsub(),
+    )
+    .proposal_id;
+    cast_vote_or_panic(&service, &scope, proposal_id, VOTE_YES, owner, "owner vote");
+
+    assert!(matches!(
+        service.get_consensus_result(&scope, proposal_id, now_ts()),
+        Err(ConsensusError::ConsensusNotReached)
+    ));
+
+    // The timeout never ran: storage still reports the stale state.
+    let later = now_ts() + PROPOSAL_EXPIRATION_TIME + 1;
+    assert!(matches!(
+        service.storage().get_consensus_result(&scope, proposal_id),
+        Err(ConsensusError::ConsensusNotReached)
+    ));
+
+    // A paused scope cannot finalize the expired session.
+    service.pause_scope(&scope).unwrap();
+    assert!(matches!(
+        service.get_consensus_result(&scope, proposal_id, later),
+        Err(ConsensusError::ConsensusExpired)
+    ));
+    let events = service.event_bus().subscribe();
+    service.resume_scope(&scope, now_ts()).unwrap();
+    assert!(
+        service
+            .get_consensus_result(&scope, proposal_id, later)
+            .unwrap()
+    );
+    assert!(
+        service
+            .storage()
+            .get_consensus_result(&scope, proposal_id)
+            .unwrap()
+    );
+    assert!(matches!(
+        events.try_recv(),
+        Ok((_, ConsensusEvent::ConsensusReached { result: true, .. }))
+    ));
+
+    // Reading again does not finalize twice.
+    assert!(
+        service
+            .get_consensus_result(&scope, proposal_id, later)
+            .unwrap()
+    );
+    assert!(events.try_recv().is_err());
+}
+
+#[test]
+fn test_time_until_expiry_counts_down_to_zero() {
+    let clock = MockClock::new(now_ts());
+    let owner = PrivateKeySigner::random();
+    let service = make_service_for(&owner).with_clock(clock.clone());
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let proposal_id = service
+        .create_proposal(
+            &scope,
+            CreateProposalRequest::new(
+                PROPOSAL_NAME.to_string(),
+                PROPOSAL_PAYLOAD,
+                proposal_owner_from_signer(&owner),
+                EXPECTED_VOTERS_COUNT_3,
+                PROPOSAL_EXPIRATION_TIME,
+                true,
+            )
+            .expect("valid proposal request"),
+            service.now(),
+        )
+        .expect("proposal should be created")
+        .proposal_id;
+
+    assert_eq!(
+        service.time_until_expiry(&scope, proposal_id).unwrap(),
+        Duration::from_secs(PROPOSAL_EXPIRATION_TIME)
+    );
+    clock.advance(Duration::from_secs(PROPOSAL_EXPIRATION_TIME - 1));
+    assert_eq!(
+        service.time_until_expiry(&scope, proposal_id).unwrap(),
+        Duration::from_secs(1)
+    );
+    clock.advance(Duration::from_secs(5));
+    assert_eq!(
+        service.time_until_expiry(&scope, proposal_id).unwrap(),
+        Duration::ZERO
+    );
+    assert!(matches!(
+        service.time_until_expiry(&scope, proposal_id + 1),
+        Err(ConsensusError::SessionNotFound)
+    ));
+}
+
+#[test]
+fn test_extend_expiration_keeps_votes_valid_and_accepts_later_votes() {
+    let owner = PrivateKeySigner::random();
+    let service = make_service_for(&owner);
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let proposal = setup_proposal(
+        &service,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_4,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+    let proposal_id = proposal.proposal_id;
+    cast_remote_vote(
+        &service,
+        &scope,
+        proposal_id,
+        VOTE_YES,
+        &wrap(PrivateKeySigner::random()),
+    )
+    .expect("vote before extending");
+
+    let extended = service
+        .extend_expiration(&scope, proposal_id, Duration::from_secs(600), now_ts())
+        .expect("extend active proposal");
+    assert_eq!(
+        extended.expiration_timestamp,
+        proposal.expiration_timestamp + 600
+    );
+    // The re-signed proposal, with its existing vote, still validates.
+    let checked = DefaultConsensusService::new(wrap(PrivateKeySigner::random()));
+    checked
+        .process_incoming_proposal(&scope, extended.clone(), now_ts())
+        .expect("extended proposal is accepted by a peer");
+
+    // A vote after the original expiration lands within the extension.
+    let past_original = proposal.expiration_timestamp + 1;
+    let vote = build_vote(
+        &extended,
+        VOTE_YES,
+        &wrap(PrivateKeySigner::random()),
+        past_original,
+    )
+    .unwrap();
+    service
+        .process_incoming_vote(&scope, vote, past_original)
+        .expect("vote after extending");
+
+    assert!(matches!(
+        service.extend_expiration(
+            &scope,
+            proposal_id,
+            Duration::from_secs(60),
+            extended.expiration_timestamp
+        ),
+        Err(ConsensusError::ConsensusExpired)
+    ));
+    assert!(matches!(
+        service.extend_expiration(
+            &scope,
+            proposal_id,
+            Duration::from_secs(365 * 24 * 3600),
+            now_ts()
+        ),
+        Err(ConsensusError::InvalidTimeout)
+    ));
+    service.finalize_now(&scope, proposal_id, now_ts()).unwrap();
+    assert!(matches!(
+        service.extend_expiration(&scope, proposal_id, Duration::from_secs(60), now_ts()),
+        Err(ConsensusError::SessionTerminal { .. })
+    ));
+}
+
+#[test]
+fn test_extend_expiration_requires_owner_and_spreads_through_merge() {
+    let owner = PrivateKeySigner::random();
+    let origin = make_service_for(&owner);
+    let peer = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let proposal = setup_proposal(
+        &origin,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_4,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+    let proposal_id = proposal.proposal_id;
+    peer.process_incoming_proposal(&scope, proposal.clone(), now_ts())
+        .unwrap();
+
+    // A peer other than the owner cannot re-sign the proposal.
+    assert!(matches!(
+        peer.extend_expiration(&scope, proposal_id, Duration::from_secs(600), now_ts()),
+        Err(ConsensusError::NotProposalOwner)
+    ));
+    assert_eq!(
+        peer.storage()
+            .get_proposal(&scope, proposal_id)
+            .unwrap()
+            .expiration_timestamp,
+        proposal.expiration_timestamp
+    );
+
+    let extended = origin
+        .extend_expiration(&scope, proposal_id, Duration::from_secs(600), now_ts())
+        .expect("owner extends");
+    assert_eq!(
+        peer.merge_proposal(&scope, extended.clone(), now_ts())
+            .unwrap(),
+        SessionTransition::StillActive
+    );
+    let merged = peer.storage().get_proposal(&scope, proposal_id).unwrap();
+    assert_eq!(merged.expiration_timestamp, extended.expiration_timestamp);
+    assert_eq!(merged.proposal_signature, extended.proposal_signature);
+
+    // The peer now accepts votes in the extension, and ignores the older copy.
+    let past_original = proposal.expiration_timestamp + 1;
+    let vote = build_vote(
+        &merged,
+        VOTE_YES,
+        &wrap(PrivateKeySigner::random()),
+        past_original,
+    )
+    .unwrap();
+    peer.process_incoming_vote(&scope, vote, past_original)
+        .expect("vote after the merged extension");
+    peer.merge_proposal(&scope, proposal, past_original)
+        .unwrap();
+    assert_eq!(
+        peer.storage()
+            .get_proposal(&scope, proposal_id)
+            .unwrap()
+            .expiration_timestamp,
+        extended.expiration_timestamp
+    );
+}
+
+#[test]
+fn test_list_scopes_returns_every_scope_with_sessions() {
+    let service = make_service();
+    assert!(service.list_scopes().unwrap().is_empty());
+
+    let scope1 = ScopeID::from(SCOPE1_NAME);
+    let scope2 = ScopeID::from(SCOPE2_NAME);
+    for scope in [&scope1, &scope2] {
+        setup_proposal(
+            &service,
+            scope,
+            &PrivateKeySigner::random(),
+            EXPECTED_VOTERS_COUNT_3,
+            true,
+            ConsensusConfig::gossipsub(),
+        );
+    }
+
+    let mut scopes = service.list_scopes().unwrap();
+    scopes.sort();
+    let mut expected = vec![scope1, scope2];
+    expected.sort();
+    assert_eq!(scopes, expected);
+}
+
+#[test]
+fn test_global_stats_sum_every_scope() {
+    let service = make_service();
+    let scope1 = ScopeID::from(SCOPE1_NAME);
+    let scope2 = ScopeID::from(SCOPE2_NAME);
+
+    let owner = PrivateKeySigner::random();
+    let decided = setup_proposal(
+        &service,
+        &scope1,
+        &owner,
+        EXPECTED_VOTERS_COUNT_3,
+        true,
+        ConsensusConfig::gossipsub(),
+    )
+    .proposal_id;
+    cast_vote_or_panic(&service, &scope1, decided, VOTE_YES, owner, "owner vote");
+    setup_proposal(
+        &service,
+        &scope1,
+        &PrivateKeySigner::random(),
+        EXPECTED_VOTERS_COUNT_3,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+    let tied = setup_partial_tie(&service, &scope2);
+    let later = now_ts() + PROPOSAL_EXPIRATION_TIME + 1;
+    service.finalize_now(&scope1, decided, later).unwrap();
+    assert!(service.finalize_now(&scope2, tied, later).is_err());
+
+    let stats = service.get_global_stats();
+    assert_eq!(stats.total_sessions, 3);
+    assert_eq!(stats.active_sessions, 1);
+    assert_eq!(stats.consensus_reached, 1);
+    assert_eq!(stats.failed_sessions, 1);
+
+    service.storage().delete_scope(&scope2).unwrap();
+    let stats = service.get_global_stats();
+    assert_eq!(stats.total_sessions, 2);
+    assert_eq!(stats.failed_sessions, 0);
+}
+
+#[test]
+fn test_remove_proposal_and_clear_scope_leave_scope_queryable() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    service
+        .scope(&scope)
+        .unwrap()
+        .with_threshold(0.75)
+        .initialize()
+        .unwrap();
+    let proposals: Vec<u64> = (0..2)
+        .map(|_| {
+            setup_proposal(
+                &service,
+                &scope,
+                &PrivateKeySigner::random(),
+                EXPECTED_VOTERS_COUNT_3,
+                true,
+                ConsensusConfig::gossipsub(),
+            )
+            .proposal_id
+        })
+        .collect();
+    let events = service.event_bus().subscribe();
+
+    let removed = service
+        .remove_proposal(&scope, proposals[0], now_ts())
+        .unwrap()
+        .expect("proposal removed");
+    assert_eq!(removed.proposal_id, proposals[0]);
+    assert!(
+        service
+            .remove_proposal(&scope, proposals[0], now_ts())
+            .unwrap()
+            .is_none()
+    );
+    assert!(matches!(
+        service.storage().get_proposal(&scope, proposals[0]),
+        Err(ConsensusError::SessionNotFound)
+    ));
+    assert_eq!(service.get_scope_stats(&scope).total_sessions, 1);
+
+    // Removing the last session leaves empty results, not errors.
+    service
+        .remove_proposal(&scope, proposals[1], now_ts())
+        .unwrap();
+    assert!(
+        service
+            .storage()
+            .get_active_proposals(&scope)
+            .unwrap()
+            .is_empty()
+    );
+    assert_eq!(service.get_scope_stats(&scope).total_sessions, 0);
+
+    setup_proposal(
+        &service,
+        &scope,
+        &PrivateKeySigner::random(),
+        EXPECTED_VOTERS_COUNT_3,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+    service.clear_scope(&scope, now_ts()).unwrap();
+    assert!(
+        service
+            .storage()
+            .get_active_proposals(&scope)
+            .unwrap()
+            .is_empty()
+    );
+    assert_eq!(
+        service
+            .storage()
+            .get_scope_config(&scope)
+            .unwrap()
+            .unwrap()
+            .default_consensus_threshold,
+        0.75
+    );
+    assert!(events.try_recv().is_err());
+}
+
+#[test]
+fn test_vote_batching_coalesces_rapid_votes() {
+    let owner = PrivateKeySigner::random();
+    let window = Duration::from_secs(10);
+    let batched = make_service_for(&owner).with_vote_batching(window);
+    let individual = make_service().with_vote_events(true);
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let proposal = setup_proposal(
+        &batched,
+        &scope,
+        &owner,
+        20,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+    let proposal_id = proposal.proposal_id;
+    individual
+        .process_incoming_proposal(&scope, proposal, now_ts())
+        .unwrap();
+    let batched_events = batched.event_bus().subscribe();
+    let individual_events = individual.event_bus().subscribe();
+
+    // Twelve rapid votes, the seventh arriving once the window has passed.
+    let start = now_ts();
+    for index in 0..12u64 {
+        let now = if index < 6 { start } else { start + 10 };
+        let voter = wrap(PrivateKeySigner::random());
+        for service in [&batched, &individual] {
+            let held = service.storage().get_proposal(&scope, proposal_id).unwrap();
+            let vote = build_vote(&held, index % 2 == 0, &voter, start).unwrap();
+            service.process_incoming_vote(&scope, vote, now).unwrap();
+        }
+    }
+    // The second batch is not yet due, then is flushed once it is.
+    batched.flush_vote_batches(start + 15);
+    batched.flush_vote_batches(start + 20);
+
+    let voters_added: Vec<Vec<u8>> = individual_events
+        .try_iter()
+        .map(|(_, event)| match event {
+            ConsensusEvent::VoteAdded { voter, .. } => voter,
+            other => panic!("expected VoteAdded, got {other:?}"),
+        })
+        .collect();
+    let batches: Vec<(Vec<Vec<u8>>, usize)> = batched_events
+        .try_iter()
+        .map(|(_, event)| match event {
+            ConsensusEvent::VotesBatch {
+                new_voters, total, ..
+            } => (new_voters, total),
+            other => panic!("expected VotesBatch, got {other:?}"),
+        })
+        .collect();
+    assert_eq!(voters_added.len(), 12);
+    assert_eq!(batches.len(), 2);
+    assert!(batches.len() < voters_added.len());
+    assert_eq!(batches[0].0.len(), 7);
+    assert_eq!(batches[0].1, 7);
+    assert_eq!(batches[1].0.len(), 5);
+    assert_eq!(batches[1].1, 12);
+    let batched_voters: Vec<Vec<u8>> = batches.into_iter().flat_map(|(voters, _)| voters).collect();
+    assert_eq!(batched_voters, voters_added);
+
+    // A pending batch is published before the outcome it leads to.
+    let mut decided_by = 0;
+    while batched
+        .storage()
+        .get_session(&scope, proposal_id)
+        .unwrap()
+        .is_some_and(|session| session.state == ConsensusState::Active)
+    {
+        let voter = wrap(PrivateKeySigner::random());
+        let held = batched.storage().get_proposal(&scope, proposal_id).unwrap();
+        let vote = build_vote(&held, VOTE_YES, &voter, start).unwrap();
+        batched
+            .process_incoming_vote(&scope, vote, start + 30)
+            .unwrap();
+        decided_by += 1;
+    }
+    assert!(matches!(
+        batched_events.try_recv(),
+        Ok((_, ConsensusEvent::VotesBatch { ref new_voters, total, .. }))
+            if new_voters.len() == decided_by && total == 12 + decided_by
+    ));
+    assert!(matches!(
+        batched_events.try_recv(),
+        Ok((_, ConsensusEvent::ConsensusReached { result: true, .. }))
+    ));
+    assert!(batched_events.try_recv().is_err());
+}
+
+#[test]
+fn test_vote_events_report_each_counted_vote() {
+    let owner = PrivateKeySigner::random();
+    let service = make_service_for(&owner).with_vote_events(true);
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let proposal = setup_proposal(
+        &service,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_4,
+        true,
+        ConsensusConfig::gossipsub().with_duplicate_vote_policy(DuplicateVotePolicy::Ignore),
+    );
+    let proposal_id = proposal.proposal_id;
+    let events = service.event_bus().subscribe();
+    let next_vote_added = || match events.try_recv() {
+        Ok((
+            _,
+            ConsensusEvent::VoteAdded {
+                voter,
+                choice,
+                round,
+                ..
+            },
+        )) => (voter, choice, round),
+        other => panic!("expected VoteAdded, got {other:?}"),
+    };
+
+    service
+        .cast_vote(&scope, proposal_id, VOTE_YES, now_ts())
+        .unwrap();
+    assert_eq!(
+        next_vote_added(),
+        (
+            proposal_owner_from_signer(&owner),
+            VoteChoice::from(VOTE_YES),
+            2
+        )
+    );
+
+    let voter = wrap(PrivateKeySigner::random());
+    let vote = cast_remote_vote(&service, &scope, proposal_id, !VOTE_YES, &voter).unwrap();
+    assert_eq!(
+        next_vote_added(),
+        (vote.vote_owner.clone(), VoteChoice::No, 2)
+    );
+
+    // An ignored re-gossip of the same vote is not reported again.
+    service
+        .process_incoming_vote(&scope, vote, now_ts())
+        .unwrap();
+    assert!(events.try_recv().is_err());
+
+    // A batch reports each vote, then the outcome it triggered.
+    let mut chained = service.storage().get_proposal(&scope, proposal_id).unwrap();
+    let batch: Vec<_> = [!VOTE_YES, VOTE_YES]
+        .into_iter()
+        .map(|choice| {
+            let vote = build_vote(
+                &chained,
+                choice,
+                &wrap(PrivateKeySigner::random()),
+                now_ts(),
+            )
+            .unwrap();
+            chained.votes.push(vote.clone());
+            vote
+        })
+        .collect();
+    service
+        .process_incoming_votes(&scope, proposal_id, batch.clone(), now_ts())
+        .unwrap();
+    assert_eq!(
+        next_vote_added(),
+        (batch[0].vote_owner.clone(), VoteChoice::No, 2)
+    );
+    assert_eq!(
+        next_vote_added(),
+        (batch[1].vote_owner.clone(), VoteChoice::Yes, 2)
+    );
+    assert!(matches!(
+        events.try_recv(),
+        Ok((_, ConsensusEvent::ConsensusReached { result: true, .. }))
+    ));
+
+    // Without the opt-in, votes publish nothing.
+    let quiet = make_service();
+    let quiet_events = quiet.event_bus().subscribe();
+    let quiet_proposal = setup_proposal(
+        &quiet,
+        &scope,
+        &PrivateKeySigner::random(),
+        EXPECTED_VOTERS_COUNT_4,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+    cast_remote_vote(
+        &quiet,
+        &scope,
+        quiet_proposal.proposal_id,
+        VOTE_YES,
+        &wrap(PrivateKeySigner::random()),
+    )
+    .unwrap();
+    assert!(quiet_events.try_recv().is_err());
+}
+
+#[test]
+fn test_abstain_votes_are_signed_and_tallied_separately() {
+    let owner = PrivateKeySigner::random();
+    let service = make_service_for(&owner);
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let proposal = setup_proposal(
+        &service,
+        &scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_4,
+        true,
+        ConsensusConfig::gossipsub(),
+    );
+    let proposal_id = proposal.proposal_id;
+
+    let own = service
+        .cast_vote(&scope, proposal_id, VoteChoice::Abstain, now_ts())
+        .unwrap();
+    assert_eq!(own.choice(), VoteChoice::Abstain);
+    assert!(!own.vote);
+
+    // The flag is covered by the vote hash: clearing it invalidates the vote.
+    let proposal = service.storage().get_proposal(&scope, proposal_id).unwrap();
+    let mut forged = build_vote(
+        &proposal,
+        VoteChoice::Abstain,
+        &wrap(PrivateKeySigner::random()),
+        now_ts(),
+    )
+    .unwrap();
+    forged.abstain = false;
+    assert!(matches!(
+        service.process_incoming_vote(&scope, forged, now_ts()),
+        Err(ConsensusError::InvalidVoteHash)
+    ));
+
+    cast_vote_or_panic(
+        &service,
+        &scope,
+        proposal_id,
+        VOTE_YES,
+        PrivateKeySigner::random(),
+        "yes vote",
+    );
+    cast_vote_or_panic(
+        &service,
+        &scope,
+        proposal_id,
+        !VOTE_YES,
+        PrivateKeySigner::random(),
+        "no vote",
+    );
+
+    // Three of four voted (turnout met), but YES and NO have one vote each.
+    let tally = service
+        .storage()
+        .get_vote_tally(&scope, proposal_id)
+        .unwrap();
+    assert_eq!(
+        (tally.yes, tally.no, tally.abstain, tally.silent),
+        (1, 1, 1, 1)
+    );
+    assert!(matches!(
+        service.get_consensus_result(&scope, proposal_id, now_ts()),
+        Err(ConsensusError::ConsensusNotReached)
+    ));
+}
+
+#[cfg(feature = "blake3")]
+#[test]
+fn test_scope_hash_algo_travels_in_proposal_header() {
+    let owner = PrivateKeySigner::random();
+    let service = make_service_for(&owner);
+    let peer = make_service();
+    let scope = ScopeID::from("blake3_scope");
+    service
+        .scope(&scope)
+        .unwrap()
+        .with_hash_algo(HashAlgo::Blake3)
+        .initialize()
+        .unwrap();
+
+    let proposal = setup_proposal_result(&service, &scope, &owner, EXPECTED_VOTERS_COUNT_3)
+        .expect("proposal should be created");
+    assert_eq!(proposal.hash_algo().unwrap(), HashAlgo::Blake3);
+    let proposal = service
+        .cast_vote_and_get_proposal(&scope, proposal.proposal_id, VOTE_YES, now_ts())
+        .expect("owner vote should be cast");
+    let vote = &proposal.votes[0];
+    assert_eq!(
+        vote.vote_hash,
+        compute_vote_hash_with(vote, HashAlgo::Blake3)
+    );
+    assert_ne!(vote.vote_hash, compute_vote_hash(vote));
+
+    // The peer's scope is left at the default; the header decides.
+    peer.process_incoming_proposal(&scope, proposal.clone(), now_ts())
+        .expect("peer should validate blake3 votes");
+    cast_vote_or_panic(
+        &peer,
+        &scope,
+        proposal.proposal_id,
+        VOTE_YES,
+        PrivateKeySigner::random(),
+        "peer vote should be hashed with the proposal's algorithm",
+    );
+
+    // The owner's signature covers the header, so it cannot be swapped in transit.
+    let mut unknown = proposal.clone();
+    unknown.hash_algo = 7;
+    assert!(matches!(
+        make_service().process_incoming_proposal(&scope, unknown.clone(), now_ts()),
+        Err(ConsensusError::InvalidProposalSignature)
+    ));
+    sign_proposal(&mut unknown, &wrap(owner.clone())).unwrap();
+    assert!(matches!(
+        make_service().process_incoming_proposal(&scope, unknown, now_ts()),
+        Err(ConsensusError::UnsupportedHashAlgo(7))
+    ));
+
+    let default_proposal = setup_proposal_result(
+        &peer,
+        &ScopeID::from("sha_scope"),
+        &owner,
+        EXPECTED_VOTERS_COUNT_3,
+    )
+    .expect("proposal should be created");
+    assert_eq!(default_proposal.hash_algo().unwrap(), HashAlgo::Sha256);
+}
+
+#[test]
+fn test_default_hash_algo_is_sha256() {
+    let owner = PrivateKeySigner::random();
+    let service = make_service_for(&owner);
+    let scope = ScopeID::from("sha_default_scope");
+
+    let proposal = setup_proposal_result(&service, &scope, &owner, EXPECTED_VOTERS_COUNT_3)
+        .expect("proposal should be created");
+    assert_eq!(proposal.hash_algo().unwrap(), HashAlgo::Sha256);
+    let proposal = service
+        .cast_vote_and_get_proposal(&scope, proposal.proposal_id, VOTE_YES, now_ts())
+        .expect("owner vote should be cast");
+    let vote = &proposal.votes[0];
+    assert_eq!(
+        vote.vote_hash,
+        compute_vote_hash_with(vote, HashAlgo::Sha256)
+    );
+    assert_eq!(vote.vote_hash, compute_vote_hash(vote));
+
+    // Without the `blake3` feature the BLAKE3 wire value is just unsupported.
+    #[cfg(not(feature = "blake3"))]
+    assert!(matches!(
+        HashAlgo::try_from(1),
+        Err(ConsensusError::UnsupportedHashAlgo(1))
+    ));
+}
+
+#[test]
+fn test_text_payload_request_stores_utf8_bytes() {
+    let owner = PrivateKeySigner::random();
+    let service = make_service_for(&owner);
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let request = CreateProposalRequest::new_text(
+        PROPOSAL_NAME.to_string(),
+        "Switch to v2",
+        proposal_owner_from_signer(&owner),
+        EXPECTED_VOTERS_COUNT_3,
+        PROPOSAL_EXPIRATION_TIME,
+        true,
+    )
+    .expect("valid proposal request");
+    assert_eq!(request.payload, b"Switch to v2".to_vec());
+
+    let proposal = service
+        .create_proposal(&scope, request, now_ts())
+        .expect("proposal should be created");
+    assert_eq!(String::from_utf8(proposal.payload).unwrap(), "Switch to v2");
+}
+
+#[test]
+fn test_request_expiration_is_relative_to_creation() {
+    let request = CreateProposalRequest::new(
+        PROPOSAL_NAME.to_string(),
+        PROPOSAL_PAYLOAD,
+        vec![1, 2, 3],
+        EXPECTED_VOTERS_COUNT_3,
+        PROPOSAL_EXPIRATION_TIME,
+        true,
+    )
+    .expect("valid proposal request");
+    let now = now_ts();
+    let proposal = request.into_proposal(now).unwrap();
+    assert_eq!(proposal.timestamp, now);
+    assert_eq!(
+        proposal.expiration_timestamp,
+        proposal.timestamp + PROPOSAL_EXPIRATION_TIME
+    );
+}
+
+#[test]
+fn test_scope_stats_track_created_finalized_and_timed_out_proposals() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let new_proposal = |expected_voters_count, liveness_criteria_yes| {
+        let owner = PrivateKeySigner::random();
+        let proposal_id = setup_proposal(
+            &service,
+            &scope,
+            &owner,
+            expected_voters_count,
+            liveness_criteria_yes,
+            ConsensusConfig::gossipsub(),
+        )
+        .proposal_id;
+        (proposal_id, owner)
+    };
+
+    let (_open, _) = new_proposal(EXPECTED_VOTERS_COUNT_3, true);
+    let (finalized, finalized_owner) = new_proposal(EXPECTED_VOTERS_COUNT_3, true);
+    let (timed_out, timed_out_owner) = new_proposal(EXPECTED_VOTERS_COUNT_4, false);
+    let stats = service.get_scope_stats(&scope);
+    assert_eq!(stats.total_sessions, 3);
+    assert_eq!(stats.active_sessions, 3);
+    assert_eq!(stats.consensus_reached, 0);
+    assert_eq!(stats.failed_sessions, 0);
+
+    for signer in [finalized_owner, PrivateKeySigner::random()] {
+        cast_vote_or_panic(&service, &scope, finalized, VOTE_YES, signer, "yes vote");
+    }
+    // 2 YES of 4, silent peers count as NO: tied at timeout, so it fails.
+    for signer in [timed_out_owner, PrivateKeySigner::random()] {
+        cast_vote_or_panic(&service, &scope, timed_out, VOTE_YES, signer, "yes vote");
+    }
+    assert_eq!(
+        service
+            .handle_consensus_timeout(&scope, timed_out, now_ts())
+            .unwrap(),
+        TimeoutOutcome::Failed
+    );
+
+    let stats = service.get_scope_stats(&scope);
+    assert_eq!(stats.total_sessions, 3);
+    assert_eq!(stats.active_sessions, 1);
+    assert_eq!(stats.consensus_reached, 1);
+    assert_eq!(stats.failed_sessions, 1);
+}
+
+/// Create an n = 4 proposal (liveness YES) whose owner votes `owner_choice`,
+/// then split the votes 2-2.
+fn setup_full_turnout_tie(
+    service: &DefaultConsensusService,
+    scope: &ScopeID,
+    tie_break: Option<TieBreak>,
+    owner_choice: bool,
+) -> u64 {
+    let owner = PrivateKeySigner::random();
+    let proposal_id = setup_proposal(
+        service,
+        scope,
+        &owner,
+        EXPECTED_VOTERS_COUNT_4,
+        true,
+        // Unanimity keeps the session undecided until the fourth vote.
+        ConsensusConfig::gossipsub()
+            .with_threshold(1.0)
+            .unwrap()
+            .with_tie_break(tie_break),
+    )
+    .proposal_id;
+    let voters = [
+        owner,
+        PrivateKeySigner::random(),
+        PrivateKeySigner::random(),
+        PrivateKeySigner::random(),
+    ];
+    let choices = [owner_choice, owner_choice, !owner_choice, !owner_choice];
+    for (signer, choice) in voters.into_iter().zip(choices) {
+        cast_vote_or_panic(service, scope, proposal_id, choice, signer, "vote");
+    }
+    proposal_id
+}
+
+#[test]
+fn test_tie_break_liveness_variants_override_proposal_liveness() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+    let result = |proposal_id| service.storage().get_consensus_result(&scope, proposal_id);
+
+    let by_default = setup_full_turnout_tie(&service, &scope, None, !VOTE_YES);
+    assert!(result(by_default).unwrap());
+    let yes = setup_full_turnout_tie(&service, &scope, Some(TieBreak::LivenessYes), !VOTE_YES);
+    assert!(result(yes).unwrap());
+    let no = setup_full_turnout_tie(&service, &scope, Some(TieBreak::LivenessNo), VOTE_YES);
+    assert!(!result(no).unwrap());
+    let explanation = service
+        .storage()
+        .with_session(&scope, no, |session| Ok(session.explain()))
+        .unwrap();
+    assert_eq!(explanation.verdict, QuorumVerdict::Decided(false));
+}
+
+#[test]
+fn test_tie_break_owner_vote_follows_proposal_owner() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE1_NAME);
+
+    for owner_choice in [VOTE_YES, !VOTE_YES] {
+        let proposal_id =
+            setup_full_turnout_tie(&service, &scope, Some(TieBreak::OwnerVote), owner_choice);
+        assert_eq!(
+            service
+                .get_consensus_result(&scope, proposal_id, now_ts())
+                .unwrap(),
+            owner_choice
+        );
+    }
+}
+
+#[test]
+fn test_round_limit_failure_is_stored_and_announced() {
+    let service = make_service();
+    let events = service.event_bus().subscribe();
+    let scope = ScopeID::from(SCOPE1_NAME);
+
+    // P2P with an explicit two-round cap: the third vote is over the limit.
+    service
+        .scope(&scope)
+        .unwrap()
+        .p2p_preset()
+        .with_max_rounds(Some(2))
+        .initialize()
+        .unwrap();
+    let proposal_id = setup_proposal_result(
+        &service,
+        &scope,
+        &PrivateKeySigner::random(),
+        EXPECTED_VOTERS_COUNT_5,
+    )
+    .expect("proposal should be created")
+    .proposal_id;
+    for _ in 0..2 {
+        cast_remote_vote(
+            &service,
+            &scope,
+            proposal_id,
+            VOTE_YES,
+            &wrap(PrivateKeySigner::random()),
+        )
+        .expect("vote within the round limit");
+    }
+    let err = cast_remote_vote(
+        &service,
+        &scope,
+        proposal_id,
+        VOTE_YES,
+        &wrap(PrivateKeySigner::random()),
+    )
+    .unwrap_err();
+    assert!(matches!(err, ConsensusError::MaxRoundsExceeded));
+
+    let (state, votes) = service
+        .storage()
+        .with_session(&scope, proposal_id, |session| {
+            Ok((session.state, session.votes().len()))
+        })
+        .unwrap();
+    assert_eq!(state, ConsensusState::Failed);
+    assert_eq!(votes, 2);
+    let reason = std::iter::from_fn(|| events.recv_timeout(Duration::from_secs(1)).ok())
+        .find_map(|(_, event)| match event {
+            ConsensusEvent::ConsensusFailed {
+                proposal_id: id,
+                reason,
+                ..
+            } if id == proposal_id => Some(reason),
+            _ => None,
+        })
+        .expect("ConsensusFailed event should be emitted");
+    assert_eq!(reason, FailReason::MaxRoundsExceeded.to_string());
+}
+
+#[test]
+fn test_tie_break_fail_fails_session_at_full_turnout() {
+    let service = make_service();
+    let events = service.event_bus().subscribe();
+    let scope = ScopeID::from(SCOPE1_NAME);
+
+    let proposal_id = setup_full_turnout_tie(&service, &scope, Some(TieBreak::Fail), VOTE_YES);
+    assert!(matches!(
+        service.storage().get_consensus_result(&scope, proposal_id),
+        Err(ConsensusError::ConsensusFailed)
+    ));
+    let reason = std::iter::from_fn(|| events.recv_timeout(Duration::from_secs(1)).ok())
+        .find_map(|(_, event)| match event {
+            ConsensusEvent::ConsensusFailed {
+                proposal_id: id,
+                reason,
+                ..
+            } if id == proposal_id => Some(reason),
+            _ => None,
+        })
+        .expect("ConsensusFailed event should be emitted");
+    assert_eq!(reason, FailReason::Tie.to_string());
+}
@@ -104,9 +104,9 @@ fn stub_scheme_reaches_consensus_without_ethereum_types() {
                 "stub-proposal".into(),
                 b"payload".to_vec(),
                 owner.signer().identity().to_vec(),
-                3,
+                4,
                 60,
-                true,
+                false,
             )
             .expect("valid proposal request"),
             Some(ConsensusConfig::gossipsub()),
@@ -131,7 +131,7 @@ fn stub_scheme_reaches_consensus_without_ethereum_types() {
         .expect("session exists");
     assert!(
         session.get_consensus_result().expect("consensus reached"),
-        "3 YES votes via stub scheme should reach consensus"
+        "3 YES votes of 4 via stub scheme should reach consensus"
     );
 }
 
@@ -176,3 +176,61 @@ fn stub_scheme_rejects_forged_signature() {
         "expected InvalidVoteSignature, got {err:?}"
     );
 }
+
+#[test]
+fn stub_scheme_signature_length_is_not_fixed_by_the_library() {
+    let storage = InMemoryConsensusStorage::<ScopeID>::new();
+    let bus = BroadcastEventBus::<ScopeID>::default();
+    let scope = ScopeID::from("stub-scope-length");
+
+    let owner = peer_service(&storage, &bus, StubSigner::new([11; STUB_IDENTITY_LEN]));
+    let proposal = owner
+        .create_proposal_with_config(
+            &scope,
+            CreateProposalRequest::new(
+                "stub-proposal".into(),
+                b"payload".to_vec(),
+                owner.signer().identity().to_vec(),
+                3,
+                60,
+                true,
+            )
+            .expect("valid proposal request"),
+            Some(ConsensusConfig::gossipsub()),
+            now_ts(),
+        )
+        .expect("proposal should be created");
+
+    // 32-byte stub signatures pass; only the scheme decides what a valid
+    // signature looks like.
+    let vote = build_vote(
+        &proposal,
+        true,
+        &StubSigner::new([12; STUB_IDENTITY_LEN]),
+        now_ts(),
+    )
+    .expect("vote");
+    assert_eq!(vote.signature.len(), 32);
+    owner
+        .process_incoming_vote(&scope, vote, now_ts())
+        .expect("non-65-byte signature accepted");
+
+    let mut truncated = build_vote(
+        &proposal,
+        true,
+        &StubSigner::new([13; STUB_IDENTITY_LEN]),
+        now_ts(),
+    )
+    .expect("vote");
+    truncated.signature.truncate(16);
+    let err = owner
+        .process_incoming_vote(&scope, truncated, now_ts())
+        .expect_err("truncated signature must be rejected by the scheme");
+    assert!(
+        matches!(
+            err,
+            hashgraph_like_consensus::error::ConsensusError::InvalidVoteSignature
+        ),
+        "expected InvalidVoteSignature, got {err:?}"
+    );
+}
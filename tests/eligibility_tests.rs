@@ -0,0 +1,168 @@
+mod common;
+use common::{make_service, now_ts, owner_bytes, wrap};
+
+use alloy::signers::local::PrivateKeySigner;
+use hashgraph_like_consensus::{
+    eligibility::EligibilityVerifier,
+    error::ConsensusError,
+    protos::consensus::v1::Vote,
+    scope::ScopeID,
+    signing::{ConsensusSignatureScheme, EthereumConsensusSigner},
+    storage::ConsensusStorage,
+    types::CreateProposalRequest,
+    utils::{build_vote, build_vote_with_eligibility_proof},
+};
+
+const SCOPE_NAME: &str = "eligibility_scope";
+const EXPECTED_VOTERS_COUNT: u32 = 4;
+const PROPOSAL_EXPIRATION_TIME: u64 = 60;
+
+/// Accepts a vote only if its proof is the authority's signature over the voter identity.
+struct AuthorityEligibility {
+    authority: Vec<u8>,
+}
+
+impl EligibilityVerifier for AuthorityEligibility {
+    fn verify(&self, vote: &Vote) -> bool {
+        EthereumConsensusSigner::verify(&self.authority, &vote.vote_owner, &vote.eligibility_proof)
+            .unwrap_or(false)
+    }
+}
+
+fn issue_proof(authority: &EthereumConsensusSigner, member: &EthereumConsensusSigner) -> Vec<u8> {
+    authority.sign(member.identity()).expect("authority signs")
+}
+
+#[test]
+fn test_eligibility_verifier_rejects_votes_without_authority_signature() {
+    let authority = wrap(PrivateKeySigner::random());
+    let service = make_service().with_eligibility_verifier(AuthorityEligibility {
+        authority: authority.identity().to_vec(),
+    });
+    let scope = ScopeID::from(SCOPE_NAME);
+
+    let owner = PrivateKeySigner::random();
+    let proposal = service
+        .create_proposal(
+            &scope,
+            CreateProposalRequest::new(
+                "Eligibility".into(),
+                vec![],
+                owner_bytes(&owner),
+                EXPECTED_VOTERS_COUNT,
+                PROPOSAL_EXPIRATION_TIME,
+                true,
+            )
+            .unwrap(),
+            now_ts(),
+        )
+        .unwrap();
+
+    // Member holding a proof issued by the authority is accepted.
+    let member = wrap(PrivateKeySigner::random());
+    let vote = build_vote_with_eligibility_proof(
+        &proposal,
+        true,
+        &member,
+        issue_proof(&authority, &member),
+        now_ts(),
+    )
+    .unwrap();
+    service
+        .process_incoming_vote(&scope, vote, now_ts())
+        .expect("eligible vote accepted");
+
+    // Vote without any proof is rejected.
+    let outsider = wrap(PrivateKeySigner::random());
+    let proposal = service
+        .storage()
+        .get_proposal(&scope, proposal.proposal_id)
+        .unwrap();
+    let vote = build_vote(&proposal, true, &outsider, now_ts()).unwrap();
+    let err = service
+        .process_incoming_vote(&scope, vote, now_ts())
+        .unwrap_err();
+    assert!(matches!(err, ConsensusError::InvalidEligibilityProof));
+
+    // Proof signed by someone other than the authority is rejected.
+    let impostor = wrap(PrivateKeySigner::random());
+    let vote = build_vote_with_eligibility_proof(
+        &proposal,
+        true,
+        &outsider,
+        issue_proof(&impostor, &outsider),
+        now_ts(),
+    )
+    .unwrap();
+    let err = service
+        .process_incoming_vote(&scope, vote, now_ts())
+        .unwrap_err();
+    assert!(matches!(err, ConsensusError::InvalidEligibilityProof));
+
+    let session = service
+        .storage()
+        .get_session(&scope, proposal.proposal_id)
+        .unwrap()
+        .expect("session exists");
+    assert_eq!(session.votes().len(), 1);
+}
+
+#[test]
+fn test_cast_vote_attaches_proof_and_proof_is_covered_by_vote_hash() {
+    let authority = wrap(PrivateKeySigner::random());
+    let verifier = || AuthorityEligibility {
+        authority: authority.identity().to_vec(),
+    };
+    let scope = ScopeID::from(SCOPE_NAME);
+
+    let alice = make_service();
+    let alice_proof = issue_proof(&authority, alice.signer());
+    let alice = alice
+        .with_eligibility_verifier(verifier())
+        .with_eligibility_proof(alice_proof.clone());
+    let bob = make_service().with_eligibility_verifier(verifier());
+
+    let proposal = alice
+        .create_proposal(
+            &scope,
+            CreateProposalRequest::new(
+                "Eligibility".into(),
+                vec![],
+                alice.signer().identity().to_vec(),
+                EXPECTED_VOTERS_COUNT,
+                PROPOSAL_EXPIRATION_TIME,
+                true,
+            )
+            .unwrap(),
+            now_ts(),
+        )
+        .unwrap();
+    let vote = alice
+        .cast_vote(&scope, proposal.proposal_id, true, now_ts())
+        .unwrap();
+    assert_eq!(vote.eligibility_proof, alice_proof);
+
+    // Bob accepts Alice's proposal because her embedded vote carries a valid proof.
+    let proposal = alice
+        .storage()
+        .get_proposal(&scope, proposal.proposal_id)
+        .unwrap();
+    bob.process_incoming_proposal(&scope, proposal.clone(), now_ts())
+        .expect("proposal with eligible vote accepted");
+
+    // Swapping the proof after signing invalidates the vote hash.
+    let member = wrap(PrivateKeySigner::random());
+    let mut vote = build_vote_with_eligibility_proof(
+        &proposal,
+        true,
+        &member,
+        issue_proof(&authority, &member),
+        now_ts(),
+    )
+    .unwrap();
+    vote.eligibility_proof = issue_proof(&authority, &wrap(PrivateKeySigner::random()));
+    let err = bob
+        .process_incoming_vote(&scope, vote, now_ts())
+        .unwrap_err();
+    assert!(matches!(err, ConsensusError::InvalidVoteHash));
+}
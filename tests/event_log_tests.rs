@@ -0,0 +1,102 @@
+mod common;
+use common::{now_ts, wrap};
+
+use alloy::signers::local::PrivateKeySigner;
+use hashgraph_like_consensus::{
+    events::{BroadcastEventBus, ConsensusEventBus, EventLog, InMemoryEventLog, LoggedEventBus},
+    scope::ScopeID,
+    service::ConsensusService,
+    signing::{ConsensusSignatureScheme, EthereumConsensusSigner},
+    storage::InMemoryConsensusStorage,
+    types::{ConsensusEvent, CreateProposalRequest},
+};
+
+const SCOPE_NAME: &str = "event_log_scope";
+const PROPOSAL_EXPIRATION_TIME: u64 = 60;
+
+type LoggedService = ConsensusService<
+    ScopeID,
+    InMemoryConsensusStorage<ScopeID>,
+    LoggedEventBus<ScopeID, BroadcastEventBus<ScopeID>, InMemoryEventLog<ScopeID>>,
+    EthereumConsensusSigner,
+>;
+
+fn logged_service(retention: usize) -> LoggedService {
+    ConsensusService::new_with_components(
+        InMemoryConsensusStorage::new(),
+        LoggedEventBus::new(
+            BroadcastEventBus::default(),
+            InMemoryEventLog::new(retention),
+        ),
+        wrap(PrivateKeySigner::random()),
+        10,
+    )
+}
+
+/// Create a single-voter proposal and decide it with the service's own vote.
+fn decide(service: &LoggedService, scope: &ScopeID, choice: bool) -> u64 {
+    let proposal = service
+        .create_proposal(
+            scope,
+            CreateProposalRequest::new(
+                "Logged".into(),
+                vec![],
+                service.signer().identity().to_vec(),
+                1,
+                PROPOSAL_EXPIRATION_TIME,
+                true,
+            )
+            .expect("valid proposal request"),
+            now_ts(),
+        )
+        .expect("proposal should be created");
+    service
+        .cast_vote(scope, proposal.proposal_id, choice, now_ts())
+        .expect("vote should succeed");
+    proposal.proposal_id
+}
+
+#[test]
+fn test_late_consumer_replays_events_in_order() {
+    let service = logged_service(100);
+    let scope = ScopeID::from(SCOPE_NAME);
+
+    let decided: Vec<(u64, bool)> = [true, false, true]
+        .into_iter()
+        .map(|choice| (decide(&service, &scope, choice), choice))
+        .collect();
+
+    // Subscribing now sees nothing from the live bus...
+    let live = service.event_bus().subscribe();
+    assert!(live.try_recv().is_err());
+
+    // ...but the log replays the exact stream.
+    let replayed = service.event_bus().replay_events(0).unwrap();
+    assert_eq!(replayed.len(), decided.len());
+    for (sequence, (logged, (proposal_id, result))) in replayed.iter().zip(&decided).enumerate() {
+        assert_eq!(logged.sequence, sequence as u64);
+        assert_eq!(logged.scope, scope);
+        assert!(matches!(
+            logged.event,
+            ConsensusEvent::ConsensusReached { proposal_id: id, result: r, .. }
+                if id == *proposal_id && r == *result
+        ));
+    }
+
+    // A consumer that already processed sequence 0 resumes from 1.
+    let resumed = service.event_bus().replay_events(1).unwrap();
+    assert_eq!(resumed, replayed[1..]);
+}
+
+#[test]
+fn test_event_log_retention_drops_oldest_events() {
+    let service = logged_service(2);
+    let scope = ScopeID::from(SCOPE_NAME);
+    for choice in [true, false, true] {
+        decide(&service, &scope, choice);
+    }
+
+    let retained = service.event_bus().log().replay_events(0).unwrap();
+    let sequences: Vec<u64> = retained.iter().map(|logged| logged.sequence).collect();
+    assert_eq!(sequences, vec![1, 2]);
+}
@@ -1,13 +1,21 @@
 mod common;
-use common::{cast_remote_vote, make_service, now_ts, owner_bytes, wrap};
+use common::{cast_remote_vote, make_service, make_service_for, now_ts, owner_bytes, wrap};
 
 use alloy::signers::local::PrivateKeySigner;
 use std::thread;
 use std::time::Duration;
 
 use hashgraph_like_consensus::{
-    error::ConsensusError, scope::ScopeID, session::ConsensusConfig, storage::ConsensusStorage,
-    types::CreateProposalRequest,
+    error::ConsensusError,
+    events::ConsensusEventBus,
+    protos::consensus::v1::Proposal,
+    scope::ScopeID,
+    service::DefaultConsensusService,
+    session::ConsensusConfig,
+    signing::EthereumConsensusSigner,
+    storage::ConsensusStorage,
+    types::{ConsensusEvent, CreateProposalRequest, SessionTransition},
+    utils::{build_vote, sign_proposal, validate_proposal},
 };
 
 const SCOPE: &str = "network_gossip_scope";
@@ -19,11 +27,11 @@ const EXPIRATION: u64 = 120;
 /// gossip votes back/forth, and both peers converge to Ok(true) consensus result.
 #[test]
 fn test_two_peers_gossip_reaches_unanimous_yes_for_n2() {
-    let peer_a = make_service();
+    let owner_a = PrivateKeySigner::random();
+    let peer_a = make_service_for(&owner_a);
     let peer_b = make_service();
     let scope = ScopeID::from(SCOPE);
 
-    let owner_a = PrivateKeySigner::random();
     let proposal = peer_a
         .create_proposal_with_config(
             &scope,
@@ -79,12 +87,12 @@ fn test_two_peers_gossip_reaches_unanimous_yes_for_n2() {
 /// but still converges to the same YES result.
 #[test]
 fn test_three_peers_gossip_converges_with_out_of_order_delivery() {
-    let peer_a = make_service();
+    let owner_a = PrivateKeySigner::random();
+    let peer_a = make_service_for(&owner_a);
     let peer_b = make_service();
     let peer_c = make_service();
     let scope = ScopeID::from(format!("{SCOPE}_3p"));
 
-    let owner_a = PrivateKeySigner::random();
     let proposal = peer_a
         .create_proposal_with_config(
             &scope,
@@ -157,13 +165,13 @@ fn test_three_peers_gossip_converges_with_out_of_order_delivery() {
 /// resulting in a 2 YES / 2 NO tie → no consensus.
 #[test]
 fn test_multi_peer_timeout_task_converges_to_failed() {
-    let peer_a = make_service();
+    let owner_a = PrivateKeySigner::random();
+    let peer_a = make_service_for(&owner_a);
     let peer_b = make_service();
     let peer_c = make_service();
     let scope = ScopeID::from(format!("{SCOPE}_timeout"));
 
     // n=4, liveness=false, 2 YES votes → 2 YES + 2 silent(NO) = tied → fail.
-    let owner_a = PrivateKeySigner::random();
     let voter_b = PrivateKeySigner::random();
     let proposal = peer_a
         .create_proposal_with_config(
@@ -257,14 +265,15 @@ fn test_multi_peer_timeout_task_converges_to_failed() {
 /// Result depends on the liveness criteria as we have 2 YES and 2 NO votes.
 #[test]
 fn test_multi_peer_timeout_task_resolves_tie_by_liveness_criteria_yes() {
-    let peer_a = make_service();
+    let owner_a = PrivateKeySigner::random();
+    let peer_a = make_service_for(&owner_a);
     let peer_b = make_service();
     let peer_c = make_service();
     let peer_d = make_service();
     let scope = ScopeID::from(format!("{SCOPE}_timeout_tie"));
 
-    // n=4, votes: 2 YES / 2 NO => tie. With liveness_criteria_yes=true, resolve to YES.
-    let owner_a = PrivateKeySigner::random();
+    // n=4, votes: YES, NO, NO, YES => tie. With liveness_criteria_yes=true, resolve to YES.
+    // The NO votes come in the middle so the weights stay tied until the last vote.
     let proposal = peer_a
         .create_proposal_with_config(
             &scope,
@@ -297,7 +306,7 @@ fn test_multi_peer_timeout_task_resolves_tie_by_liveness_criteria_yes() {
     }
 
     let voter_b = PrivateKeySigner::random();
-    let vote_b = cast_remote_vote(&peer_b, &scope, proposal.proposal_id, true, &wrap(voter_b))
+    let vote_b = cast_remote_vote(&peer_b, &scope, proposal.proposal_id, false, &wrap(voter_b))
         .expect("vote_b");
     for peer in [&peer_a, &peer_c, &peer_d] {
         peer.process_incoming_vote(&scope, vote_b.clone(), now_ts())
@@ -313,7 +322,7 @@ fn test_multi_peer_timeout_task_resolves_tie_by_liveness_criteria_yes() {
     }
 
     let voter_d = PrivateKeySigner::random();
-    let vote_d = cast_remote_vote(&peer_d, &scope, proposal.proposal_id, false, &wrap(voter_d))
+    let vote_d = cast_remote_vote(&peer_d, &scope, proposal.proposal_id, true, &wrap(voter_d))
         .expect("vote_d");
     for peer in [&peer_a, &peer_b, &peer_c] {
         peer.process_incoming_vote(&scope, vote_d.clone(), now_ts())
@@ -375,3 +384,702 @@ fn test_multi_peer_timeout_task_resolves_tie_by_liveness_criteria_yes() {
     assert!(res_c);
     assert!(res_d);
 }
+
+/// A vote that overtakes its proposal is held by a peer with the orphan vote
+/// buffer and applied once the proposal lands.
+#[test]
+fn test_vote_arriving_before_proposal_is_applied_from_orphan_buffer() {
+    let owner = PrivateKeySigner::random();
+    let peer_a = make_service_for(&owner);
+    let peer_b = make_service().with_orphan_vote_buffer(16, Duration::from_secs(EXPIRATION));
+    let peer_c = make_service();
+    let scope = ScopeID::from(SCOPE);
+
+    let proposal = peer_a
+        .create_proposal(
+            &scope,
+            CreateProposalRequest::new(
+                PROPOSAL_NAME.to_string(),
+                PROPOSAL_PAYLOAD,
+                owner_bytes(&owner),
+                3,
+                EXPIRATION,
+                true,
+            )
+            .expect("valid proposal request"),
+            now_ts(),
+        )
+        .expect("peer_a proposal");
+    cast_remote_vote(&peer_a, &scope, proposal.proposal_id, true, &wrap(owner))
+        .expect("owner vote");
+    let proposal_with_owner_vote = peer_a
+        .storage()
+        .get_proposal(&scope, proposal.proposal_id)
+        .unwrap();
+    let overtaking_vote = cast_remote_vote(
+        &peer_a,
+        &scope,
+        proposal.proposal_id,
+        true,
+        &wrap(PrivateKeySigner::random()),
+    )
+    .expect("second vote");
+
+    // Without the buffer the vote is simply rejected.
+    assert!(matches!(
+        peer_c.process_incoming_vote(&scope, overtaking_vote.clone(), now_ts()),
+        Err(ConsensusError::SessionNotFound)
+    ));
+
+    peer_b
+        .process_incoming_vote(&scope, overtaking_vote, now_ts())
+        .expect("orphan vote is held");
+    assert!(matches!(
+        peer_b.storage().get_proposal(&scope, proposal.proposal_id),
+        Err(ConsensusError::SessionNotFound)
+    ));
+
+    peer_b
+        .process_incoming_proposal(&scope, proposal_with_owner_vote, now_ts())
+        .expect("peer_b accepts proposal");
+    assert_eq!(
+        peer_b
+            .storage()
+            .get_proposal(&scope, proposal.proposal_id)
+            .unwrap()
+            .votes
+            .len(),
+        2
+    );
+    assert!(
+        peer_b
+            .storage()
+            .get_consensus_result(&scope, proposal.proposal_id)
+            .unwrap()
+    );
+}
+
+/// A gossiped proposal expiring in the year 9999 would pin a session forever;
+/// peers reject it unless their scope allows such a lifetime.
+#[test]
+fn test_far_future_expiration_is_rejected_on_ingest() {
+    const YEAR_9999: u64 = 253_402_300_799;
+    let owner = PrivateKeySigner::random();
+    let lenient_peer = make_service_for(&owner);
+    let peer = make_service();
+    let scope = ScopeID::from(SCOPE);
+    lenient_peer
+        .scope(&scope)
+        .unwrap()
+        .with_max_proposal_lifetime(Duration::from_secs(YEAR_9999))
+        .initialize()
+        .unwrap();
+
+    let now = now_ts();
+    let request = CreateProposalRequest::new(
+        PROPOSAL_NAME.to_string(),
+        PROPOSAL_PAYLOAD,
+        owner_bytes(&owner),
+        2,
+        YEAR_9999 - now,
+        true,
+    )
+    .expect("valid proposal request");
+    assert!(matches!(
+        peer.create_proposal(&scope, request.clone(), now),
+        Err(ConsensusError::InvalidTimeout)
+    ));
+    let proposal = lenient_peer
+        .create_proposal(&scope, request, now)
+        .expect("lifetime within the scope's limit");
+    assert_eq!(proposal.expiration_timestamp, YEAR_9999);
+
+    assert!(matches!(
+        validate_proposal::<EthereumConsensusSigner>(&proposal, now),
+        Err(ConsensusError::InvalidTimeout)
+    ));
+    assert!(matches!(
+        peer.process_incoming_proposal(&scope, proposal.clone(), now),
+        Err(ConsensusError::InvalidTimeout)
+    ));
+    assert!(
+        peer.storage()
+            .get_proposal(&scope, proposal.proposal_id)
+            .is_err()
+    );
+}
+
+/// Votes older than the scope's `max_vote_age` are refused even while the
+/// proposal is still open.
+#[test]
+fn test_vote_older_than_max_vote_age_is_rejected() {
+    let owner = PrivateKeySigner::random();
+    let strict_peer = make_service();
+    let peer = make_service_for(&owner);
+    let scope = ScopeID::from(SCOPE);
+    strict_peer
+        .scope(&scope)
+        .unwrap()
+        .with_max_vote_age(Duration::from_secs(30))
+        .initialize()
+        .unwrap();
+
+    let now = now_ts();
+    let request = CreateProposalRequest::new(
+        PROPOSAL_NAME.to_string(),
+        PROPOSAL_PAYLOAD,
+        owner_bytes(&owner),
+        3,
+        EXPIRATION,
+        true,
+    )
+    .expect("valid proposal request");
+    let proposal = peer
+        .create_proposal(&scope, request, now - 60)
+        .expect("proposal");
+    strict_peer
+        .process_incoming_proposal(&scope, proposal.clone(), now)
+        .expect("proposal without votes");
+
+    let vote = build_vote(&proposal, true, &wrap(owner), now - 45).expect("vote");
+    assert!(matches!(
+        strict_peer.process_incoming_vote(&scope, vote.clone(), now),
+        Err(ConsensusError::VoteTooOld {
+            age_secs: 45,
+            max_age_secs: 30
+        })
+    ));
+    peer.process_incoming_vote(&scope, vote, now)
+        .expect("within the default one-hour window");
+}
+
+/// Peers may refuse to record proposals created long ago, even if unexpired.
+#[test]
+fn test_stale_proposal_is_refused_when_max_age_is_set() {
+    let owner = PrivateKeySigner::random();
+    let origin = make_service_for(&owner);
+    let strict_peer = make_service();
+    let peer = make_service();
+    let scope = ScopeID::from(SCOPE);
+    strict_peer
+        .scope(&scope)
+        .unwrap()
+        .with_max_inbound_proposal_age(Duration::from_secs(600))
+        .initialize()
+        .unwrap();
+
+    let now = now_ts();
+    let created = now - 3600;
+    let proposal = origin
+        .create_proposal(
+            &scope,
+            CreateProposalRequest::new(
+                PROPOSAL_NAME.to_string(),
+                PROPOSAL_PAYLOAD,
+                owner_bytes(&owner),
+                2,
+                2 * 3600,
+                true,
+            )
+            .expect("valid proposal request"),
+            created,
+        )
+        .expect("origin proposal");
+
+    assert!(matches!(
+        strict_peer.process_incoming_proposal(&scope, proposal.clone(), now),
+        Err(ConsensusError::ProposalTooOld {
+            age_secs: 3600,
+            max_age_secs: 600,
+        })
+    ));
+    assert!(
+        strict_peer
+            .storage()
+            .get_proposal(&scope, proposal.proposal_id)
+            .is_err()
+    );
+
+    peer.process_incoming_proposal(&scope, proposal, now)
+        .expect("no age limit by default");
+}
+
+#[test]
+fn test_spoofed_proposal_owner_is_rejected() {
+    let owner = PrivateKeySigner::random();
+    let origin = make_service_for(&owner);
+    let peer = make_service();
+    let scope = ScopeID::from(SCOPE);
+    let proposal = origin
+        .create_proposal(
+            &scope,
+            CreateProposalRequest::new(
+                PROPOSAL_NAME.to_string(),
+                PROPOSAL_PAYLOAD,
+                owner_bytes(&owner),
+                3,
+                EXPIRATION,
+                true,
+            )
+            .expect("valid proposal request"),
+            now_ts(),
+        )
+        .expect("proposal");
+    assert!(!proposal.proposal_signature.is_empty());
+
+    // Claiming someone else's identity, altering a signed field, or dropping
+    // the signature all fail before anything is stored.
+    let mut spoofed = proposal.clone();
+    spoofed.proposal_owner = owner_bytes(&PrivateKeySigner::random());
+    let mut tampered = proposal.clone();
+    tampered.payload = b"send funds elsewhere".to_vec();
+    let mut unsigned = proposal.clone();
+    unsigned.proposal_signature.clear();
+    for forged in [spoofed, tampered, unsigned] {
+        assert!(matches!(
+            validate_proposal::<EthereumConsensusSigner>(&forged, now_ts()),
+            Err(ConsensusError::InvalidProposalSignature)
+        ));
+        assert!(matches!(
+            peer.process_incoming_proposal(&scope, forged, now_ts()),
+            Err(ConsensusError::InvalidProposalSignature)
+        ));
+    }
+    assert!(
+        peer.storage()
+            .get_proposal(&scope, proposal.proposal_id)
+            .is_err()
+    );
+
+    peer.process_incoming_proposal(&scope, proposal, now_ts())
+        .expect("genuine proposal accepted");
+}
+
+#[test]
+fn test_merge_proposal_unions_votes_after_partition() {
+    let owner = PrivateKeySigner::random();
+    let peer_a = make_service_for(&owner);
+    let peer_b = make_service();
+    let scope = ScopeID::from(SCOPE);
+    let now = now_ts();
+    let proposal = peer_a
+        .create_proposal(
+            &scope,
+            CreateProposalRequest::new(
+                PROPOSAL_NAME.to_string(),
+                PROPOSAL_PAYLOAD,
+                owner_bytes(&owner),
+                5,
+                EXPIRATION,
+                true,
+            )
+            .expect("valid proposal request"),
+            now,
+        )
+        .expect("proposal");
+    peer_b
+        .process_incoming_proposal(&scope, proposal.clone(), now)
+        .expect("gossip proposal");
+
+    // Both peers see the first vote; only B sees the second.
+    let voter1 = wrap(PrivateKeySigner::random());
+    let vote1 = build_vote(&proposal, true, &voter1, now).unwrap();
+    for peer in [&peer_a, &peer_b] {
+        peer.process_incoming_vote(&scope, vote1.clone(), now)
+            .expect("first vote");
+    }
+    let on_b = peer_b
+        .storage()
+        .get_proposal(&scope, proposal.proposal_id)
+        .unwrap();
+    let vote2 = build_vote(&on_b, false, &wrap(PrivateKeySigner::random()), now + 1).unwrap();
+    peer_b
+        .process_incoming_vote(&scope, vote2.clone(), now + 1)
+        .expect("second vote");
+    let remote = peer_b
+        .storage()
+        .get_proposal(&scope, proposal.proposal_id)
+        .unwrap();
+
+    // A signed proposal with a different header for the same ID is refused.
+    let header_changes: [fn(&mut Proposal); 5] = [
+        |p| p.name.push('!'),
+        |p| p.payload.push(0),
+        |p| p.expected_voters_count = 7,
+        |p| p.timestamp -= 1,
+        |p| p.liveness_criteria_yes = !p.liveness_criteria_yes,
+    ];
+    for change in header_changes {
+        let mut other_header = proposal.clone();
+        change(&mut other_header);
+        other_header.votes.clear();
+        sign_proposal(&mut other_header, &wrap(owner.clone())).unwrap();
+        assert!(matches!(
+            peer_a.merge_proposal(&scope, other_header, now + 1),
+            Err(ConsensusError::ProposalHeaderMismatch)
+        ));
+    }
+
+    // A copy carrying a conflicting vote from voter1 is equivocation.
+    let mut conflicting = proposal.clone();
+    conflicting
+        .votes
+        .push(build_vote(&proposal, false, &voter1, now).unwrap());
+    assert!(matches!(
+        peer_a.merge_proposal(&scope, conflicting, now + 1),
+        Err(ConsensusError::Equivocation { .. })
+    ));
+
+    assert_eq!(
+        peer_a
+            .merge_proposal(&scope, remote.clone(), now + 1)
+            .unwrap(),
+        SessionTransition::StillActive
+    );
+    let merged = peer_a
+        .storage()
+        .get_proposal(&scope, proposal.proposal_id)
+        .unwrap();
+    assert_eq!(merged.votes, remote.votes);
+    assert_eq!(merged.round, remote.round);
+
+    // Merging the same copy again has nothing new to apply.
+    assert_eq!(
+        peer_a.merge_proposal(&scope, remote, now + 1).unwrap(),
+        SessionTransition::StillActive
+    );
+    assert_eq!(
+        peer_a
+            .storage()
+            .get_proposal(&scope, proposal.proposal_id)
+            .unwrap()
+            .votes
+            .len(),
+        2
+    );
+}
+
+#[test]
+fn test_merge_proposal_joins_branches_cast_during_partition() {
+    let owner = PrivateKeySigner::random();
+    let peer_a = make_service_for(&owner);
+    let peer_b = make_service();
+    let scope = ScopeID::from(SCOPE);
+    let now = now_ts();
+    let proposal = peer_a
+        .create_proposal(
+            &scope,
+            CreateProposalRequest::new(
+                PROPOSAL_NAME.to_string(),
+                PROPOSAL_PAYLOAD,
+                owner_bytes(&owner),
+                7,
+                EXPIRATION,
+                true,
+            )
+            .expect("valid proposal request"),
+            now,
+        )
+        .expect("proposal");
+    peer_b
+        .process_incoming_proposal(&scope, proposal.clone(), now)
+        .expect("gossip proposal");
+    let vote1 = build_vote(&proposal, true, &wrap(PrivateKeySigner::random()), now).unwrap();
+    for peer in [&peer_a, &peer_b] {
+        peer.process_incoming_vote(&scope, vote1.clone(), now)
+            .expect("first vote");
+    }
+
+    // During the partition each side appends its own vote after vote1.
+    for (peer, ts) in [(&peer_a, now + 1), (&peer_b, now + 2)] {
+        let held = peer
+            .storage()
+            .get_proposal(&scope, proposal.proposal_id)
+            .unwrap();
+        let vote = build_vote(&held, false, &wrap(PrivateKeySigner::random()), ts).unwrap();
+        peer.process_incoming_vote(&scope, vote, ts)
+            .expect("partitioned vote");
+    }
+    let remote = peer_b
+        .storage()
+        .get_proposal(&scope, proposal.proposal_id)
+        .unwrap();
+
+    // Both branches link to vote1, so the union is not one chain but merges.
+    assert_eq!(
+        peer_a.merge_proposal(&scope, remote, now + 2).unwrap(),
+        SessionTransition::StillActive
+    );
+    let merged = peer_a
+        .storage()
+        .get_proposal(&scope, proposal.proposal_id)
+        .unwrap();
+    assert_eq!(merged.votes.len(), 3);
+    assert!(peer_a.verify_scope_integrity(&scope).unwrap().is_clean());
+
+    // A third peer accepts the merged copy, whether new to the proposal or
+    // merging it into the copy it already holds.
+    let fresh = make_service();
+    fresh
+        .process_incoming_proposal(&scope, merged.clone(), now + 2)
+        .expect("fresh peer ingests the merged copy");
+    let behind = make_service();
+    behind
+        .process_incoming_proposal(&scope, proposal.clone(), now)
+        .expect("gossip proposal");
+    behind
+        .process_incoming_vote(&scope, vote1.clone(), now)
+        .expect("first vote");
+    behind
+        .merge_proposal(&scope, merged.clone(), now + 2)
+        .expect("merged copy merges again");
+    for peer in [&fresh, &behind] {
+        let held = peer
+            .storage()
+            .get_proposal(&scope, proposal.proposal_id)
+            .unwrap();
+        assert_eq!(held.votes, merged.votes);
+        assert_eq!(held.round, merged.round);
+        assert!(peer.verify_scope_integrity(&scope).unwrap().is_clean());
+    }
+
+    // A batch built on the merged copy still extends it.
+    let next = build_vote(&merged, true, &wrap(PrivateKeySigner::random()), now + 3).unwrap();
+    peer_a
+        .process_incoming_votes(&scope, proposal.proposal_id, vec![next], now + 3)
+        .expect("batch after merge");
+}
+
+#[test]
+fn test_vote_events_cover_embedded_and_merged_votes() {
+    let owner = PrivateKeySigner::random();
+    let peer_a = make_service_for(&owner);
+    let peer_b = make_service().with_vote_events(true);
+    let events = peer_b.event_bus().subscribe();
+    let scope = ScopeID::from(SCOPE);
+    let now = now_ts();
+    let proposal = peer_a
+        .create_proposal(
+            &scope,
+            CreateProposalRequest::new(
+                PROPOSAL_NAME.to_string(),
+                PROPOSAL_PAYLOAD,
+                owner_bytes(&owner),
+                5,
+                EXPIRATION,
+                true,
+            )
+            .expect("valid proposal request"),
+            now,
+        )
+        .expect("proposal");
+    peer_a
+        .cast_vote(&scope, proposal.proposal_id, true, now)
+        .expect("owner vote");
+    cast_remote_vote(
+        &peer_a,
+        &scope,
+        proposal.proposal_id,
+        false,
+        &wrap(PrivateKeySigner::random()),
+    )
+    .expect("remote vote");
+    let gossiped = peer_a
+        .storage()
+        .get_proposal(&scope, proposal.proposal_id)
+        .unwrap();
+    let voters_added = || -> Vec<Vec<u8>> {
+        events
+            .try_iter()
+            .filter_map(|(_, event)| match event {
+                ConsensusEvent::VoteAdded { voter, .. } => Some(voter),
+                _ => None,
+            })
+            .collect()
+    };
+
+    // Votes embedded in a received proposal are reported like any other.
+    peer_b
+        .process_incoming_proposal(&scope, gossiped.clone(), now)
+        .expect("gossip proposal");
+    let embedded: Vec<_> = gossiped
+        .votes
+        .iter()
+        .map(|v| v.vote_owner.clone())
+        .collect();
+    assert_eq!(voters_added(), embedded);
+
+    // So are the votes a merge brings in, and only those.
+    let third = cast_remote_vote(
+        &peer_a,
+        &scope,
+        proposal.proposal_id,
+        true,
+        &wrap(PrivateKeySigner::random()),
+    )
+    .expect("third vote");
+    let remote = peer_a
+        .storage()
+        .get_proposal(&scope, proposal.proposal_id)
+        .unwrap();
+    peer_b
+        .merge_proposal(&scope, remote, now + 1)
+        .expect("merge");
+    assert_eq!(voters_added(), vec![third.vote_owner]);
+}
+
+#[test]
+fn test_vote_digest_sync_sends_only_missing_votes() {
+    let owner = PrivateKeySigner::random();
+    let peer_a = make_service_for(&owner);
+    let peer_b = make_service();
+    let scope = ScopeID::from(SCOPE);
+    let now = now_ts();
+    let proposal = peer_a
+        .create_proposal(
+            &scope,
+            CreateProposalRequest::new(
+                PROPOSAL_NAME.to_string(),
+                PROPOSAL_PAYLOAD,
+                owner_bytes(&owner),
+                5,
+                EXPIRATION,
+                true,
+            )
+            .expect("valid proposal request"),
+            now,
+        )
+        .expect("proposal");
+    let first = cast_remote_vote(
+        &peer_a,
+        &scope,
+        proposal.proposal_id,
+        true,
+        &wrap(PrivateKeySigner::random()),
+    )
+    .expect("first vote");
+    peer_b
+        .process_incoming_proposal(
+            &scope,
+            peer_a
+                .storage()
+                .get_proposal(&scope, proposal.proposal_id)
+                .unwrap(),
+            now,
+        )
+        .expect("gossip proposal");
+    let later: Vec<_> = (0..2)
+        .map(|_| {
+            cast_remote_vote(
+                &peer_a,
+                &scope,
+                proposal.proposal_id,
+                false,
+                &wrap(PrivateKeySigner::random()),
+            )
+            .expect("later vote")
+        })
+        .collect();
+
+    let digest = peer_b.vote_digest(&scope, proposal.proposal_id).unwrap();
+    assert_eq!(digest, vec![first.vote_id]);
+    let missing = peer_a
+        .collect_votes_not_in(&scope, proposal.proposal_id, &digest)
+        .unwrap();
+    assert_eq!(missing, later);
+
+    peer_b
+        .process_incoming_votes(&scope, proposal.proposal_id, missing, now_ts())
+        .expect("apply missing votes");
+    assert_eq!(
+        peer_b.vote_digest(&scope, proposal.proposal_id).unwrap(),
+        peer_a.vote_digest(&scope, proposal.proposal_id).unwrap()
+    );
+    assert!(
+        peer_a
+            .collect_votes_not_in(
+                &scope,
+                proposal.proposal_id,
+                &peer_b.vote_digest(&scope, proposal.proposal_id).unwrap()
+            )
+            .unwrap()
+            .is_empty()
+    );
+    assert!(matches!(
+        peer_b.vote_digest(&scope, proposal.proposal_id + 1),
+        Err(ConsensusError::SessionNotFound)
+    ));
+}
+
+#[test]
+fn test_session_state_digest_ignores_vote_arrival_order() {
+    let owner = PrivateKeySigner::random();
+    let peer_a = make_service_for(&owner);
+    let peer_b = make_service();
+    let scope = ScopeID::from(SCOPE);
+    let now = now_ts();
+    let proposal = peer_a
+        .create_proposal(
+            &scope,
+            CreateProposalRequest::new(
+                PROPOSAL_NAME.to_string(),
+                PROPOSAL_PAYLOAD,
+                owner_bytes(&owner),
+                5,
+                EXPIRATION,
+                true,
+            )
+            .expect("valid proposal request"),
+            now,
+        )
+        .expect("proposal");
+    peer_b
+        .process_incoming_proposal(&scope, proposal.clone(), now)
+        .expect("gossip proposal");
+    let votes: Vec<_> = [true, false, true]
+        .into_iter()
+        .map(|choice| {
+            build_vote(&proposal, choice, &wrap(PrivateKeySigner::random()), now).unwrap()
+        })
+        .collect();
+    let digest = |peer: &DefaultConsensusService| {
+        peer.session_state_digest(&scope, proposal.proposal_id)
+            .unwrap()
+    };
+    assert_eq!(digest(&peer_a), digest(&peer_b));
+
+    for vote in &votes {
+        peer_a
+            .process_incoming_vote(&scope, vote.clone(), now)
+            .unwrap();
+    }
+    for vote in votes.iter().rev() {
+        peer_b
+            .process_incoming_vote(&scope, vote.clone(), now)
+            .unwrap();
+    }
+    assert_ne!(
+        peer_a
+            .storage()
+            .get_proposal(&scope, proposal.proposal_id)
+            .unwrap()
+            .votes,
+        peer_b
+            .storage()
+            .get_proposal(&scope, proposal.proposal_id)
+            .unwrap()
+            .votes
+    );
+    assert_eq!(digest(&peer_a), digest(&peer_b));
+
+    // One extra vote on A makes the digests diverge.
+    cast_remote_vote(
+        &peer_a,
+        &scope,
+        proposal.proposal_id,
+        true,
+        &wrap(PrivateKeySigner::random()),
+    )
+    .unwrap();
+    assert_ne!(digest(&peer_a), digest(&peer_b));
+}
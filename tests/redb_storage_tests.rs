@@ -0,0 +1,225 @@
+#![cfg(feature = "redb")]
+
+mod common;
+use common::{now_ts, owner_bytes, wrap};
+
+use std::path::PathBuf;
+
+use alloy::signers::local::PrivateKeySigner;
+use hashgraph_like_consensus::{
+    error::ConsensusError,
+    events::BroadcastEventBus,
+    scope::ScopeID,
+    scope_config::NetworkType,
+    service::ConsensusService,
+    session::ConsensusState,
+    storage::{ConsensusStorage, RedbConsensusStorage},
+    types::CreateProposalRequest,
+    utils::build_vote,
+};
+
+const SCOPE: &str = "redb_scope";
+
+/// A fresh database path under the system temp dir, removed on drop.
+struct TempDb(PathBuf);
+
+impl TempDb {
+    fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!(
+            "hashgraph-like-consensus-{name}-{}-{}.redb",
+            std::process::id(),
+            now_ts()
+        ));
+        let _ = std::fs::remove_file(&path);
+        Self(path)
+    }
+}
+
+impl Drop for TempDb {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+fn make_request(signer: &PrivateKeySigner, name: &str) -> CreateProposalRequest {
+    CreateProposalRequest::new(name.to_string(), vec![], owner_bytes(signer), 3, 60, true)
+        .expect("valid proposal request")
+}
+
+#[test]
+fn test_sessions_and_configs_survive_reopen() {
+    let db = TempDb::new("reopen");
+    let signer = PrivateKeySigner::random();
+    let scope = ScopeID::from(SCOPE);
+
+    let proposal_id = {
+        let service = ConsensusService::new_with_components(
+            RedbConsensusStorage::<ScopeID>::open(&db.0).expect("open database"),
+            BroadcastEventBus::<ScopeID>::default(),
+            wrap(signer.clone()),
+            10,
+        );
+        service
+            .scope(&scope)
+            .unwrap()
+            .p2p_preset()
+            .initialize()
+            .unwrap();
+        let proposal = service
+            .create_proposal(&scope, make_request(&signer, "Persisted"), now_ts())
+            .expect("create proposal");
+        service
+            .cast_vote(&scope, proposal.proposal_id, true, now_ts())
+            .expect("cast vote");
+        proposal.proposal_id
+    };
+
+    let storage = RedbConsensusStorage::<ScopeID>::open(&db.0).expect("reopen database");
+    let session = storage
+        .get_session(&scope, proposal_id)
+        .unwrap()
+        .expect("session persisted");
+    assert_eq!(session.proposal.name, "Persisted");
+    assert!(session.has_voted(&owner_bytes(&signer)));
+    assert_eq!(
+        storage
+            .get_scope_config(&scope)
+            .unwrap()
+            .unwrap()
+            .network_type,
+        NetworkType::P2P
+    );
+    assert_eq!(storage.list_scopes().unwrap(), Some(vec![scope.clone()]));
+
+    // The reopened database keeps serving a running service.
+    let service = ConsensusService::new_with_components(
+        storage,
+        BroadcastEventBus::<ScopeID>::default(),
+        wrap(signer.clone()),
+        10,
+    );
+    assert!(
+        service
+            .cast_vote(&scope, proposal_id, false, now_ts())
+            .is_err(),
+        "the persisted vote still blocks a second one"
+    );
+}
+
+#[test]
+fn test_scope_operations_match_in_memory_semantics() {
+    let db = TempDb::new("scope-ops");
+    let signer = PrivateKeySigner::random();
+    let service = ConsensusService::new_with_components(
+        RedbConsensusStorage::<ScopeID>::open(&db.0).expect("open database"),
+        BroadcastEventBus::<ScopeID>::default(),
+        wrap(signer.clone()),
+        10,
+    );
+    let scope = ScopeID::from(SCOPE);
+    let renamed = ScopeID::from("redb_scope_renamed");
+
+    let mut ids: Vec<u64> = (0..3)
+        .map(|i| {
+            service
+                .create_proposal(&scope, make_request(&signer, &format!("P{i}")), now_ts())
+                .unwrap()
+                .proposal_id
+        })
+        .collect();
+    ids.sort_unstable();
+
+    let storage = service.storage();
+    let streamed: Vec<u64> = storage
+        .stream_scope_sessions(&scope)
+        .map(|session| session.unwrap().proposal.proposal_id)
+        .collect();
+    assert_eq!(streamed, ids);
+
+    storage
+        .update_session(&scope, ids[0], |session| {
+            session.proposal.name = "Edited".to_string();
+            Ok(())
+        })
+        .unwrap();
+    assert_eq!(
+        storage
+            .get_session(&scope, ids[0])
+            .unwrap()
+            .unwrap()
+            .proposal
+            .name,
+        "Edited"
+    );
+
+    storage.rename_scope(&scope, &renamed).unwrap();
+    assert!(storage.list_scope_sessions(&scope).unwrap().is_none());
+    let snapshot = storage.rollover_scope(&renamed).unwrap();
+    let rolled: Vec<u64> = snapshot
+        .sessions
+        .iter()
+        .map(|session| session.proposal.proposal_id)
+        .collect();
+    assert_eq!(rolled, ids);
+    assert!(storage.list_scopes().unwrap().is_none());
+}
+
+#[test]
+fn test_round_limit_failure_survives_transaction() {
+    let db = TempDb::new("round-limit");
+    let signer = PrivateKeySigner::random();
+    let scope = ScopeID::from(SCOPE);
+    let service = ConsensusService::new_with_components(
+        RedbConsensusStorage::<ScopeID>::open(&db.0).expect("open database"),
+        BroadcastEventBus::<ScopeID>::default(),
+        wrap(signer.clone()),
+        10,
+    );
+    service
+        .scope(&scope)
+        .unwrap()
+        .p2p_preset()
+        .with_max_rounds(Some(2))
+        .initialize()
+        .unwrap();
+    let request = CreateProposalRequest::new(
+        "Capped".to_string(),
+        vec![],
+        owner_bytes(&signer),
+        5,
+        60,
+        true,
+    )
+    .expect("valid proposal request");
+    let proposal_id = service
+        .create_proposal(&scope, request, now_ts())
+        .expect("create proposal")
+        .proposal_id;
+    service
+        .cast_vote(&scope, proposal_id, true, now_ts())
+        .expect("first vote");
+    for (choice, expect_ok) in [(false, true), (true, false)] {
+        let proposal = service.storage().get_proposal(&scope, proposal_id).unwrap();
+        let vote = build_vote(
+            &proposal,
+            choice,
+            &wrap(PrivateKeySigner::random()),
+            now_ts(),
+        )
+        .unwrap();
+        let result = service.process_incoming_vote(&scope, vote, now_ts());
+        assert_eq!(result.is_ok(), expect_ok);
+        if !expect_ok {
+            assert!(matches!(result, Err(ConsensusError::MaxRoundsExceeded)));
+        }
+    }
+
+    // The failure is committed, not rolled back with the rejected vote.
+    let session = service
+        .storage()
+        .get_session(&scope, proposal_id)
+        .unwrap()
+        .expect("session persisted");
+    assert_eq!(session.state, ConsensusState::Failed);
+    assert_eq!(session.votes().len(), 2);
+}
@@ -8,8 +8,10 @@ use prost::Message;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use hashgraph_like_consensus::{
+    clock::MockClock,
     error::ConsensusError,
     scope::ScopeID,
+    scope_config::NetworkType,
     session::ConsensusConfig,
     storage::ConsensusStorage,
     types::CreateProposalRequest,
@@ -437,7 +439,7 @@ fn test_gossipsub_batch_vote_processing() {
     .expect("valid proposal request");
 
     let mut proposal = request
-        .into_proposal(now_ts())
+        .into_signed_proposal(&wrap(proposal_owner), now_ts())
         .expect("proposal should be created");
 
     // Add votes to the proposal (simulating votes received from network)
@@ -492,6 +494,12 @@ fn test_gossipsub_batch_vote_processing() {
 fn test_p2p_batch_vote_processing() {
     let service = make_service();
     let scope = ScopeID::from("batch_p2p");
+    service
+        .scope(&scope)
+        .unwrap()
+        .with_network_type(NetworkType::P2P)
+        .initialize()
+        .unwrap();
     let proposal_owner = PrivateKeySigner::random();
 
     // Create a P2P proposal manually (simulating receiving from network)
@@ -506,7 +514,7 @@ fn test_p2p_batch_vote_processing() {
     .expect("valid proposal request");
 
     let mut proposal = request
-        .into_proposal(now_ts())
+        .into_signed_proposal(&wrap(proposal_owner.clone()), now_ts())
         .expect("proposal should be created");
 
     // Add votes up to the limit (6 votes)
@@ -545,17 +553,19 @@ fn test_p2p_batch_vote_processing() {
     // Verify that consensus prevents further votes (session is no longer active)
     // Try to add one more vote - should fail because consensus is already reached
     let voter7 = PrivateKeySigner::random();
-    let _vote_result = cast_remote_vote(
+    let vote_result = cast_remote_vote(
         &service,
         &scope,
         proposal.proposal_id,
         VOTE_YES,
         &wrap(voter7),
     );
+    assert!(
+        matches!(vote_result, Err(ConsensusError::SessionTerminal { .. })),
+        "P2P: Votes after consensus should be rejected as terminal"
+    );
 
-    // The vote might succeed (returns vote) but won't be added because session reached consensus
-    // Or it might fail with SessionNotActive
-    // Either way, verify the vote count doesn't increase and consensus result stays the same
+    // Verify the consensus result stays the same
     let final_consensus = service
         .storage()
         .get_consensus_result(&scope, proposal.proposal_id);
@@ -876,7 +886,8 @@ fn test_n_gt_2_consensus_requirements() {
 /// RFC Section 2.5.4: Test that expired proposals are rejected
 #[test]
 fn test_expired_proposal_rejected() {
-    let service = make_service();
+    let clock = MockClock::new(now_ts());
+    let service = make_service().with_clock(clock.clone());
     let scope = ScopeID::from(SCOPE);
     let proposal_owner = PrivateKeySigner::random();
 
@@ -893,20 +904,20 @@ fn test_expired_proposal_rejected() {
             )
             .expect("valid proposal request"),
             Some(ConsensusConfig::gossipsub()),
-            now_ts(),
+            service.now(),
         )
         .expect("proposal should be created");
 
-    std::thread::sleep(Duration::from_secs(EXPIRATION_WAIT_TIME_2_SECOND));
-    let voter = PrivateKeySigner::random();
-    let err = cast_remote_vote(
-        &service,
-        &scope,
-        proposal.proposal_id,
-        VOTE_YES,
-        &wrap(voter),
-    )
-    .expect_err("Should reject vote on expired proposal");
+    clock.advance(Duration::from_secs(EXPIRATION_WAIT_TIME_2_SECOND));
+    let voter = wrap(PrivateKeySigner::random());
+    let stored = service
+        .storage()
+        .get_proposal(&scope, proposal.proposal_id)
+        .unwrap();
+    let vote = build_vote(&stored, VOTE_YES, &voter, service.now()).unwrap();
+    let err = service
+        .process_incoming_vote(&scope, vote, service.now())
+        .expect_err("Should reject vote on expired proposal");
 
     // process_incoming_vote rejects via validate_vote (VoteExpired) when the
     // current timestamp is past expiration; the direct cast_vote path would
@@ -1027,7 +1038,7 @@ fn test_equality_of_votes_handling() {
         &service,
         &scope,
         proposal.proposal_id,
-        VOTE_YES,
+        VOTE_NO,
         &wrap(voter2),
     )
     .expect("second vote");
@@ -1047,13 +1058,14 @@ fn test_equality_of_votes_handling() {
         &service,
         &scope,
         proposal.proposal_id,
-        VOTE_NO,
+        VOTE_YES,
         &wrap(voter4),
     )
     .expect("fourth vote");
 
     std::thread::sleep(Duration::from_millis(EXPIRATION_WAIT_TIME));
-    // We have 4 votes: 2 YES, 2 NO (equality)
+    // We have 4 votes: 2 YES, 2 NO (equality). The NO votes come in the middle so
+    // silent-peer weighting cannot decide the session before the fourth vote.
     // With liveness_criteria_yes = true, should resolve to YES
     let result = service
         .storage()
@@ -1,11 +1,13 @@
 mod common;
-use common::{make_service, now_ts};
+use common::{make_service, make_service_for, now_ts};
 
 use std::time::Duration;
 
+use alloy::signers::local::PrivateKeySigner;
 use hashgraph_like_consensus::{
     error::ConsensusError, scope::ScopeID, scope_config::NetworkType, session::ConsensusConfig,
-    storage::ConsensusStorage, types::CreateProposalRequest,
+    signing::EthereumConsensusSigner, storage::ConsensusStorage, types::CreateProposalRequest,
+    utils::validate_proposal,
 };
 
 const SCOPE_NAME: &str = "test_scope";
@@ -347,3 +349,91 @@ fn test_scope_config_update_replaces_all_fields() {
     assert!(!built.default_liveness_criteria_yes);
     assert_eq!(built.max_rounds_override, Some(7));
 }
+
+#[test]
+fn test_validate_create_enforces_scope_payload_limit_without_storing() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE_NAME);
+    service
+        .scope(&scope)
+        .unwrap()
+        .with_max_payload_bytes(16)
+        .initialize()
+        .unwrap();
+
+    let request = |payload: Vec<u8>| {
+        CreateProposalRequest::new("Sized".into(), payload, vec![1, 2, 3], 3, 60, true)
+            .expect("valid proposal request")
+    };
+
+    let oversized = request(vec![0u8; 17]);
+    assert!(matches!(
+        service.validate_create(&scope, &oversized, now_ts()),
+        Err(ConsensusError::PayloadTooLarge { size: 17, max: 16 })
+    ));
+    assert!(matches!(
+        service.create_proposal(&scope, oversized, now_ts()),
+        Err(ConsensusError::PayloadTooLarge { .. })
+    ));
+
+    service
+        .validate_create(&scope, &request(vec![0u8; 16]), now_ts())
+        .expect("payload at the limit is accepted");
+    assert!(
+        service
+            .storage()
+            .get_active_proposals(&scope)
+            .unwrap()
+            .is_empty(),
+        "pre-validation must not persist anything"
+    );
+}
+
+#[test]
+fn test_incoming_proposal_payload_limit() {
+    let owner = PrivateKeySigner::random();
+    let sender = make_service_for(&owner);
+    let scope = ScopeID::from(SCOPE_NAME);
+    sender
+        .scope(&scope)
+        .unwrap()
+        .with_max_payload_bytes(0)
+        .initialize()
+        .unwrap();
+    let request = CreateProposalRequest::new(
+        "Large".into(),
+        vec![0u8; 64 * 1024 + 1],
+        owner.address().to_vec(),
+        3,
+        60,
+        true,
+    )
+    .expect("valid proposal request");
+    let proposal = sender
+        .create_proposal(&scope, request, now_ts())
+        .expect("a limit of 0 lifts the payload cap");
+
+    // The default 64 KiB limit applies on the gossip entry point and in the free validator.
+    assert!(matches!(
+        make_service().process_incoming_proposal(&scope, proposal.clone(), now_ts()),
+        Err(ConsensusError::PayloadTooLarge {
+            size: 65537,
+            max: 65536
+        })
+    ));
+    assert!(matches!(
+        validate_proposal::<EthereumConsensusSigner>(&proposal, now_ts()),
+        Err(ConsensusError::PayloadTooLarge { .. })
+    ));
+
+    let unlimited = make_service();
+    unlimited
+        .scope(&scope)
+        .unwrap()
+        .with_max_payload_bytes(0)
+        .initialize()
+        .unwrap();
+    unlimited
+        .process_incoming_proposal(&scope, proposal, now_ts())
+        .expect("unlimited scope accepts the proposal");
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "serde")]
+
+mod common;
+use common::{cast_remote_vote, make_service, now_ts, owner_bytes, wrap};
+
+use alloy::signers::local::PrivateKeySigner;
+use hashgraph_like_consensus::{
+    scope::ScopeID,
+    scope_config::{NetworkType, ScopeConfig},
+    session::{ConsensusConfig, ConsensusSession, ConsensusState},
+    storage::ConsensusStorage,
+    types::CreateProposalRequest,
+};
+
+const SCOPE: &str = "serde_scope";
+
+#[test]
+fn test_session_with_votes_round_trips_through_json() {
+    let service = make_service();
+    let scope = ScopeID::from(SCOPE);
+    let owner = PrivateKeySigner::random();
+    let request = CreateProposalRequest::new(
+        "Serde".into(),
+        b"payload".to_vec(),
+        owner_bytes(&owner),
+        4,
+        60,
+        true,
+    )
+    .expect("valid proposal request");
+    let proposal = service
+        .create_proposal(&scope, request, now_ts())
+        .expect("create proposal");
+    service
+        .cast_vote(&scope, proposal.proposal_id, true, now_ts())
+        .expect("own vote");
+    cast_remote_vote(
+        &service,
+        &scope,
+        proposal.proposal_id,
+        false,
+        &wrap(PrivateKeySigner::random()),
+    )
+    .expect("remote vote");
+
+    let session = service
+        .storage()
+        .get_session(&scope, proposal.proposal_id)
+        .unwrap()
+        .unwrap();
+    assert_eq!(session.votes().len(), 2);
+
+    let json = serde_json::to_string(&session).expect("serialize session");
+    let decoded: ConsensusSession = serde_json::from_str(&json).expect("deserialize session");
+
+    assert_eq!(decoded.proposal.proposal_id, session.proposal.proposal_id);
+    assert_eq!(decoded.proposal, session.proposal);
+    assert_eq!(decoded.votes(), session.votes());
+    assert_eq!(decoded.state, session.state);
+    assert_eq!(decoded.config, session.config);
+}
+
+#[test]
+fn test_states_and_configs_round_trip_through_json() {
+    for state in [
+        ConsensusState::Active,
+        ConsensusState::ConsensusReached(true),
+        ConsensusState::ConsensusReached(false),
+        ConsensusState::Failed,
+    ] {
+        let json = serde_json::to_string(&state).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ConsensusState>(&json).unwrap(),
+            state
+        );
+    }
+
+    let config = ConsensusConfig::p2p().with_threshold(0.75).unwrap();
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(
+        serde_json::from_str::<ConsensusConfig>(&json).unwrap(),
+        config
+    );
+
+    let scope_config = ScopeConfig {
+        network_type: NetworkType::P2P,
+        ..ScopeConfig::default()
+    };
+    let json = serde_json::to_string(&scope_config).unwrap();
+    let decoded: ScopeConfig = serde_json::from_str(&json).unwrap();
+    assert_eq!(decoded.network_type, NetworkType::P2P);
+    assert_eq!(
+        decoded.default_consensus_threshold,
+        scope_config.default_consensus_threshold
+    );
+}
@@ -0,0 +1,379 @@
+mod common;
+use common::{now_ts, owner_bytes, wrap};
+
+use std::sync::{
+    Arc, Mutex,
+    atomic::{AtomicBool, Ordering},
+};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use alloy::signers::local::PrivateKeySigner;
+
+use hashgraph_like_consensus::{
+    clock::MockClock,
+    error::ConsensusError,
+    events::BroadcastEventBus,
+    scope::ScopeID,
+    scope_config::ScopeConfig,
+    service::ConsensusService,
+    session::ConsensusSession,
+    storage::{ConsensusStorage, InMemoryConsensusStorage, ScopeSnapshot},
+    types::CreateProposalRequest,
+};
+
+const SCOPE: &str = "storage_failure_scope";
+
+/// In-memory storage whose session writes fail with `StorageBackend` while
+/// `failing` is set, as a database would when its connection drops. While
+/// `colliding` is set, every insert finds a session already stored, as if
+/// the generated proposal ID collided. Updates to the session in
+/// `failing_proposal` fail on their own, as a corrupt row would.
+#[derive(Clone, Default)]
+struct FaultyStorage {
+    inner: InMemoryConsensusStorage<ScopeID>,
+    failing: Arc<AtomicBool>,
+    colliding: Arc<AtomicBool>,
+    failing_proposal: Arc<Mutex<Option<u64>>>,
+}
+
+impl FaultyStorage {
+    fn check(&self) -> Result<(), ConsensusError> {
+        if self.failing.load(Ordering::SeqCst) {
+            let io = std::io::Error::new(std::io::ErrorKind::BrokenPipe, "connection lost");
+            return Err(ConsensusError::StorageBackend(Box::new(io)));
+        }
+        Ok(())
+    }
+}
+
+impl ConsensusStorage<ScopeID> for FaultyStorage {
+    fn save_session(
+        &self,
+        scope: &ScopeID,
+        session: ConsensusSession,
+    ) -> Result<(), ConsensusError> {
+        self.check()?;
+        self.inner.save_session(scope, session)
+    }
+
+    fn insert_session_if_absent(
+        &self,
+        scope: &ScopeID,
+        session: ConsensusSession,
+    ) -> Result<bool, ConsensusError> {
+        self.check()?;
+        if self.colliding.load(Ordering::SeqCst) {
+            return Ok(false);
+        }
+        self.inner.insert_session_if_absent(scope, session)
+    }
+
+    fn get_session(
+        &self,
+        scope: &ScopeID,
+        proposal_id: u64,
+    ) -> Result<Option<ConsensusSession>, ConsensusError> {
+        self.inner.get_session(scope, proposal_id)
+    }
+
+    fn remove_session(
+        &self,
+        scope: &ScopeID,
+        proposal_id: u64,
+    ) -> Result<Option<ConsensusSession>, ConsensusError> {
+        self.check()?;
+        self.inner.remove_session(scope, proposal_id)
+    }
+
+    fn list_scope_sessions(
+        &self,
+        scope: &ScopeID,
+    ) -> Result<Option<Vec<ConsensusSession>>, ConsensusError> {
+        self.inner.list_scope_sessions(scope)
+    }
+
+    fn stream_scope_sessions(
+        &self,
+        scope: &ScopeID,
+    ) -> impl Iterator<Item = Result<ConsensusSession, ConsensusError>> {
+        self.inner.stream_scope_sessions(scope)
+    }
+
+    fn replace_scope_sessions(
+        &self,
+        scope: &ScopeID,
+        sessions: Vec<ConsensusSession>,
+    ) -> Result<(), ConsensusError> {
+        self.check()?;
+        self.inner.replace_scope_sessions(scope, sessions)
+    }
+
+    fn list_scopes(&self) -> Result<Option<Vec<ScopeID>>, ConsensusError> {
+        self.inner.list_scopes()
+    }
+
+    fn update_session<R, F>(
+        &self,
+        scope: &ScopeID,
+        proposal_id: u64,
+        mutator: F,
+    ) -> Result<R, ConsensusError>
+    where
+        F: FnOnce(&mut ConsensusSession) -> Result<R, ConsensusError>,
+    {
+        self.check()?;
+        if *self.failing_proposal.lock().unwrap() == Some(proposal_id) {
+            return Err(ConsensusError::SessionNotFound);
+        }
+        self.inner.update_session(scope, proposal_id, mutator)
+    }
+
+    fn update_scope_sessions<F>(&self, scope: &ScopeID, mutator: F) -> Result<(), ConsensusError>
+    where
+        F: FnOnce(&mut Vec<ConsensusSession>) -> Result<(), ConsensusError>,
+    {
+        self.check()?;
+        self.inner.update_scope_sessions(scope, mutator)
+    }
+
+    fn get_scope_config(&self, scope: &ScopeID) -> Result<Option<ScopeConfig>, ConsensusError> {
+        self.inner.get_scope_config(scope)
+    }
+
+    fn set_scope_config(&self, scope: &ScopeID, config: ScopeConfig) -> Result<(), ConsensusError> {
+        self.check()?;
+        self.inner.set_scope_config(scope, config)
+    }
+
+    fn delete_scope(&self, scope: &ScopeID) -> Result<(), ConsensusError> {
+        self.check()?;
+        self.inner.delete_scope(scope)
+    }
+
+    fn rename_scope(&self, old: &ScopeID, new: &ScopeID) -> Result<(), ConsensusError> {
+        self.check()?;
+        self.inner.rename_scope(old, new)
+    }
+
+    fn rollover_scope(&self, scope: &ScopeID) -> Result<ScopeSnapshot, ConsensusError> {
+        self.check()?;
+        self.inner.rollover_scope(scope)
+    }
+
+    fn update_scope_config<F>(&self, scope: &ScopeID, updater: F) -> Result<(), ConsensusError>
+    where
+        F: FnOnce(&mut ScopeConfig) -> Result<(), ConsensusError>,
+    {
+        self.check()?;
+        self.inner.update_scope_config(scope, updater)
+    }
+
+    fn is_scope_paused(&self, scope: &ScopeID) -> Result<bool, ConsensusError> {
+        self.inner.is_scope_paused(scope)
+    }
+
+    fn set_scope_paused(&self, scope: &ScopeID, paused: bool) -> Result<(), ConsensusError> {
+        self.check()?;
+        self.inner.set_scope_paused(scope, paused)
+    }
+}
+
+#[test]
+fn test_storage_backend_failure_surfaces_from_service() {
+    let storage = FaultyStorage::default();
+    let signer = PrivateKeySigner::random();
+    let service = ConsensusService::new_with_components(
+        storage.clone(),
+        BroadcastEventBus::<ScopeID>::default(),
+        wrap(signer.clone()),
+        10,
+    );
+    let scope = ScopeID::from(SCOPE);
+    let request = CreateProposalRequest::new(
+        "Faulty storage".to_string(),
+        vec![],
+        owner_bytes(&signer),
+        3,
+        60,
+        true,
+    )
+    .expect("valid proposal request");
+
+    let proposal = service
+        .create_proposal(&scope, request.clone(), now_ts())
+        .expect("healthy backend");
+
+    storage.failing.store(true, Ordering::SeqCst);
+    let err = service
+        .create_proposal(&scope, request, now_ts())
+        .unwrap_err();
+    assert!(matches!(err, ConsensusError::StorageBackend(_)));
+    assert_eq!(err.to_string(), "Storage backend failure: connection lost");
+    let err = service
+        .cast_vote(&scope, proposal.proposal_id, true, now_ts())
+        .unwrap_err();
+    assert!(matches!(err, ConsensusError::StorageBackend(_)));
+    assert!(std::error::Error::source(&err).is_some());
+
+    // Once the backend recovers, the untouched session is usable again.
+    storage.failing.store(false, Ordering::SeqCst);
+    service
+        .cast_vote(&scope, proposal.proposal_id, true, now_ts())
+        .expect("vote after recovery");
+    assert!(
+        service
+            .storage()
+            .get_session(&scope, proposal.proposal_id)
+            .unwrap()
+            .unwrap()
+            .has_voted(&owner_bytes(&signer))
+    );
+}
+
+#[test]
+fn test_colliding_proposal_id_does_not_overwrite_on_create() {
+    let storage = FaultyStorage::default();
+    let signer = PrivateKeySigner::random();
+    let service = ConsensusService::new_with_components(
+        storage.clone(),
+        BroadcastEventBus::<ScopeID>::default(),
+        wrap(signer.clone()),
+        10,
+    );
+    let scope = ScopeID::from(SCOPE);
+    let request = CreateProposalRequest::new(
+        "Colliding".to_string(),
+        vec![],
+        owner_bytes(&signer),
+        3,
+        60,
+        true,
+    )
+    .expect("valid proposal request");
+
+    storage.colliding.store(true, Ordering::SeqCst);
+    let err = service
+        .create_proposal(&scope, request.clone(), now_ts())
+        .unwrap_err();
+    assert!(matches!(err, ConsensusError::ProposalAlreadyExist));
+    assert!(
+        service
+            .storage()
+            .list_scope_sessions(&scope)
+            .unwrap()
+            .is_none()
+    );
+
+    storage.colliding.store(false, Ordering::SeqCst);
+    service
+        .create_proposal(&scope, request, now_ts())
+        .expect("retry with a fresh id");
+}
+
+#[test]
+fn test_expiry_sweep_continues_past_a_failing_session() {
+    let storage = FaultyStorage::default();
+    let signer = PrivateKeySigner::random();
+    let service = ConsensusService::new_with_components(
+        storage.clone(),
+        BroadcastEventBus::<ScopeID>::default(),
+        wrap(signer.clone()),
+        10,
+    );
+    let scope = ScopeID::from(SCOPE);
+    let request = CreateProposalRequest::new(
+        "Expiring".to_string(),
+        vec![],
+        owner_bytes(&signer),
+        3,
+        60,
+        true,
+    )
+    .expect("valid proposal request");
+    let mut ids: Vec<u64> = (0..3)
+        .map(|_| {
+            service
+                .create_proposal(&scope, request.clone(), now_ts())
+                .expect("healthy backend")
+                .proposal_id
+        })
+        .collect();
+    ids.sort_unstable();
+    let broken = ids[0];
+    *storage.failing_proposal.lock().unwrap() = Some(broken);
+
+    // The first session in the sweep fails; the others are still finalized.
+    let later = now_ts() + 61;
+    let sweep = service.finalize_expired(&scope, later).unwrap();
+    assert_eq!(
+        sweep.finalized,
+        vec![(ids[1], Some(true)), (ids[2], Some(true))]
+    );
+    assert_eq!(sweep.errors.len(), 1);
+    assert!(matches!(
+        sweep.errors[0],
+        (id, ConsensusError::SessionNotFound) if id == broken
+    ));
+    assert!(
+        service
+            .storage()
+            .get_session(&scope, broken)
+            .unwrap()
+            .unwrap()
+            .is_active()
+    );
+
+    // The session left active is retried by the next sweep.
+    *storage.failing_proposal.lock().unwrap() = None;
+    let swept = service.finalize_all_expired(later).unwrap();
+    assert_eq!(swept.len(), 1);
+    let (swept_scope, sweep) = &swept[0];
+    assert_eq!(swept_scope, &scope);
+    assert_eq!(
+        sweep.as_ref().unwrap().finalized,
+        vec![(broken, Some(true))]
+    );
+}
+
+#[test]
+fn test_reaper_keeps_last_sweep_error() {
+    let storage = FaultyStorage::default();
+    let signer = PrivateKeySigner::random();
+    let clock = MockClock::new(now_ts());
+    let service = ConsensusService::new_with_components(
+        storage.clone(),
+        BroadcastEventBus::<ScopeID>::default(),
+        wrap(signer.clone()),
+        10,
+    )
+    .with_clock(clock.clone());
+    let scope = ScopeID::from(SCOPE);
+    let request = CreateProposalRequest::new(
+        "Expiring".to_string(),
+        vec![],
+        owner_bytes(&signer),
+        3,
+        60,
+        true,
+    )
+    .expect("valid proposal request");
+    let broken = service
+        .create_proposal(&scope, request, service.now())
+        .expect("healthy backend")
+        .proposal_id;
+    *storage.failing_proposal.lock().unwrap() = Some(broken);
+    clock.advance(Duration::from_secs(61));
+
+    let reaper = service.spawn_reaper(Duration::from_millis(10));
+    let deadline = Instant::now() + Duration::from_secs(5);
+    let err = loop {
+        if let Some(err) = reaper.take_last_error() {
+            break err;
+        }
+        assert!(Instant::now() < deadline, "reaper reports the failed sweep");
+        thread::sleep(Duration::from_millis(10));
+    };
+    assert!(matches!(err, ConsensusError::SessionNotFound));
+    reaper.abort();
+}
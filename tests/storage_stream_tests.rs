@@ -53,7 +53,7 @@ fn test_stream_scope_sessions_yields_all_sessions_in_scope() {
         .save_session(&scope, session2.clone())
         .expect("save session2");
 
-    let mut got_ids: Vec<u32> = storage
+    let mut got_ids: Vec<u64> = storage
         .stream_scope_sessions(&scope)
         .map(|r| r.expect("stream item").proposal.proposal_id)
         .collect();
@@ -146,7 +146,7 @@ fn test_update_session_and_update_scope_sessions_error_and_cleanup_paths() {
 
     // update_session missing path.
     let err = storage
-        .update_session(&scope, u32::MAX, |_session| Ok(()))
+        .update_session(&scope, u64::MAX, |_session| Ok(()))
         .expect_err("missing session should fail");
     assert!(matches!(
         err,
@@ -204,6 +204,7 @@ fn test_scope_config_storage_validation_and_updates() {
         default_timeout: std::time::Duration::from_secs(60),
         default_liveness_criteria_yes: true,
         max_rounds_override: Some(0),
+        ..ScopeConfig::default()
     };
     let err = storage
         .set_scope_config(&scope, invalid)
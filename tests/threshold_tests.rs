@@ -2,7 +2,12 @@ use std::collections::HashMap;
 
 use hashgraph_like_consensus::{
     protos::consensus::v1::Vote,
-    utils::{calculate_consensus_result, has_sufficient_votes},
+    scope_config::ThresholdMode,
+    types::VoteChoice,
+    utils::{
+        calculate_consensus_result, calculate_weighted_consensus_result, has_sufficient_votes,
+    },
+    weights::VoterWeights,
 };
 
 #[test]
@@ -39,7 +44,7 @@ fn test_two_thirds_threshold_rounding() {
 
 #[test]
 fn test_calculate_consensus_result_variants() {
-    let yes_vote = |id: u32| Vote {
+    let yes_vote = |id: u64| Vote {
         vote_id: id,
         vote_owner: vec![id as u8],
         proposal_id: 1,
@@ -49,8 +54,10 @@ fn test_calculate_consensus_result_variants() {
         received_hash: vec![],
         vote_hash: vec![id as u8],
         signature: vec![],
+        eligibility_proof: vec![],
+        abstain: false,
     };
-    let no_vote = |id: u32| Vote {
+    let no_vote = |id: u64| Vote {
         vote: false,
         vote_hash: vec![id as u8],
         ..yes_vote(id)
@@ -163,3 +170,210 @@ fn test_calculate_consensus_result_variants() {
         None
     );
 }
+
+/// Smallest turnout that `has_sufficient_votes` accepts for `n > 2` voters.
+fn required_votes(expected_voters: u32, threshold: f64) -> u32 {
+    (0..=expected_voters)
+        .find(|&votes| has_sufficient_votes(votes, expected_voters, threshold))
+        .unwrap_or(expected_voters + 1)
+}
+
+#[test]
+fn test_thresholds_near_two_thirds_follow_exact_ceiling() {
+    // 0.6666666 = 3333333/5000000 and 0.6667 = 6667/10000 are taken as written;
+    // 2.0 / 3.0 is exactly 2/3. Each requires ceil(n * threshold) exactly.
+    let cases: [(f64, u128, u128); 3] = [
+        (0.6666666, 3_333_333, 5_000_000),
+        (2.0 / 3.0, 2, 3),
+        (0.6667, 6_667, 10_000),
+    ];
+    for n in [3u32, 4, 5, 6, 9, 10, 30, 100, 3_000, 10_000_000, u32::MAX] {
+        let mut previous = 0;
+        for (threshold, numerator, denominator) in cases {
+            let expected = (u128::from(n) * numerator).div_ceil(denominator) as u32;
+            let required = if n <= 100 {
+                required_votes(n, threshold)
+            } else {
+                expected
+            };
+            assert_eq!(required, expected, "n = {n}, threshold = {threshold}");
+            assert!(has_sufficient_votes(expected, n, threshold));
+            assert!(!has_sufficient_votes(expected - 1, n, threshold));
+            assert!(required >= previous, "not monotonic at n = {n}");
+            previous = required;
+        }
+    }
+
+    // Decimal thresholds have no float artefacts: 0.1 of 30 is 3, 0.9 of 10 is 9.
+    assert_eq!(required_votes(30, 0.1), 3);
+    assert_eq!(required_votes(10, 0.9), 9);
+}
+
+#[test]
+fn test_absolute_yes_threshold_ignores_expected_voters_fraction() {
+    let vote = |id: u8, choice: bool| Vote {
+        vote_id: u64::from(id),
+        vote_owner: vec![id],
+        proposal_id: 1,
+        timestamp: 0,
+        vote: choice,
+        parent_hash: vec![],
+        received_hash: vec![],
+        vote_hash: vec![id],
+        signature: vec![],
+        eligibility_proof: vec![],
+        abstain: false,
+    };
+    let mode = ThresholdMode::AbsoluteYes(3);
+    let mut votes: HashMap<Vec<u8>, Vote> = HashMap::new();
+
+    // 2 of 10 YES: undecided, while a 2/3 fraction would need 7 voters first.
+    votes.insert(vec![1], vote(1, true));
+    votes.insert(vec![2], vote(2, true));
+    assert_eq!(
+        calculate_consensus_result(&votes, 10, mode, true, false),
+        None
+    );
+
+    // The third YES decides, with 7 peers silent.
+    votes.insert(vec![3], vote(3, true));
+    assert_eq!(
+        calculate_consensus_result(&votes, 10, mode, false, false),
+        Some(true)
+    );
+
+    // NO once fewer than 3 peers could still vote YES: 8 NO of 10.
+    votes.clear();
+    for id in 1..=7 {
+        votes.insert(vec![id], vote(id, false));
+    }
+    assert_eq!(
+        calculate_consensus_result(&votes, 10, mode, true, false),
+        None
+    );
+    votes.insert(vec![8], vote(8, false));
+    assert_eq!(
+        calculate_consensus_result(&votes, 10, mode, true, false),
+        Some(false)
+    );
+
+    // At timeout silent peers follow the liveness side.
+    votes.clear();
+    votes.insert(vec![1], vote(1, true));
+    assert_eq!(
+        calculate_consensus_result(&votes, 10, mode, true, true),
+        Some(true)
+    );
+    assert_eq!(
+        calculate_consensus_result(&votes, 10, mode, false, true),
+        Some(false)
+    );
+
+    // Either 3 YES or 8 NO can decide, so 3 votes may suffice.
+    assert!(!has_sufficient_votes(2, 10, mode));
+    assert!(has_sufficient_votes(3, 10, mode));
+}
+
+#[test]
+fn test_weighted_result_sums_voter_weights() {
+    let vote = |id: u8, choice: bool| Vote {
+        vote_id: u64::from(id),
+        vote_owner: vec![id],
+        proposal_id: 1,
+        timestamp: 0,
+        vote: choice,
+        parent_hash: vec![],
+        received_hash: vec![],
+        vote_hash: vec![id],
+        signature: vec![],
+        eligibility_proof: vec![],
+        abstain: false,
+    };
+    let weights = VoterWeights::new().with_weight(vec![1], 10);
+    assert_eq!(weights.total_weight(4), 13);
+
+    // Three NOs weigh 3 of 13: below the 2/3 turnout of 9.
+    let mut votes: HashMap<Vec<u8>, Vote> = HashMap::new();
+    for id in 2..=4 {
+        votes.insert(vec![id], vote(id, false));
+    }
+    assert_eq!(
+        calculate_weighted_consensus_result(&votes, 4, &weights, 2.0 / 3.0, false, false),
+        None
+    );
+    // Unweighted, the same three NOs of four voters already decide NO.
+    assert_eq!(
+        calculate_consensus_result(&votes, 4, 2.0 / 3.0, false, false),
+        Some(false)
+    );
+
+    // The single weight-10 YES outvotes them.
+    votes.insert(vec![1], vote(1, true));
+    assert_eq!(
+        calculate_weighted_consensus_result(&votes, 4, &weights, 2.0 / 3.0, false, false),
+        Some(true)
+    );
+
+    // All weights 1: identical to the unweighted result.
+    let unit: VoterWeights = (1..=4u8).map(|id| (vec![id], 1)).collect();
+    for liveness in [true, false] {
+        for is_timeout in [true, false] {
+            assert_eq!(
+                calculate_weighted_consensus_result(
+                    &votes,
+                    4,
+                    &unit,
+                    2.0 / 3.0,
+                    liveness,
+                    is_timeout
+                ),
+                calculate_consensus_result(&votes, 4, 2.0 / 3.0, liveness, is_timeout)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_abstentions_count_toward_turnout_but_neither_side() {
+    use VoteChoice::{Abstain, No, Yes};
+
+    let vote = |id: u8, choice: VoteChoice| Vote {
+        vote_id: u64::from(id),
+        vote_owner: vec![id],
+        proposal_id: 1,
+        timestamp: 0,
+        vote: choice == VoteChoice::Yes,
+        parent_hash: vec![],
+        received_hash: vec![],
+        vote_hash: vec![id],
+        signature: vec![],
+        eligibility_proof: vec![],
+        abstain: choice == VoteChoice::Abstain,
+    };
+    let votes_of = |choices: &[VoteChoice]| -> HashMap<Vec<u8>, Vote> {
+        (1..)
+            .zip(choices)
+            .map(|(id, &choice)| (vec![id], vote(id, choice)))
+            .collect()
+    };
+    // 4 voters need a turnout of 3: an abstention completes it.
+    assert_eq!(
+        calculate_consensus_result(&votes_of(&[Yes, Yes]), 4, 2.0 / 3.0, true, false),
+        None
+    );
+    assert_eq!(
+        calculate_consensus_result(&votes_of(&[Yes, Yes, Abstain]), 4, 2.0 / 3.0, true, false),
+        Some(true)
+    );
+
+    // An abstention is not a NO: with silent peers weighted NO, a NO in its
+    // place would decide NO, while the abstention leaves the sides tied.
+    assert_eq!(
+        calculate_consensus_result(&votes_of(&[Yes, No]), 3, 2.0 / 3.0, false, false),
+        Some(false)
+    );
+    assert_eq!(
+        calculate_consensus_result(&votes_of(&[Yes, Abstain]), 3, 2.0 / 3.0, false, false),
+        None
+    );
+}
@@ -0,0 +1,77 @@
+mod common;
+use common::{make_service, now_ts, owner_bytes, wrap};
+
+use alloy::signers::local::PrivateKeySigner;
+use hashgraph_like_consensus::{
+    error::ConsensusError,
+    scope::ScopeID,
+    service::DefaultConsensusService,
+    timestamp_policy::{RfcTimestampPolicy, TimestampPolicy},
+    types::CreateProposalRequest,
+    utils::build_vote,
+};
+
+const SCOPE_NAME: &str = "timestamp_policy_scope";
+const EXPECTED_VOTERS_COUNT: u32 = 5;
+const PROPOSAL_EXPIRATION_TIME: u64 = 3600;
+const FRESHNESS_WINDOW: u64 = 5 * 60;
+
+/// RFC rules plus: a vote must arrive within five minutes of being cast.
+struct FreshnessWindow;
+
+impl TimestampPolicy for FreshnessWindow {
+    fn validate(
+        &self,
+        vote_timestamp: u64,
+        creation_time: u64,
+        expiration_timestamp: u64,
+        now: u64,
+    ) -> Result<(), ConsensusError> {
+        RfcTimestampPolicy.validate(vote_timestamp, creation_time, expiration_timestamp, now)?;
+        if now.saturating_sub(vote_timestamp) > FRESHNESS_WINDOW {
+            return Err(ConsensusError::InvalidVoteTimestamp);
+        }
+        Ok(())
+    }
+}
+
+/// Create a proposal, then deliver a vote cast at creation time `delay` seconds later.
+fn deliver_vote_after(service: &DefaultConsensusService, delay: u64) -> Result<(), ConsensusError> {
+    let scope = ScopeID::from(SCOPE_NAME);
+    let owner = PrivateKeySigner::random();
+    let now = now_ts();
+    let proposal = service
+        .create_proposal(
+            &scope,
+            CreateProposalRequest::new(
+                "Freshness".into(),
+                vec![],
+                owner_bytes(&owner),
+                EXPECTED_VOTERS_COUNT,
+                PROPOSAL_EXPIRATION_TIME,
+                true,
+            )
+            .expect("valid proposal request"),
+            now,
+        )
+        .expect("proposal should be created");
+    let vote = build_vote(&proposal, true, &wrap(owner), now).expect("vote");
+    service.process_incoming_vote(&scope, vote, now + delay)
+}
+
+#[test]
+fn test_custom_policy_enforces_freshness_window() {
+    let service = make_service().with_timestamp_policy(FreshnessWindow);
+
+    deliver_vote_after(&service, 10).expect("fresh vote accepted");
+    assert!(matches!(
+        deliver_vote_after(&service, FRESHNESS_WINDOW + 1),
+        Err(ConsensusError::InvalidVoteTimestamp)
+    ));
+}
+
+#[test]
+fn test_default_policy_accepts_stale_vote_before_expiration() {
+    let service = make_service();
+    deliver_vote_after(&service, FRESHNESS_WINDOW + 1).expect("vote accepted until expiration");
+}
@@ -8,8 +8,9 @@ use hashgraph_like_consensus::{
     service::DefaultConsensusService,
     session::ConsensusConfig,
     signing::{ConsensusSignatureScheme, EthereumConsensusSigner},
+    storage::ConsensusStorage,
     types::CreateProposalRequest,
-    utils::{build_vote, validate_proposal},
+    utils::{build_vote, build_vote_from_session, validate_proposal},
 };
 
 const SCOPE: &str = "vote_scope";
@@ -112,3 +113,51 @@ fn test_parent_hash_for_same_voter() {
     validate_proposal::<EthereumConsensusSigner>(&proposal_with_vote, now_ts())
         .expect("proposal with parent hash chain should validate");
 }
+
+#[test]
+fn test_build_vote_from_session_links_like_build_vote() {
+    let proposal_owner = wrap(PrivateKeySigner::random());
+    let voter = wrap(PrivateKeySigner::random());
+    let service = DefaultConsensusService::new(proposal_owner.clone());
+    let scope = ScopeID::from(SCOPE);
+
+    let proposal = service
+        .create_proposal_with_config(
+            &scope,
+            CreateProposalRequest::new(
+                PROPOSAL_NAME.to_string(),
+                PROPOSAL_PAYLOAD,
+                proposal_owner.identity().to_vec(),
+                EXPECTED_VOTERS_COUNT,
+                EXPIRATION,
+                true,
+            )
+            .expect("valid proposal request"),
+            Some(ConsensusConfig::gossipsub()),
+            now_ts(),
+        )
+        .expect("proposal");
+    service
+        .cast_vote(&scope, proposal.proposal_id, VOTE_YES, now_ts())
+        .expect("proposal_owner vote");
+    let session = service
+        .storage()
+        .get_session(&scope, proposal.proposal_id)
+        .expect("storage read")
+        .expect("session");
+
+    // One voter with an earlier vote (parent link), one without.
+    for signer in [&proposal_owner, &voter] {
+        let from_session =
+            build_vote_from_session(&session, VOTE_NO, signer, now_ts()).expect("session vote");
+        let from_proposal =
+            build_vote(&session.proposal, VOTE_NO, signer, now_ts()).expect("proposal vote");
+        assert_eq!(from_session.parent_hash, from_proposal.parent_hash);
+        assert_eq!(from_session.received_hash, from_proposal.received_hash);
+
+        let mut proposal_with_vote = session.proposal.clone();
+        proposal_with_vote.votes.push(from_session);
+        validate_proposal::<EthereumConsensusSigner>(&proposal_with_vote, now_ts())
+            .expect("session-built vote should validate");
+    }
+}
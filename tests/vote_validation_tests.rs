@@ -1,6 +1,7 @@
 mod common;
-use common::{cast_remote_vote_and_get_proposal, make_service, now_ts, owner_bytes, wrap};
+use common::{cast_remote_vote_and_get_proposal, make_service_for, now_ts, owner_bytes, wrap};
 
+use alloy::primitives::{Signature, U256};
 use alloy::signers::{SignerSync, local::PrivateKeySigner};
 use hashgraph_like_consensus::signing::EthereumConsensusSigner;
 
@@ -11,7 +12,7 @@ use hashgraph_like_consensus::{
     scope::ScopeID,
     session::ConsensusConfig,
     types::CreateProposalRequest,
-    utils::{build_vote, compute_vote_hash, validate_proposal},
+    utils::{build_vote, compute_vote_hash, validate_proposal, validate_proposal_report},
 };
 
 const SCOPE: &str = "validation_scope";
@@ -42,9 +43,9 @@ fn resign_vote(
 
 #[test]
 fn test_vote_created_with_helper_is_valid() {
-    let service = make_service();
-    let scope = ScopeID::from(SCOPE);
     let proposal_owner = PrivateKeySigner::random();
+    let service = make_service_for(&proposal_owner);
+    let scope = ScopeID::from(SCOPE);
 
     let proposal = service
         .create_proposal_with_config(
@@ -83,9 +84,9 @@ fn test_vote_created_with_helper_is_valid() {
 
 #[test]
 fn test_invalid_signature_is_rejected() {
-    let service = make_service();
-    let scope = ScopeID::from(SCOPE);
     let proposal_owner = PrivateKeySigner::random();
+    let service = make_service_for(&proposal_owner);
+    let scope = ScopeID::from(SCOPE);
 
     let proposal = service
         .create_proposal_with_config(
@@ -135,10 +136,96 @@ fn test_invalid_signature_is_rejected() {
 }
 
 #[test]
-fn test_vote_chain_validation_rejects_bad_received_hash() {
-    let service = make_service();
+fn test_validation_report_lists_every_issue() {
+    let proposal_owner = PrivateKeySigner::random();
+    let service = make_service_for(&proposal_owner);
     let scope = ScopeID::from(SCOPE);
+
+    let proposal = service
+        .create_proposal_with_config(
+            &scope,
+            CreateProposalRequest::new(
+                PROPOSAL_NAME.to_string(),
+                PROPOSAL_PAYLOAD,
+                owner_bytes(&proposal_owner),
+                EXPECTED_VOTERS_COUNT_3,
+                EXPIRATION,
+                true,
+            )
+            .expect("valid proposal request"),
+            Some(ConsensusConfig::gossipsub()),
+            now_ts(),
+        )
+        .expect("proposal");
+    let proposal = cast_remote_vote_and_get_proposal(
+        &service,
+        &scope,
+        proposal.proposal_id,
+        VOTE_YES,
+        &wrap(proposal_owner),
+    )
+    .expect("proposal_owner vote");
+    assert!(validate_proposal_report::<EthereumConsensusSigner>(&proposal, now_ts()).is_valid());
+
+    // Both votes link to the owner's vote, as parallel votes do, so only
+    // their signatures are at fault.
+    let mut forged = build_vote(
+        &proposal,
+        VOTE_YES,
+        &wrap(PrivateKeySigner::random()),
+        now_ts(),
+    )
+    .expect("forged vote");
+    forged.signature = PrivateKeySigner::random()
+        .sign_message_sync(&forged.encode_to_vec())
+        .expect("sign with wrong key")
+        .as_bytes()
+        .to_vec();
+    let mut unsigned = build_vote(
+        &proposal,
+        VOTE_NO,
+        &wrap(PrivateKeySigner::random()),
+        now_ts(),
+    )
+    .expect("unsigned vote");
+    unsigned.signature.clear();
+
+    let mut invalid = proposal.clone();
+    invalid.votes.push(forged);
+    invalid.votes.push(unsigned);
+
+    let report = validate_proposal_report::<EthereumConsensusSigner>(&invalid, now_ts());
+    assert!(!report.is_valid());
+    assert!(report.proposal_issues.is_empty());
+    assert!(
+        matches!(
+            report.vote_issues.as_slice(),
+            [
+                (1, ConsensusError::InvalidVoteSignature),
+                (2, ConsensusError::EmptySignature),
+            ]
+        ),
+        "issues: {:?}",
+        report.vote_issues
+    );
+    // The fail-fast path stops at the first of them.
+    assert!(matches!(
+        validate_proposal::<EthereumConsensusSigner>(&invalid, now_ts()),
+        Err(ConsensusError::InvalidVoteSignature)
+    ));
+
+    let expired = validate_proposal_report::<EthereumConsensusSigner>(
+        &proposal,
+        proposal.expiration_timestamp + 1,
+    );
+    assert!(!expired.proposal_issues.is_empty());
+}
+
+#[test]
+fn test_vote_chain_validation_rejects_bad_received_hash() {
     let proposal_owner = PrivateKeySigner::random();
+    let service = make_service_for(&proposal_owner);
+    let scope = ScopeID::from(SCOPE);
 
     let proposal = service
         .create_proposal_with_config(
@@ -197,9 +284,9 @@ fn test_vote_chain_validation_rejects_bad_received_hash() {
 
 #[test]
 fn test_validate_proposal_rejects_empty_vote_owner() {
-    let service = make_service();
-    let scope = ScopeID::from(SCOPE);
     let proposal_owner = PrivateKeySigner::random();
+    let service = make_service_for(&proposal_owner);
+    let scope = ScopeID::from(SCOPE);
 
     let proposal = service
         .create_proposal_with_config(
@@ -231,9 +318,9 @@ fn test_validate_proposal_rejects_empty_vote_owner() {
 
 #[test]
 fn test_validate_proposal_rejects_empty_vote_hash() {
-    let service = make_service();
-    let scope = ScopeID::from(SCOPE);
     let proposal_owner = PrivateKeySigner::random();
+    let service = make_service_for(&proposal_owner);
+    let scope = ScopeID::from(SCOPE);
 
     let proposal = service
         .create_proposal_with_config(
@@ -265,9 +352,9 @@ fn test_validate_proposal_rejects_empty_vote_hash() {
 
 #[test]
 fn test_validate_proposal_rejects_empty_signature() {
-    let service = make_service();
-    let scope = ScopeID::from(SCOPE);
     let proposal_owner = PrivateKeySigner::random();
+    let service = make_service_for(&proposal_owner);
+    let scope = ScopeID::from(SCOPE);
 
     let proposal = service
         .create_proposal_with_config(
@@ -299,9 +386,9 @@ fn test_validate_proposal_rejects_empty_signature() {
 
 #[test]
 fn test_validate_proposal_rejects_mismatched_signature_length() {
-    let service = make_service();
-    let scope = ScopeID::from(SCOPE);
     let proposal_owner = PrivateKeySigner::random();
+    let service = make_service_for(&proposal_owner);
+    let scope = ScopeID::from(SCOPE);
 
     let proposal = service
         .create_proposal_with_config(
@@ -335,9 +422,9 @@ fn test_validate_proposal_rejects_mismatched_signature_length() {
 
 #[test]
 fn test_vote_chain_validation_rejects_bad_parent_hash_owner_mismatch() {
-    let service = make_service();
-    let scope = ScopeID::from(SCOPE);
     let proposal_owner = PrivateKeySigner::random();
+    let service = make_service_for(&proposal_owner);
+    let scope = ScopeID::from(SCOPE);
 
     let proposal = service
         .create_proposal_with_config(
@@ -375,3 +462,127 @@ fn test_vote_chain_validation_rejects_bad_parent_hash_owner_mismatch() {
         .expect_err("parent hash owner mismatch should fail");
     assert!(matches!(err, ConsensusError::ParentHashMismatch));
 }
+
+/// secp256k1 group order `n`; `(r, n - s)` with the parity flipped is the
+/// malleated twin of a valid signature `(r, s)`.
+const SECP256K1_ORDER: &str = "0xfffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+
+fn to_high_s(signature: &[u8]) -> Vec<u8> {
+    let sig = Signature::from_raw(signature).expect("valid signature");
+    let order: U256 = SECP256K1_ORDER.parse().expect("order");
+    Signature::new(sig.r(), order - sig.s(), !sig.v())
+        .as_bytes()
+        .to_vec()
+}
+
+#[test]
+fn test_high_s_signature_rejected_only_under_strict_mode() {
+    let proposal_owner = PrivateKeySigner::random();
+    let service = make_service_for(&proposal_owner);
+    let scope = ScopeID::from(SCOPE);
+
+    let mut proposals = Vec::new();
+    for config in [
+        ConsensusConfig::gossipsub(),
+        ConsensusConfig::gossipsub().with_strict_signatures(false),
+    ] {
+        let proposal = service
+            .create_proposal_with_config(
+                &scope,
+                CreateProposalRequest::new(
+                    PROPOSAL_NAME.to_string(),
+                    PROPOSAL_PAYLOAD,
+                    owner_bytes(&proposal_owner),
+                    EXPECTED_VOTERS_COUNT_3,
+                    EXPIRATION,
+                    true,
+                )
+                .expect("valid proposal request"),
+                Some(config),
+                now_ts(),
+            )
+            .expect("proposal");
+        proposals.push(proposal);
+    }
+
+    let voter = PrivateKeySigner::random();
+    let mut outcomes = Vec::new();
+    for proposal in proposals {
+        let mut vote =
+            build_vote(&proposal, VOTE_YES, &wrap(voter.clone()), now_ts()).expect("vote");
+        let low_s = Signature::from_raw(&vote.signature).expect("valid signature");
+        assert!(low_s.normalize_s().is_none(), "build_vote must emit low-S");
+        vote.signature = to_high_s(&vote.signature);
+        outcomes.push(service.process_incoming_vote(&scope, vote, now_ts()));
+    }
+
+    assert!(matches!(
+        outcomes[0],
+        Err(ConsensusError::NonCanonicalSignature)
+    ));
+    assert!(
+        outcomes[1].is_ok(),
+        "lenient mode accepts: {:?}",
+        outcomes[1]
+    );
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn test_parallel_validation_matches_sequential() {
+    use hashgraph_like_consensus::utils::validate_proposal_parallel;
+
+    let proposal_owner = PrivateKeySigner::random();
+    let service = make_service_for(&proposal_owner);
+    let scope = ScopeID::from(SCOPE);
+    let mut proposal = service
+        .create_proposal(
+            &scope,
+            CreateProposalRequest::new(
+                PROPOSAL_NAME.to_string(),
+                PROPOSAL_PAYLOAD,
+                owner_bytes(&proposal_owner),
+                32,
+                EXPIRATION,
+                true,
+            )
+            .expect("valid proposal request"),
+            now_ts(),
+        )
+        .expect("proposal");
+    for _ in 0..32 {
+        let vote = build_vote(
+            &proposal,
+            VOTE_YES,
+            &wrap(PrivateKeySigner::random()),
+            now_ts(),
+        )
+        .expect("vote");
+        proposal.votes.push(vote);
+    }
+    assert!(validate_proposal_parallel::<EthereumConsensusSigner>(&proposal, now_ts()).is_ok());
+
+    // Several bad votes: both paths must report the earliest one.
+    let mut invalid = proposal.clone();
+    invalid.votes[20].vote_owner.clear();
+    invalid.votes[7].signature = invalid.votes[6].signature.clone();
+    invalid.votes[25].signature.clear();
+    let sequential = validate_proposal::<EthereumConsensusSigner>(&invalid, now_ts());
+    let parallel = validate_proposal_parallel::<EthereumConsensusSigner>(&invalid, now_ts());
+    assert!(
+        matches!(sequential, Err(ConsensusError::InvalidVoteSignature)),
+        "sequential: {sequential:?}"
+    );
+    assert!(
+        matches!(parallel, Err(ConsensusError::InvalidVoteSignature)),
+        "parallel: {parallel:?}"
+    );
+
+    // Per-vote checks pass, so the chain check decides.
+    let mut reordered = proposal.clone();
+    reordered.votes.swap(3, 4);
+    assert!(matches!(
+        validate_proposal_parallel::<EthereumConsensusSigner>(&reordered, now_ts()),
+        Err(ConsensusError::ReceivedHashMismatch)
+    ));
+}
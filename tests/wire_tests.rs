@@ -0,0 +1,201 @@
+mod common;
+use common::{cast_remote_vote, make_service, make_service_for, now_ts, owner_bytes, wrap};
+
+use alloy::signers::local::PrivateKeySigner;
+use hashgraph_like_consensus::{
+    error::ConsensusError,
+    protos::consensus::v1::{ConsensusMessage, consensus_message::Payload},
+    scope::ScopeID,
+    storage::ConsensusStorage,
+    types::CreateProposalRequest,
+    utils::{build_vote, sign_proposal},
+    wire::{self, PROTOCOL_VERSION},
+};
+use prost::Message;
+
+const SCOPE_NAME: &str = "wire_scope";
+
+#[test]
+fn test_envelope_round_trips_proposal_and_vote_into_service() {
+    let owner = PrivateKeySigner::random();
+    let sender = make_service_for(&owner);
+    let receiver = make_service();
+    let scope = ScopeID::from(SCOPE_NAME);
+
+    let proposal = sender
+        .create_proposal(
+            &scope,
+            CreateProposalRequest::new(
+                "Wire".into(),
+                b"payload".to_vec(),
+                owner_bytes(&owner),
+                3,
+                60,
+                true,
+            )
+            .unwrap(),
+            now_ts(),
+        )
+        .unwrap();
+
+    let bytes = wire::encode_proposal(&scope, proposal.clone());
+    let (decoded_scope, payload) = wire::decode::<ScopeID>(&bytes).unwrap();
+    assert_eq!(decoded_scope, scope);
+    assert!(matches!(payload, Payload::Proposal(ref p) if *p == proposal));
+
+    let routed = receiver.process_incoming_message(&bytes, now_ts()).unwrap();
+    assert_eq!(routed, scope);
+    assert_eq!(
+        receiver
+            .storage()
+            .get_proposal(&scope, proposal.proposal_id)
+            .unwrap(),
+        proposal
+    );
+
+    let vote = build_vote(&proposal, true, &wrap(owner), now_ts()).unwrap();
+    let bytes = wire::encode_vote(&scope, vote.clone());
+    let (_, payload) = wire::decode::<ScopeID>(&bytes).unwrap();
+    assert!(matches!(payload, Payload::Vote(ref v) if *v == vote));
+
+    receiver.process_incoming_message(&bytes, now_ts()).unwrap();
+    let session = receiver
+        .storage()
+        .get_session(&scope, proposal.proposal_id)
+        .unwrap()
+        .expect("session exists");
+    assert!(session.votes().contains_key(&vote.vote_owner));
+}
+
+#[test]
+fn test_envelope_rejects_malformed_and_unknown_version() {
+    let service = make_service();
+
+    let err = service
+        .process_incoming_message(b"\xff\xff\xff", now_ts())
+        .unwrap_err();
+    assert!(matches!(err, ConsensusError::MalformedMessage));
+
+    let empty = ConsensusMessage {
+        version: PROTOCOL_VERSION,
+        scope: SCOPE_NAME.as_bytes().to_vec(),
+        payload: None,
+    }
+    .encode_to_vec();
+    let err = service
+        .process_incoming_message(&empty, now_ts())
+        .unwrap_err();
+    assert!(matches!(err, ConsensusError::MalformedMessage));
+
+    let future = ConsensusMessage {
+        version: PROTOCOL_VERSION + 1,
+        scope: SCOPE_NAME.as_bytes().to_vec(),
+        payload: None,
+    }
+    .encode_to_vec();
+    let err = service
+        .process_incoming_message(&future, now_ts())
+        .unwrap_err();
+    assert!(matches!(
+        err,
+        ConsensusError::UnsupportedProtocolVersion(v) if v == PROTOCOL_VERSION + 1
+    ));
+}
+
+#[test]
+fn test_ids_above_32_bits_survive_the_wire() {
+    let owner = PrivateKeySigner::random();
+    let receiver = make_service();
+    let scope = ScopeID::from(SCOPE_NAME);
+
+    let mut proposal =
+        CreateProposalRequest::new("Wide".into(), vec![], owner_bytes(&owner), 3, 60, true)
+            .unwrap()
+            .into_proposal(now_ts())
+            .unwrap();
+    proposal.proposal_id = u64::from(u32::MAX) + 7;
+    sign_proposal(&mut proposal, &wrap(owner.clone())).unwrap();
+    receiver
+        .process_incoming_message(&wire::encode_proposal(&scope, proposal.clone()), now_ts())
+        .unwrap();
+
+    let vote = build_vote(&proposal, true, &wrap(owner), now_ts()).unwrap();
+    assert_eq!(vote.proposal_id, proposal.proposal_id);
+    receiver
+        .process_incoming_message(&wire::encode_vote(&scope, vote.clone()), now_ts())
+        .unwrap();
+    let session = receiver
+        .storage()
+        .get_session(&scope, proposal.proposal_id)
+        .unwrap()
+        .expect("session exists");
+    assert_eq!(session.votes()[&vote.vote_owner], vote);
+}
+
+#[test]
+fn test_snapshot_export_round_trips_and_detects_flipped_bytes() {
+    let owner = PrivateKeySigner::random();
+    let sender = make_service_for(&owner);
+    let receiver = make_service();
+    let scope = ScopeID::from(SCOPE_NAME);
+
+    let proposal = sender
+        .create_proposal(
+            &scope,
+            CreateProposalRequest::new(
+                "Snapshot".into(),
+                b"payload".to_vec(),
+                owner_bytes(&owner),
+                3,
+                60,
+                true,
+            )
+            .unwrap(),
+            now_ts(),
+        )
+        .unwrap();
+    cast_remote_vote(
+        &sender,
+        &scope,
+        proposal.proposal_id,
+        true,
+        &wrap(PrivateKeySigner::random()),
+    )
+    .unwrap();
+
+    let snapshot = sender.storage().rollover_scope(&scope).unwrap();
+    let bytes = wire::encode_snapshot(&scope, &snapshot);
+
+    let (decoded_scope, proposals) = wire::decode_snapshot::<ScopeID>(&bytes).unwrap();
+    assert_eq!(decoded_scope, scope);
+    assert_eq!(proposals, vec![snapshot.sessions[0].proposal.clone()]);
+    for proposal in proposals {
+        receiver
+            .process_incoming_proposal(&scope, proposal, now_ts())
+            .unwrap();
+    }
+    let imported = receiver
+        .storage()
+        .get_session(&scope, proposal.proposal_id)
+        .unwrap()
+        .expect("session imported");
+    assert_eq!(imported.votes().len(), 1);
+
+    // Any single flipped byte, in the checksum or the body, is caught.
+    for index in 0..bytes.len() {
+        let mut corrupt = bytes.clone();
+        corrupt[index] ^= 0x01;
+        assert!(matches!(
+            wire::decode_snapshot::<ScopeID>(&corrupt),
+            Err(ConsensusError::CorruptSnapshot)
+        ));
+    }
+    assert!(matches!(
+        wire::decode_snapshot::<ScopeID>(&bytes[..bytes.len() - 1]),
+        Err(ConsensusError::CorruptSnapshot)
+    ));
+    assert!(matches!(
+        wire::decode_snapshot::<ScopeID>(&[]),
+        Err(ConsensusError::CorruptSnapshot)
+    ));
+}